@@ -0,0 +1,422 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+use std::fmt::Display;
+
+use anna_encooder_x86_64::{
+    encode::{EncodeError, EncodeOptions, encode_with_options},
+    instruction::Instruction,
+};
+
+/// An item of program, e.g.
+///
+/// ```asm
+/// start:                  ; Label
+///     mov eax, [rel num1] ; Instruction
+///     jmp start           ; Instruction
+/// num1:                   ; Label
+///     dd 0x1234           ; Data
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub enum Item {
+    Label(String),
+    Instruction(Instruction),
+    Data(Vec<u8>),
+}
+
+/// The address of a label in the image.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub address: u64,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Image {
+    pub base_address: u64,
+    pub bytes: Vec<u8>,
+    pub symbols: Vec<Symbol>, // in the order of definition
+}
+
+/// The error of assembling, `item` is the index of the item in the program.
+#[derive(Debug, PartialEq, Clone)]
+pub enum AssembleError {
+    DuplicateLabel(String),
+    UndefinedLabel { item: usize, label: String },
+    Encode { item: usize, error: EncodeError },
+}
+
+impl Display for AssembleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssembleError::DuplicateLabel(label) => {
+                write!(f, "label \"{}\" is defined more than once", label)
+            }
+            AssembleError::UndefinedLabel { item, label } => {
+                write!(f, "item {}: label \"{}\" is not defined", item, label)
+            }
+            AssembleError::Encode { item, error } => write!(f, "item {}: {}", item, error),
+        }
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+/// Assembles the program into an image that is loaded at the base address.
+///
+/// Branch relaxation:
+///
+/// All branches start in the short form (rel8), the program is laid out and encoded
+/// repeatedly with the label addresses of the previous pass, and a branch grows to
+/// the near form (rel32) once its target is out of the range of rel8. Since growing
+/// a branch only increases the distances between the other branches and their
+/// targets, the layout converges when no instruction changes its size.
+///
+/// An instruction never shrinks back (it is encoded with `long_branch` once it has
+/// grown), so the number of passes is bounded by the number of branches.
+pub fn assemble(items: &[Item], base_address: u64) -> Result<Image, AssembleError> {
+    let mut names: Vec<&str> = vec![];
+    for item in items {
+        if let Item::Label(name) = item {
+            if names.contains(&name.as_str()) {
+                return Err(AssembleError::DuplicateLabel(name.clone()));
+            }
+            names.push(name);
+        }
+    }
+
+    // the sizes of instructions are unknown in the first pass, they are
+    // assumed to be zero so that all branches start in the short form.
+    let mut sizes: Vec<usize> = items
+        .iter()
+        .map(|item| match item {
+            Item::Data(data) => data.len(),
+            _ => 0,
+        })
+        .collect();
+
+    let mut long_branches = vec![false; items.len()];
+    let mut codes: Vec<Vec<u8>> = vec![vec![]; items.len()];
+
+    loop {
+        let (addresses, symbols) = layout(items, &sizes, base_address);
+        let lable_address_list: Vec<(&str, u64)> = symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.address))
+            .collect();
+
+        let mut changed = false;
+
+        for (idx, item) in items.iter().enumerate() {
+            let Item::Instruction(instruction) = item else {
+                continue;
+            };
+
+            let mut code = encode_item(
+                idx,
+                instruction,
+                addresses[idx],
+                &lable_address_list,
+                long_branches[idx],
+            )?;
+
+            if code.len() < sizes[idx] && !long_branches[idx] {
+                long_branches[idx] = true;
+                code = encode_item(
+                    idx,
+                    instruction,
+                    addresses[idx],
+                    &lable_address_list,
+                    true,
+                )?;
+            }
+
+            if code.len() != sizes[idx] {
+                sizes[idx] = code.len();
+                changed = true;
+            }
+
+            codes[idx] = code;
+        }
+
+        if !changed {
+            let mut bytes = vec![];
+            for (item, code) in items.iter().zip(codes.iter()) {
+                match item {
+                    Item::Label(_) => {}
+                    Item::Instruction(_) => bytes.extend_from_slice(code),
+                    Item::Data(data) => bytes.extend_from_slice(data),
+                }
+            }
+
+            return Ok(Image {
+                base_address,
+                bytes,
+                symbols,
+            });
+        }
+    }
+}
+
+/// Calculates the address of each item and the symbol table by the sizes of items.
+fn layout(items: &[Item], sizes: &[usize], base_address: u64) -> (Vec<u64>, Vec<Symbol>) {
+    let mut addresses = vec![];
+    let mut symbols = vec![];
+    let mut address = base_address;
+
+    for (item, size) in items.iter().zip(sizes.iter()) {
+        addresses.push(address);
+
+        if let Item::Label(name) = item {
+            symbols.push(Symbol {
+                name: name.clone(),
+                address,
+            });
+        }
+
+        address += *size as u64;
+    }
+
+    (addresses, symbols)
+}
+
+fn encode_item(
+    idx: usize,
+    instruction: &Instruction,
+    address: u64,
+    lable_address_list: &[(&str, u64)],
+    long_branch: bool,
+) -> Result<Vec<u8>, AssembleError> {
+    let options = EncodeOptions {
+        long_branch,
+        ..Default::default()
+    };
+
+    let encoded = encode_with_options(instruction, address, lable_address_list, &options)
+        .map_err(|error| AssembleError::Encode { item: idx, error })?;
+
+    // all labels of the program are in the list, so a fixup means the label is not defined.
+    if let Some(fixup) = encoded.fixups.first() {
+        return Err(AssembleError::UndefinedLabel {
+            item: idx,
+            label: fixup.label.clone(),
+        });
+    }
+
+    Ok(encoded.bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use anna_encooder_x86_64::{
+        encode::EncodeError,
+        instruction::{Instruction, MemoryOperand, Operand, OperandSize, Register},
+        mnemonic::Mnemonic,
+    };
+
+    use super::{AssembleError, Item, Symbol, assemble};
+
+    fn label(name: &str) -> Item {
+        Item::Label(name.to_owned())
+    }
+
+    fn instruction(mnemonic: Mnemonic, operands: &[Operand]) -> Item {
+        Item::Instruction(Instruction::new(mnemonic, operands))
+    }
+
+    fn jump(mnemonic: Mnemonic, target: &str) -> Item {
+        instruction(mnemonic, &[Operand::Label(target.to_owned())])
+    }
+
+    fn symbol(name: &str, address: u64) -> Symbol {
+        Symbol {
+            name: name.to_owned(),
+            address,
+        }
+    }
+
+    #[test]
+    fn test_assemble() {
+        // 0x1000 start:
+        // 0x1000     mov ecx, 10  -> b9 0a000000
+        // 0x1005 .loop:
+        // 0x1005     jmp .loop    -> eb fe
+        // 0x1007     jmp start    -> eb f7
+
+        let image = assemble(
+            &[
+                label("start"),
+                instruction(
+                    Mnemonic::MOV,
+                    &[Register::ECX.into(), Operand::Immediate8(10)],
+                ),
+                label(".loop"),
+                jump(Mnemonic::JMP, ".loop"),
+                jump(Mnemonic::JMP, "start"),
+            ],
+            0x1000,
+        )
+        .unwrap();
+
+        assert_eq!(image.base_address, 0x1000);
+        assert_eq!(
+            image.bytes,
+            [0xb9, 0x0a, 0x00, 0x00, 0x00, 0xeb, 0xfe, 0xeb, 0xf7]
+        );
+        assert_eq!(
+            image.symbols,
+            [symbol("start", 0x1000), symbol(".loop", 0x1005)]
+        );
+    }
+
+    #[test]
+    fn test_assemble_branch_relaxation() {
+        // the target is out of the range of rel8
+        //
+        // 0x0000     jmp end  -> e9 c8000000
+        // 0x0005     (200 bytes)
+        // 0x00cd end:
+
+        let image = assemble(
+            &[
+                jump(Mnemonic::JMP, "end"),
+                Item::Data(vec![0x90; 200]),
+                label("end"),
+            ],
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(image.bytes[..5], [0xe9, 0xc8, 0x00, 0x00, 0x00]);
+        assert_eq!(image.bytes.len(), 205);
+        assert_eq!(image.symbols, [symbol("end", 0xcd)]);
+
+        // growing a branch pushes the target of another branch out of range
+        //
+        // pass 1: `jmp b` is 128 bytes away from b, it grows to rel32.
+        // pass 2: `jmp a` is 129 bytes away from a, it grows to rel32.
+        // pass 3: no changes.
+        //
+        // 0x0000     jmp a    -> e9 81000000 (0x86 - 0x05)
+        // 0x0005     jmp b    -> e9 80000000 (0x8a - 0x0a)
+        // 0x000a     (124 bytes)
+        // 0x0086 a:
+        // 0x0086     (4 bytes)
+        // 0x008a b:
+
+        let image = assemble(
+            &[
+                jump(Mnemonic::JMP, "a"),
+                jump(Mnemonic::JMP, "b"),
+                Item::Data(vec![0x90; 124]),
+                label("a"),
+                Item::Data(vec![0x90; 4]),
+                label("b"),
+            ],
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(
+            image.bytes[..10],
+            [0xe9, 0x81, 0x00, 0x00, 0x00, 0xe9, 0x80, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(image.symbols, [symbol("a", 0x86), symbol("b", 0x8a)]);
+
+        // the boundary of rel8
+        //
+        // 0x0000     jmp a    -> eb 7f
+        // 0x0002     (127 bytes)
+        // 0x0081 a:
+
+        let image = assemble(
+            &[
+                jump(Mnemonic::JMP, "a"),
+                Item::Data(vec![0x90; 127]),
+                label("a"),
+            ],
+            0,
+        )
+        .unwrap();
+
+        assert_eq!(image.bytes[..2], [0xeb, 0x7f]);
+    }
+
+    #[test]
+    fn test_assemble_call_and_data() {
+        // `call` has no rel8 form, it is always rel32.
+        //
+        // 0x1000     call func            -> e8 00000000
+        // 0x1005 func:
+        // 0x1005     mov eax, [rel num1]  -> 8b 05 00000000
+        // 0x100b num1:
+        // 0x100b     dd 0x12345678
+
+        let image = assemble(
+            &[
+                jump(Mnemonic::CALL, "func"),
+                label("func"),
+                instruction(
+                    Mnemonic::MOV,
+                    &[
+                        Register::EAX.into(),
+                        MemoryOperand::new_rip_relative(OperandSize::Unsized)
+                            .with_symbol("num1")
+                            .into(),
+                    ],
+                ),
+                label("num1"),
+                Item::Data(vec![0x78, 0x56, 0x34, 0x12]),
+            ],
+            0x1000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            image.bytes,
+            [
+                0xe8, 0x00, 0x00, 0x00, 0x00, // call func
+                0x8b, 0x05, 0x00, 0x00, 0x00, 0x00, // mov eax, [rel num1]
+                0x78, 0x56, 0x34, 0x12, // num1
+            ]
+        );
+        assert_eq!(
+            image.symbols,
+            [symbol("func", 0x1005), symbol("num1", 0x100b)]
+        );
+    }
+
+    #[test]
+    fn test_assemble_error() {
+        assert_eq!(
+            assemble(&[label("a"), label("a")], 0).unwrap_err(),
+            AssembleError::DuplicateLabel("a".to_owned())
+        );
+
+        assert_eq!(
+            assemble(&[label("a"), jump(Mnemonic::JMP, "b")], 0).unwrap_err(),
+            AssembleError::UndefinedLabel {
+                item: 1,
+                label: "b".to_owned()
+            }
+        );
+
+        assert_eq!(
+            assemble(
+                &[instruction(
+                    Mnemonic::MOV,
+                    &[Register::EAX.into(), Register::CX.into()]
+                )],
+                0
+            )
+            .unwrap_err(),
+            AssembleError::Encode {
+                item: 0,
+                error: EncodeError::InvalidOperands(Mnemonic::MOV)
+            }
+        );
+    }
+}
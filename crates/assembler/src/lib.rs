@@ -0,0 +1,17 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+/* *
+ * The assembler lays out a whole program, i.e. a list of labels, instructions
+ * and data, resolves the labels and generates a byte image and a symbol table.
+ *
+ * The size of a branch instruction depends on the distance to its target,
+ * and the distance depends on the sizes of the instructions in between,
+ * so the layout is repeated until the addresses of all labels are stable,
+ * see `assembler::assemble`.
+ */
+
+pub mod assembler;
@@ -0,0 +1,5791 @@
+// Copyright (c) 2025 Hemashushu <hippospark@gmail.com>, All rights reserved.
+//
+// This Source Code Form is subject to the terms of
+// the Mozilla Public License version 2.0 and additional exceptions.
+// For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
+
+use crate::{
+    instruction::{
+        ImplicitOperand, Instruction, InstructionDefinition, OpcodeMap, Operand, OperandAccess,
+        OperandDefinition, OperandEncoding, OperandSize, OperandType, Prefix, Register,
+        RegisterClass, RegisterType,
+    },
+    mnemonic::{Condition, Mnemonic},
+};
+
+use Condition::{A, AE, B, BE, E, G, GE, L, LE, NE, NO, NP, NS, O, P, S};
+use Mnemonic::*;
+use OpcodeMap::{Legacy, Map0F, Map0F38, Map0F3A};
+use Prefix::{LOCK, REP, REPE, REPNE};
+use OperandAccess::{Read, ReadWrite, Write};
+use Register::{
+    AH, AL, AX, CL, DX, EAX, EBX, ECX, EDX, R11, RAX, RBP, RBX, RCX, RDI, RDX, RSI, RSP, XMM0,
+};
+use OperandSize::{Byte, Dword, Qword, Word, XMMWord, YMMWord};
+
+/* *
+ * The instruction definition table.
+ *
+ * Each entry is one form of an instruction, the comment above it is the
+ * "Opcode" and "Instruction" columns of the Intel manual.
+ *
+ * When more than one form matches the operands, the encoder selects the shortest
+ * one, and the order of entries breaks the tie, e.g. `mov rax, rcx` can be encoded
+ * by both `89 /r` (MR) and `8B /r` (RM), and the former is what NASM/GAS generate.
+ *
+ * Adding a new instruction is done by adding its forms to this table, and
+ * the mnemonic to `Mnemonic`.
+ */
+pub static INSTRUCTION_DEFINITIONS: &[InstructionDefinition] = &[
+    // MOV -- Move
+    //
+    // 88 /r, MOV r/m8, r8
+    InstructionDefinition::new(MOV, Legacy, 0x88, &[rm(Byte, Write), reg(Byte, Read)]),
+    // 89 /r, MOV r/m16, r16
+    InstructionDefinition::new(MOV, Legacy, 0x89, &[rm(Word, Write), reg(Word, Read)]),
+    // 89 /r, MOV r/m32, r32
+    InstructionDefinition::new(MOV, Legacy, 0x89, &[rm(Dword, Write), reg(Dword, Read)]),
+    // REX.W + 89 /r, MOV r/m64, r64
+    InstructionDefinition::new(MOV, Legacy, 0x89, &[rm(Qword, Write), reg(Qword, Read)]),
+    // 8A /r, MOV r8, r/m8
+    InstructionDefinition::new(MOV, Legacy, 0x8a, &[reg(Byte, Write), rm(Byte, Read)]),
+    // 8B /r, MOV r16, r/m16
+    InstructionDefinition::new(MOV, Legacy, 0x8b, &[reg(Word, Write), rm(Word, Read)]),
+    // 8B /r, MOV r32, r/m32
+    InstructionDefinition::new(MOV, Legacy, 0x8b, &[reg(Dword, Write), rm(Dword, Read)]),
+    // REX.W + 8B /r, MOV r64, r/m64
+    InstructionDefinition::new(MOV, Legacy, 0x8b, &[reg(Qword, Write), rm(Qword, Read)]),
+    // B0+ rb ib, MOV r8, imm8
+    InstructionDefinition::new(MOV, Legacy, 0xb0, &[opcode_reg(Byte, Write), imm(Byte)]),
+    // B8+ rw iw, MOV r16, imm16
+    InstructionDefinition::new(MOV, Legacy, 0xb8, &[opcode_reg(Word, Write), imm(Word)]),
+    // B8+ rd id, MOV r32, imm32
+    InstructionDefinition::new(MOV, Legacy, 0xb8, &[opcode_reg(Dword, Write), imm(Dword)]),
+    // REX.W + B8+ rd io, MOV r64, imm64
+    InstructionDefinition::new(MOV, Legacy, 0xb8, &[opcode_reg(Qword, Write), imm(Qword)]),
+    // C6 /0 ib, MOV r/m8, imm8
+    InstructionDefinition::new(MOV, Legacy, 0xc6, &[rm(Byte, Write), imm(Byte)])
+        .with_opcode_extension(0),
+    // C7 /0 iw, MOV r/m16, imm16
+    InstructionDefinition::new(MOV, Legacy, 0xc7, &[rm(Word, Write), imm(Word)])
+        .with_opcode_extension(0),
+    // C7 /0 id, MOV r/m32, imm32
+    InstructionDefinition::new(MOV, Legacy, 0xc7, &[rm(Dword, Write), imm(Dword)])
+        .with_opcode_extension(0),
+    // REX.W + C7 /0 id, MOV r/m64, imm32 (sign-extended)
+    InstructionDefinition::new(MOV, Legacy, 0xc7, &[rm(Qword, Write), simm(Dword)])
+        .with_opcode_extension(0),
+    //
+    // MOV -- Move to/from Debug Registers
+    //
+    // The general-purpose register is always 64-bit in 64-bit mode, and the ModRM.mod
+    // is ignored (treated as 11), so there is no memory form.
+    //
+    // 0F 21 /r, MOV r64, DR0-DR7
+    InstructionDefinition::new(MOV, Map0F, 0x21, &[rm_reg(Qword, Write), dr(Read)])
+        .with_default_64(),
+    // 0F 23 /r, MOV DR0-DR7, r64
+    InstructionDefinition::new(MOV, Map0F, 0x23, &[dr(Write), rm_reg(Qword, Read)])
+        .with_default_64(),
+    //
+    // MOVZX -- Move With Zero-Extend
+    //
+    // 0F B6 /r, MOVZX r16, r/m8
+    InstructionDefinition::new(MOVZX, Map0F, 0xb6, &[reg(Word, Write), rm(Byte, Read)]),
+    // 0F B6 /r, MOVZX r32, r/m8
+    InstructionDefinition::new(MOVZX, Map0F, 0xb6, &[reg(Dword, Write), rm(Byte, Read)]),
+    // REX.W + 0F B6 /r, MOVZX r64, r/m8
+    InstructionDefinition::new(MOVZX, Map0F, 0xb6, &[reg(Qword, Write), rm(Byte, Read)]),
+    // 0F B7 /r, MOVZX r32, r/m16
+    InstructionDefinition::new(MOVZX, Map0F, 0xb7, &[reg(Dword, Write), rm(Word, Read)]),
+    // REX.W + 0F B7 /r, MOVZX r64, r/m16
+    InstructionDefinition::new(MOVZX, Map0F, 0xb7, &[reg(Qword, Write), rm(Word, Read)]),
+    //
+    // MOVSX/MOVSXD -- Move With Sign-Extension
+    //
+    // 0F BE /r, MOVSX r16, r/m8
+    InstructionDefinition::new(MOVSX, Map0F, 0xbe, &[reg(Word, Write), rm(Byte, Read)]),
+    // 0F BE /r, MOVSX r32, r/m8
+    InstructionDefinition::new(MOVSX, Map0F, 0xbe, &[reg(Dword, Write), rm(Byte, Read)]),
+    // REX.W + 0F BE /r, MOVSX r64, r/m8
+    InstructionDefinition::new(MOVSX, Map0F, 0xbe, &[reg(Qword, Write), rm(Byte, Read)]),
+    // 0F BF /r, MOVSX r32, r/m16
+    InstructionDefinition::new(MOVSX, Map0F, 0xbf, &[reg(Dword, Write), rm(Word, Read)]),
+    // REX.W + 0F BF /r, MOVSX r64, r/m16
+    InstructionDefinition::new(MOVSX, Map0F, 0xbf, &[reg(Qword, Write), rm(Word, Read)]),
+    // 63 /r, MOVSXD r16, r/m16
+    InstructionDefinition::new(MOVSXD, Legacy, 0x63, &[reg(Word, Write), rm(Word, Read)]),
+    // 63 /r, MOVSXD r32, r/m32
+    InstructionDefinition::new(MOVSXD, Legacy, 0x63, &[reg(Dword, Write), rm(Dword, Read)]),
+    // REX.W + 63 /r, MOVSXD r64, r/m32
+    InstructionDefinition::new(MOVSXD, Legacy, 0x63, &[reg(Qword, Write), rm(Dword, Read)]),
+    // LEA -- Load Effective Address
+    //
+    // 8D /r, LEA r16, m
+    InstructionDefinition::new(LEA, Legacy, 0x8d, &[reg(Word, Write), m()]),
+    // 8D /r, LEA r32, m
+    InstructionDefinition::new(LEA, Legacy, 0x8d, &[reg(Dword, Write), m()]),
+    // REX.W + 8D /r, LEA r64, m
+    InstructionDefinition::new(LEA, Legacy, 0x8d, &[reg(Qword, Write), m()]),
+    // ADD -- Add
+    //
+    // 04 ib, ADD AL, imm8
+    InstructionDefinition::new(ADD, Legacy, 0x04, &[fixed(AL, ReadWrite), imm(Byte)]),
+    // 05 iw, ADD AX, imm16
+    InstructionDefinition::new(ADD, Legacy, 0x05, &[fixed(AX, ReadWrite), imm(Word)]),
+    // 05 id, ADD EAX, imm32
+    InstructionDefinition::new(ADD, Legacy, 0x05, &[fixed(EAX, ReadWrite), imm(Dword)]),
+    // REX.W + 05 id, ADD RAX, imm32
+    InstructionDefinition::new(ADD, Legacy, 0x05, &[fixed(RAX, ReadWrite), simm(Dword)]),
+    // 80 /0 ib, ADD r/m8, imm8
+    InstructionDefinition::new(ADD, Legacy, 0x80, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /0 iw, ADD r/m16, imm16
+    InstructionDefinition::new(ADD, Legacy, 0x81, &[rm(Word, ReadWrite), imm(Word)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /0 id, ADD r/m32, imm32
+    InstructionDefinition::new(ADD, Legacy, 0x81, &[rm(Dword, ReadWrite), imm(Dword)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 81 /0 id, ADD r/m64, imm32
+    InstructionDefinition::new(ADD, Legacy, 0x81, &[rm(Qword, ReadWrite), simm(Dword)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /0 ib, ADD r/m16, imm8
+    InstructionDefinition::new(ADD, Legacy, 0x83, &[rm(Word, ReadWrite), simm(Byte)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /0 ib, ADD r/m32, imm8
+    InstructionDefinition::new(ADD, Legacy, 0x83, &[rm(Dword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 83 /0 ib, ADD r/m64, imm8
+    InstructionDefinition::new(ADD, Legacy, 0x83, &[rm(Qword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // 00 /r, ADD r/m8, r8
+    InstructionDefinition::new(ADD, Legacy, 0x00, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 01 /r, ADD r/m16, r16
+    InstructionDefinition::new(ADD, Legacy, 0x01, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 01 /r, ADD r/m32, r32
+    InstructionDefinition::new(ADD, Legacy, 0x01, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 01 /r, ADD r/m64, r64
+    InstructionDefinition::new(ADD, Legacy, 0x01, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 02 /r, ADD r8, r/m8
+    InstructionDefinition::new(ADD, Legacy, 0x02, &[reg(Byte, ReadWrite), rm(Byte, Read)]),
+    // 03 /r, ADD r16, r/m16
+    InstructionDefinition::new(ADD, Legacy, 0x03, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 03 /r, ADD r32, r/m32
+    InstructionDefinition::new(ADD, Legacy, 0x03, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 03 /r, ADD r64, r/m64
+    InstructionDefinition::new(ADD, Legacy, 0x03, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // OR -- Logical Inclusive OR
+    //
+    // 0C ib, OR AL, imm8
+    InstructionDefinition::new(OR, Legacy, 0x0c, &[fixed(AL, ReadWrite), imm(Byte)]),
+    // 0D iw, OR AX, imm16
+    InstructionDefinition::new(OR, Legacy, 0x0d, &[fixed(AX, ReadWrite), imm(Word)]),
+    // 0D id, OR EAX, imm32
+    InstructionDefinition::new(OR, Legacy, 0x0d, &[fixed(EAX, ReadWrite), imm(Dword)]),
+    // REX.W + 0D id, OR RAX, imm32
+    InstructionDefinition::new(OR, Legacy, 0x0d, &[fixed(RAX, ReadWrite), simm(Dword)]),
+    // 80 /1 ib, OR r/m8, imm8
+    InstructionDefinition::new(OR, Legacy, 0x80, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /1 iw, OR r/m16, imm16
+    InstructionDefinition::new(OR, Legacy, 0x81, &[rm(Word, ReadWrite), imm(Word)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /1 id, OR r/m32, imm32
+    InstructionDefinition::new(OR, Legacy, 0x81, &[rm(Dword, ReadWrite), imm(Dword)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 81 /1 id, OR r/m64, imm32
+    InstructionDefinition::new(OR, Legacy, 0x81, &[rm(Qword, ReadWrite), simm(Dword)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /1 ib, OR r/m16, imm8
+    InstructionDefinition::new(OR, Legacy, 0x83, &[rm(Word, ReadWrite), simm(Byte)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /1 ib, OR r/m32, imm8
+    InstructionDefinition::new(OR, Legacy, 0x83, &[rm(Dword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 83 /1 ib, OR r/m64, imm8
+    InstructionDefinition::new(OR, Legacy, 0x83, &[rm(Qword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // 08 /r, OR r/m8, r8
+    InstructionDefinition::new(OR, Legacy, 0x08, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 09 /r, OR r/m16, r16
+    InstructionDefinition::new(OR, Legacy, 0x09, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 09 /r, OR r/m32, r32
+    InstructionDefinition::new(OR, Legacy, 0x09, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 09 /r, OR r/m64, r64
+    InstructionDefinition::new(OR, Legacy, 0x09, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0A /r, OR r8, r/m8
+    InstructionDefinition::new(OR, Legacy, 0x0a, &[reg(Byte, ReadWrite), rm(Byte, Read)]),
+    // 0B /r, OR r16, r/m16
+    InstructionDefinition::new(OR, Legacy, 0x0b, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0B /r, OR r32, r/m32
+    InstructionDefinition::new(OR, Legacy, 0x0b, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0B /r, OR r64, r/m64
+    InstructionDefinition::new(OR, Legacy, 0x0b, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // ADC -- Add With Carry
+    //
+    // 14 ib, ADC AL, imm8
+    InstructionDefinition::new(ADC, Legacy, 0x14, &[fixed(AL, ReadWrite), imm(Byte)]),
+    // 15 iw, ADC AX, imm16
+    InstructionDefinition::new(ADC, Legacy, 0x15, &[fixed(AX, ReadWrite), imm(Word)]),
+    // 15 id, ADC EAX, imm32
+    InstructionDefinition::new(ADC, Legacy, 0x15, &[fixed(EAX, ReadWrite), imm(Dword)]),
+    // REX.W + 15 id, ADC RAX, imm32
+    InstructionDefinition::new(ADC, Legacy, 0x15, &[fixed(RAX, ReadWrite), simm(Dword)]),
+    // 80 /2 ib, ADC r/m8, imm8
+    InstructionDefinition::new(ADC, Legacy, 0x80, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /2 iw, ADC r/m16, imm16
+    InstructionDefinition::new(ADC, Legacy, 0x81, &[rm(Word, ReadWrite), imm(Word)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /2 id, ADC r/m32, imm32
+    InstructionDefinition::new(ADC, Legacy, 0x81, &[rm(Dword, ReadWrite), imm(Dword)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 81 /2 id, ADC r/m64, imm32
+    InstructionDefinition::new(ADC, Legacy, 0x81, &[rm(Qword, ReadWrite), simm(Dword)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /2 ib, ADC r/m16, imm8
+    InstructionDefinition::new(ADC, Legacy, 0x83, &[rm(Word, ReadWrite), simm(Byte)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /2 ib, ADC r/m32, imm8
+    InstructionDefinition::new(ADC, Legacy, 0x83, &[rm(Dword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 83 /2 ib, ADC r/m64, imm8
+    InstructionDefinition::new(ADC, Legacy, 0x83, &[rm(Qword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // 10 /r, ADC r/m8, r8
+    InstructionDefinition::new(ADC, Legacy, 0x10, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 11 /r, ADC r/m16, r16
+    InstructionDefinition::new(ADC, Legacy, 0x11, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 11 /r, ADC r/m32, r32
+    InstructionDefinition::new(ADC, Legacy, 0x11, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 11 /r, ADC r/m64, r64
+    InstructionDefinition::new(ADC, Legacy, 0x11, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 12 /r, ADC r8, r/m8
+    InstructionDefinition::new(ADC, Legacy, 0x12, &[reg(Byte, ReadWrite), rm(Byte, Read)]),
+    // 13 /r, ADC r16, r/m16
+    InstructionDefinition::new(ADC, Legacy, 0x13, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 13 /r, ADC r32, r/m32
+    InstructionDefinition::new(ADC, Legacy, 0x13, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 13 /r, ADC r64, r/m64
+    InstructionDefinition::new(ADC, Legacy, 0x13, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // SBB -- Integer Subtraction With Borrow
+    //
+    // 1C ib, SBB AL, imm8
+    InstructionDefinition::new(SBB, Legacy, 0x1c, &[fixed(AL, ReadWrite), imm(Byte)]),
+    // 1D iw, SBB AX, imm16
+    InstructionDefinition::new(SBB, Legacy, 0x1d, &[fixed(AX, ReadWrite), imm(Word)]),
+    // 1D id, SBB EAX, imm32
+    InstructionDefinition::new(SBB, Legacy, 0x1d, &[fixed(EAX, ReadWrite), imm(Dword)]),
+    // REX.W + 1D id, SBB RAX, imm32
+    InstructionDefinition::new(SBB, Legacy, 0x1d, &[fixed(RAX, ReadWrite), simm(Dword)]),
+    // 80 /3 ib, SBB r/m8, imm8
+    InstructionDefinition::new(SBB, Legacy, 0x80, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /3 iw, SBB r/m16, imm16
+    InstructionDefinition::new(SBB, Legacy, 0x81, &[rm(Word, ReadWrite), imm(Word)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /3 id, SBB r/m32, imm32
+    InstructionDefinition::new(SBB, Legacy, 0x81, &[rm(Dword, ReadWrite), imm(Dword)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 81 /3 id, SBB r/m64, imm32
+    InstructionDefinition::new(SBB, Legacy, 0x81, &[rm(Qword, ReadWrite), simm(Dword)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /3 ib, SBB r/m16, imm8
+    InstructionDefinition::new(SBB, Legacy, 0x83, &[rm(Word, ReadWrite), simm(Byte)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /3 ib, SBB r/m32, imm8
+    InstructionDefinition::new(SBB, Legacy, 0x83, &[rm(Dword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 83 /3 ib, SBB r/m64, imm8
+    InstructionDefinition::new(SBB, Legacy, 0x83, &[rm(Qword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // 18 /r, SBB r/m8, r8
+    InstructionDefinition::new(SBB, Legacy, 0x18, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 19 /r, SBB r/m16, r16
+    InstructionDefinition::new(SBB, Legacy, 0x19, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 19 /r, SBB r/m32, r32
+    InstructionDefinition::new(SBB, Legacy, 0x19, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 19 /r, SBB r/m64, r64
+    InstructionDefinition::new(SBB, Legacy, 0x19, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 1A /r, SBB r8, r/m8
+    InstructionDefinition::new(SBB, Legacy, 0x1a, &[reg(Byte, ReadWrite), rm(Byte, Read)]),
+    // 1B /r, SBB r16, r/m16
+    InstructionDefinition::new(SBB, Legacy, 0x1b, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 1B /r, SBB r32, r/m32
+    InstructionDefinition::new(SBB, Legacy, 0x1b, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 1B /r, SBB r64, r/m64
+    InstructionDefinition::new(SBB, Legacy, 0x1b, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // AND -- Logical AND
+    //
+    // 24 ib, AND AL, imm8
+    InstructionDefinition::new(AND, Legacy, 0x24, &[fixed(AL, ReadWrite), imm(Byte)]),
+    // 25 iw, AND AX, imm16
+    InstructionDefinition::new(AND, Legacy, 0x25, &[fixed(AX, ReadWrite), imm(Word)]),
+    // 25 id, AND EAX, imm32
+    InstructionDefinition::new(AND, Legacy, 0x25, &[fixed(EAX, ReadWrite), imm(Dword)]),
+    // REX.W + 25 id, AND RAX, imm32
+    InstructionDefinition::new(AND, Legacy, 0x25, &[fixed(RAX, ReadWrite), simm(Dword)]),
+    // 80 /4 ib, AND r/m8, imm8
+    InstructionDefinition::new(AND, Legacy, 0x80, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(4)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /4 iw, AND r/m16, imm16
+    InstructionDefinition::new(AND, Legacy, 0x81, &[rm(Word, ReadWrite), imm(Word)])
+        .with_opcode_extension(4)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /4 id, AND r/m32, imm32
+    InstructionDefinition::new(AND, Legacy, 0x81, &[rm(Dword, ReadWrite), imm(Dword)])
+        .with_opcode_extension(4)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 81 /4 id, AND r/m64, imm32
+    InstructionDefinition::new(AND, Legacy, 0x81, &[rm(Qword, ReadWrite), simm(Dword)])
+        .with_opcode_extension(4)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /4 ib, AND r/m16, imm8
+    InstructionDefinition::new(AND, Legacy, 0x83, &[rm(Word, ReadWrite), simm(Byte)])
+        .with_opcode_extension(4)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /4 ib, AND r/m32, imm8
+    InstructionDefinition::new(AND, Legacy, 0x83, &[rm(Dword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(4)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 83 /4 ib, AND r/m64, imm8
+    InstructionDefinition::new(AND, Legacy, 0x83, &[rm(Qword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(4)
+        .with_allowed_prefixes(&[LOCK]),
+    // 20 /r, AND r/m8, r8
+    InstructionDefinition::new(AND, Legacy, 0x20, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 21 /r, AND r/m16, r16
+    InstructionDefinition::new(AND, Legacy, 0x21, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 21 /r, AND r/m32, r32
+    InstructionDefinition::new(AND, Legacy, 0x21, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 21 /r, AND r/m64, r64
+    InstructionDefinition::new(AND, Legacy, 0x21, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 22 /r, AND r8, r/m8
+    InstructionDefinition::new(AND, Legacy, 0x22, &[reg(Byte, ReadWrite), rm(Byte, Read)]),
+    // 23 /r, AND r16, r/m16
+    InstructionDefinition::new(AND, Legacy, 0x23, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 23 /r, AND r32, r/m32
+    InstructionDefinition::new(AND, Legacy, 0x23, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 23 /r, AND r64, r/m64
+    InstructionDefinition::new(AND, Legacy, 0x23, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // SUB -- Subtract
+    //
+    // 2C ib, SUB AL, imm8
+    InstructionDefinition::new(SUB, Legacy, 0x2c, &[fixed(AL, ReadWrite), imm(Byte)]),
+    // 2D iw, SUB AX, imm16
+    InstructionDefinition::new(SUB, Legacy, 0x2d, &[fixed(AX, ReadWrite), imm(Word)]),
+    // 2D id, SUB EAX, imm32
+    InstructionDefinition::new(SUB, Legacy, 0x2d, &[fixed(EAX, ReadWrite), imm(Dword)]),
+    // REX.W + 2D id, SUB RAX, imm32
+    InstructionDefinition::new(SUB, Legacy, 0x2d, &[fixed(RAX, ReadWrite), simm(Dword)]),
+    // 80 /5 ib, SUB r/m8, imm8
+    InstructionDefinition::new(SUB, Legacy, 0x80, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /5 iw, SUB r/m16, imm16
+    InstructionDefinition::new(SUB, Legacy, 0x81, &[rm(Word, ReadWrite), imm(Word)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /5 id, SUB r/m32, imm32
+    InstructionDefinition::new(SUB, Legacy, 0x81, &[rm(Dword, ReadWrite), imm(Dword)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 81 /5 id, SUB r/m64, imm32
+    InstructionDefinition::new(SUB, Legacy, 0x81, &[rm(Qword, ReadWrite), simm(Dword)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /5 ib, SUB r/m16, imm8
+    InstructionDefinition::new(SUB, Legacy, 0x83, &[rm(Word, ReadWrite), simm(Byte)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /5 ib, SUB r/m32, imm8
+    InstructionDefinition::new(SUB, Legacy, 0x83, &[rm(Dword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 83 /5 ib, SUB r/m64, imm8
+    InstructionDefinition::new(SUB, Legacy, 0x83, &[rm(Qword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // 28 /r, SUB r/m8, r8
+    InstructionDefinition::new(SUB, Legacy, 0x28, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 29 /r, SUB r/m16, r16
+    InstructionDefinition::new(SUB, Legacy, 0x29, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 29 /r, SUB r/m32, r32
+    InstructionDefinition::new(SUB, Legacy, 0x29, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 29 /r, SUB r/m64, r64
+    InstructionDefinition::new(SUB, Legacy, 0x29, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 2A /r, SUB r8, r/m8
+    InstructionDefinition::new(SUB, Legacy, 0x2a, &[reg(Byte, ReadWrite), rm(Byte, Read)]),
+    // 2B /r, SUB r16, r/m16
+    InstructionDefinition::new(SUB, Legacy, 0x2b, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 2B /r, SUB r32, r/m32
+    InstructionDefinition::new(SUB, Legacy, 0x2b, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 2B /r, SUB r64, r/m64
+    InstructionDefinition::new(SUB, Legacy, 0x2b, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // XOR -- Logical Exclusive OR
+    //
+    // 34 ib, XOR AL, imm8
+    InstructionDefinition::new(XOR, Legacy, 0x34, &[fixed(AL, ReadWrite), imm(Byte)]),
+    // 35 iw, XOR AX, imm16
+    InstructionDefinition::new(XOR, Legacy, 0x35, &[fixed(AX, ReadWrite), imm(Word)]),
+    // 35 id, XOR EAX, imm32
+    InstructionDefinition::new(XOR, Legacy, 0x35, &[fixed(EAX, ReadWrite), imm(Dword)]),
+    // REX.W + 35 id, XOR RAX, imm32
+    InstructionDefinition::new(XOR, Legacy, 0x35, &[fixed(RAX, ReadWrite), simm(Dword)]),
+    // 80 /6 ib, XOR r/m8, imm8
+    InstructionDefinition::new(XOR, Legacy, 0x80, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /6 iw, XOR r/m16, imm16
+    InstructionDefinition::new(XOR, Legacy, 0x81, &[rm(Word, ReadWrite), imm(Word)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // 81 /6 id, XOR r/m32, imm32
+    InstructionDefinition::new(XOR, Legacy, 0x81, &[rm(Dword, ReadWrite), imm(Dword)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 81 /6 id, XOR r/m64, imm32
+    InstructionDefinition::new(XOR, Legacy, 0x81, &[rm(Qword, ReadWrite), simm(Dword)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /6 ib, XOR r/m16, imm8
+    InstructionDefinition::new(XOR, Legacy, 0x83, &[rm(Word, ReadWrite), simm(Byte)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // 83 /6 ib, XOR r/m32, imm8
+    InstructionDefinition::new(XOR, Legacy, 0x83, &[rm(Dword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 83 /6 ib, XOR r/m64, imm8
+    InstructionDefinition::new(XOR, Legacy, 0x83, &[rm(Qword, ReadWrite), simm(Byte)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // 30 /r, XOR r/m8, r8
+    InstructionDefinition::new(XOR, Legacy, 0x30, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 31 /r, XOR r/m16, r16
+    InstructionDefinition::new(XOR, Legacy, 0x31, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 31 /r, XOR r/m32, r32
+    InstructionDefinition::new(XOR, Legacy, 0x31, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 31 /r, XOR r/m64, r64
+    InstructionDefinition::new(XOR, Legacy, 0x31, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 32 /r, XOR r8, r/m8
+    InstructionDefinition::new(XOR, Legacy, 0x32, &[reg(Byte, ReadWrite), rm(Byte, Read)]),
+    // 33 /r, XOR r16, r/m16
+    InstructionDefinition::new(XOR, Legacy, 0x33, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 33 /r, XOR r32, r/m32
+    InstructionDefinition::new(XOR, Legacy, 0x33, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 33 /r, XOR r64, r/m64
+    InstructionDefinition::new(XOR, Legacy, 0x33, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // CMP -- Compare Two Operands
+    //
+    // 3C ib, CMP AL, imm8
+    InstructionDefinition::new(CMP, Legacy, 0x3c, &[fixed(AL, Read), imm(Byte)]),
+    // 3D iw, CMP AX, imm16
+    InstructionDefinition::new(CMP, Legacy, 0x3d, &[fixed(AX, Read), imm(Word)]),
+    // 3D id, CMP EAX, imm32
+    InstructionDefinition::new(CMP, Legacy, 0x3d, &[fixed(EAX, Read), imm(Dword)]),
+    // REX.W + 3D id, CMP RAX, imm32
+    InstructionDefinition::new(CMP, Legacy, 0x3d, &[fixed(RAX, Read), simm(Dword)]),
+    // 80 /7 ib, CMP r/m8, imm8
+    InstructionDefinition::new(CMP, Legacy, 0x80, &[rm(Byte, Read), imm(Byte)])
+        .with_opcode_extension(7),
+    // 81 /7 iw, CMP r/m16, imm16
+    InstructionDefinition::new(CMP, Legacy, 0x81, &[rm(Word, Read), imm(Word)])
+        .with_opcode_extension(7),
+    // 81 /7 id, CMP r/m32, imm32
+    InstructionDefinition::new(CMP, Legacy, 0x81, &[rm(Dword, Read), imm(Dword)])
+        .with_opcode_extension(7),
+    // REX.W + 81 /7 id, CMP r/m64, imm32
+    InstructionDefinition::new(CMP, Legacy, 0x81, &[rm(Qword, Read), simm(Dword)])
+        .with_opcode_extension(7),
+    // 83 /7 ib, CMP r/m16, imm8
+    InstructionDefinition::new(CMP, Legacy, 0x83, &[rm(Word, Read), simm(Byte)])
+        .with_opcode_extension(7),
+    // 83 /7 ib, CMP r/m32, imm8
+    InstructionDefinition::new(CMP, Legacy, 0x83, &[rm(Dword, Read), simm(Byte)])
+        .with_opcode_extension(7),
+    // REX.W + 83 /7 ib, CMP r/m64, imm8
+    InstructionDefinition::new(CMP, Legacy, 0x83, &[rm(Qword, Read), simm(Byte)])
+        .with_opcode_extension(7),
+    // 38 /r, CMP r/m8, r8
+    InstructionDefinition::new(CMP, Legacy, 0x38, &[rm(Byte, Read), reg(Byte, Read)]),
+    // 39 /r, CMP r/m16, r16
+    InstructionDefinition::new(CMP, Legacy, 0x39, &[rm(Word, Read), reg(Word, Read)]),
+    // 39 /r, CMP r/m32, r32
+    InstructionDefinition::new(CMP, Legacy, 0x39, &[rm(Dword, Read), reg(Dword, Read)]),
+    // REX.W + 39 /r, CMP r/m64, r64
+    InstructionDefinition::new(CMP, Legacy, 0x39, &[rm(Qword, Read), reg(Qword, Read)]),
+    // 3A /r, CMP r8, r/m8
+    InstructionDefinition::new(CMP, Legacy, 0x3a, &[reg(Byte, Read), rm(Byte, Read)]),
+    // 3B /r, CMP r16, r/m16
+    InstructionDefinition::new(CMP, Legacy, 0x3b, &[reg(Word, Read), rm(Word, Read)]),
+    // 3B /r, CMP r32, r/m32
+    InstructionDefinition::new(CMP, Legacy, 0x3b, &[reg(Dword, Read), rm(Dword, Read)]),
+    // REX.W + 3B /r, CMP r64, r/m64
+    InstructionDefinition::new(CMP, Legacy, 0x3b, &[reg(Qword, Read), rm(Qword, Read)]),
+    // TEST -- Logical Compare
+    //
+    // A8 ib, TEST AL, imm8
+    InstructionDefinition::new(TEST, Legacy, 0xa8, &[fixed(AL, Read), imm(Byte)]),
+    // A9 iw, TEST AX, imm16
+    InstructionDefinition::new(TEST, Legacy, 0xa9, &[fixed(AX, Read), imm(Word)]),
+    // A9 id, TEST EAX, imm32
+    InstructionDefinition::new(TEST, Legacy, 0xa9, &[fixed(EAX, Read), imm(Dword)]),
+    // REX.W + A9 id, TEST RAX, imm32
+    InstructionDefinition::new(TEST, Legacy, 0xa9, &[fixed(RAX, Read), simm(Dword)]),
+    // F6 /0 ib, TEST r/m8, imm8
+    InstructionDefinition::new(TEST, Legacy, 0xf6, &[rm(Byte, Read), imm(Byte)])
+        .with_opcode_extension(0),
+    // F7 /0 iw, TEST r/m16, imm16
+    InstructionDefinition::new(TEST, Legacy, 0xf7, &[rm(Word, Read), imm(Word)])
+        .with_opcode_extension(0),
+    // F7 /0 id, TEST r/m32, imm32
+    InstructionDefinition::new(TEST, Legacy, 0xf7, &[rm(Dword, Read), imm(Dword)])
+        .with_opcode_extension(0),
+    // REX.W + F7 /0 id, TEST r/m64, imm32
+    InstructionDefinition::new(TEST, Legacy, 0xf7, &[rm(Qword, Read), simm(Dword)])
+        .with_opcode_extension(0),
+    // 84 /r, TEST r/m8, r8
+    InstructionDefinition::new(TEST, Legacy, 0x84, &[rm(Byte, Read), reg(Byte, Read)]),
+    // 85 /r, TEST r/m16, r16
+    InstructionDefinition::new(TEST, Legacy, 0x85, &[rm(Word, Read), reg(Word, Read)]),
+    // 85 /r, TEST r/m32, r32
+    InstructionDefinition::new(TEST, Legacy, 0x85, &[rm(Dword, Read), reg(Dword, Read)]),
+    // REX.W + 85 /r, TEST r/m64, r64
+    InstructionDefinition::new(TEST, Legacy, 0x85, &[rm(Qword, Read), reg(Qword, Read)]),
+    // ROL/ROR/RCL/RCR -- Rotate
+    //
+    // D0 /0, ROL r/m8, 1
+    InstructionDefinition::new(ROL, Legacy, 0xd0, &[rm(Byte, ReadWrite), one()])
+        .with_opcode_extension(0),
+    // D1 /0, ROL r/m16, 1
+    InstructionDefinition::new(ROL, Legacy, 0xd1, &[rm(Word, ReadWrite), one()])
+        .with_opcode_extension(0),
+    // D1 /0, ROL r/m32, 1
+    InstructionDefinition::new(ROL, Legacy, 0xd1, &[rm(Dword, ReadWrite), one()])
+        .with_opcode_extension(0),
+    // REX.W + D1 /0, ROL r/m64, 1
+    InstructionDefinition::new(ROL, Legacy, 0xd1, &[rm(Qword, ReadWrite), one()])
+        .with_opcode_extension(0),
+    // D2 /0, ROL r/m8, CL
+    InstructionDefinition::new(ROL, Legacy, 0xd2, &[rm(Byte, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(0),
+    // D3 /0, ROL r/m16, CL
+    InstructionDefinition::new(ROL, Legacy, 0xd3, &[rm(Word, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(0),
+    // D3 /0, ROL r/m32, CL
+    InstructionDefinition::new(ROL, Legacy, 0xd3, &[rm(Dword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(0),
+    // REX.W + D3 /0, ROL r/m64, CL
+    InstructionDefinition::new(ROL, Legacy, 0xd3, &[rm(Qword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(0),
+    // C0 /0 ib, ROL r/m8, imm8
+    InstructionDefinition::new(ROL, Legacy, 0xc0, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(0),
+    // C1 /0 ib, ROL r/m16, imm8
+    InstructionDefinition::new(ROL, Legacy, 0xc1, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(0),
+    // C1 /0 ib, ROL r/m32, imm8
+    InstructionDefinition::new(ROL, Legacy, 0xc1, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(0),
+    // REX.W + C1 /0 ib, ROL r/m64, imm8
+    InstructionDefinition::new(ROL, Legacy, 0xc1, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(0),
+    // D0 /1, ROR r/m8, 1
+    InstructionDefinition::new(ROR, Legacy, 0xd0, &[rm(Byte, ReadWrite), one()])
+        .with_opcode_extension(1),
+    // D1 /1, ROR r/m16, 1
+    InstructionDefinition::new(ROR, Legacy, 0xd1, &[rm(Word, ReadWrite), one()])
+        .with_opcode_extension(1),
+    // D1 /1, ROR r/m32, 1
+    InstructionDefinition::new(ROR, Legacy, 0xd1, &[rm(Dword, ReadWrite), one()])
+        .with_opcode_extension(1),
+    // REX.W + D1 /1, ROR r/m64, 1
+    InstructionDefinition::new(ROR, Legacy, 0xd1, &[rm(Qword, ReadWrite), one()])
+        .with_opcode_extension(1),
+    // D2 /1, ROR r/m8, CL
+    InstructionDefinition::new(ROR, Legacy, 0xd2, &[rm(Byte, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(1),
+    // D3 /1, ROR r/m16, CL
+    InstructionDefinition::new(ROR, Legacy, 0xd3, &[rm(Word, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(1),
+    // D3 /1, ROR r/m32, CL
+    InstructionDefinition::new(ROR, Legacy, 0xd3, &[rm(Dword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(1),
+    // REX.W + D3 /1, ROR r/m64, CL
+    InstructionDefinition::new(ROR, Legacy, 0xd3, &[rm(Qword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(1),
+    // C0 /1 ib, ROR r/m8, imm8
+    InstructionDefinition::new(ROR, Legacy, 0xc0, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(1),
+    // C1 /1 ib, ROR r/m16, imm8
+    InstructionDefinition::new(ROR, Legacy, 0xc1, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(1),
+    // C1 /1 ib, ROR r/m32, imm8
+    InstructionDefinition::new(ROR, Legacy, 0xc1, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(1),
+    // REX.W + C1 /1 ib, ROR r/m64, imm8
+    InstructionDefinition::new(ROR, Legacy, 0xc1, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(1),
+    // D0 /2, RCL r/m8, 1
+    InstructionDefinition::new(RCL, Legacy, 0xd0, &[rm(Byte, ReadWrite), one()])
+        .with_opcode_extension(2),
+    // D1 /2, RCL r/m16, 1
+    InstructionDefinition::new(RCL, Legacy, 0xd1, &[rm(Word, ReadWrite), one()])
+        .with_opcode_extension(2),
+    // D1 /2, RCL r/m32, 1
+    InstructionDefinition::new(RCL, Legacy, 0xd1, &[rm(Dword, ReadWrite), one()])
+        .with_opcode_extension(2),
+    // REX.W + D1 /2, RCL r/m64, 1
+    InstructionDefinition::new(RCL, Legacy, 0xd1, &[rm(Qword, ReadWrite), one()])
+        .with_opcode_extension(2),
+    // D2 /2, RCL r/m8, CL
+    InstructionDefinition::new(RCL, Legacy, 0xd2, &[rm(Byte, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(2),
+    // D3 /2, RCL r/m16, CL
+    InstructionDefinition::new(RCL, Legacy, 0xd3, &[rm(Word, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(2),
+    // D3 /2, RCL r/m32, CL
+    InstructionDefinition::new(RCL, Legacy, 0xd3, &[rm(Dword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(2),
+    // REX.W + D3 /2, RCL r/m64, CL
+    InstructionDefinition::new(RCL, Legacy, 0xd3, &[rm(Qword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(2),
+    // C0 /2 ib, RCL r/m8, imm8
+    InstructionDefinition::new(RCL, Legacy, 0xc0, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(2),
+    // C1 /2 ib, RCL r/m16, imm8
+    InstructionDefinition::new(RCL, Legacy, 0xc1, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(2),
+    // C1 /2 ib, RCL r/m32, imm8
+    InstructionDefinition::new(RCL, Legacy, 0xc1, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(2),
+    // REX.W + C1 /2 ib, RCL r/m64, imm8
+    InstructionDefinition::new(RCL, Legacy, 0xc1, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(2),
+    // D0 /3, RCR r/m8, 1
+    InstructionDefinition::new(RCR, Legacy, 0xd0, &[rm(Byte, ReadWrite), one()])
+        .with_opcode_extension(3),
+    // D1 /3, RCR r/m16, 1
+    InstructionDefinition::new(RCR, Legacy, 0xd1, &[rm(Word, ReadWrite), one()])
+        .with_opcode_extension(3),
+    // D1 /3, RCR r/m32, 1
+    InstructionDefinition::new(RCR, Legacy, 0xd1, &[rm(Dword, ReadWrite), one()])
+        .with_opcode_extension(3),
+    // REX.W + D1 /3, RCR r/m64, 1
+    InstructionDefinition::new(RCR, Legacy, 0xd1, &[rm(Qword, ReadWrite), one()])
+        .with_opcode_extension(3),
+    // D2 /3, RCR r/m8, CL
+    InstructionDefinition::new(RCR, Legacy, 0xd2, &[rm(Byte, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(3),
+    // D3 /3, RCR r/m16, CL
+    InstructionDefinition::new(RCR, Legacy, 0xd3, &[rm(Word, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(3),
+    // D3 /3, RCR r/m32, CL
+    InstructionDefinition::new(RCR, Legacy, 0xd3, &[rm(Dword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(3),
+    // REX.W + D3 /3, RCR r/m64, CL
+    InstructionDefinition::new(RCR, Legacy, 0xd3, &[rm(Qword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(3),
+    // C0 /3 ib, RCR r/m8, imm8
+    InstructionDefinition::new(RCR, Legacy, 0xc0, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(3),
+    // C1 /3 ib, RCR r/m16, imm8
+    InstructionDefinition::new(RCR, Legacy, 0xc1, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(3),
+    // C1 /3 ib, RCR r/m32, imm8
+    InstructionDefinition::new(RCR, Legacy, 0xc1, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(3),
+    // REX.W + C1 /3 ib, RCR r/m64, imm8
+    InstructionDefinition::new(RCR, Legacy, 0xc1, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(3),
+    // SAL/SAR/SHL/SHR -- Shift
+    //
+    // D0 /4, SHL r/m8, 1
+    InstructionDefinition::new(SHL, Legacy, 0xd0, &[rm(Byte, ReadWrite), one()])
+        .with_opcode_extension(4),
+    // D1 /4, SHL r/m16, 1
+    InstructionDefinition::new(SHL, Legacy, 0xd1, &[rm(Word, ReadWrite), one()])
+        .with_opcode_extension(4),
+    // D1 /4, SHL r/m32, 1
+    InstructionDefinition::new(SHL, Legacy, 0xd1, &[rm(Dword, ReadWrite), one()])
+        .with_opcode_extension(4),
+    // REX.W + D1 /4, SHL r/m64, 1
+    InstructionDefinition::new(SHL, Legacy, 0xd1, &[rm(Qword, ReadWrite), one()])
+        .with_opcode_extension(4),
+    // D2 /4, SHL r/m8, CL
+    InstructionDefinition::new(SHL, Legacy, 0xd2, &[rm(Byte, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(4),
+    // D3 /4, SHL r/m16, CL
+    InstructionDefinition::new(SHL, Legacy, 0xd3, &[rm(Word, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(4),
+    // D3 /4, SHL r/m32, CL
+    InstructionDefinition::new(SHL, Legacy, 0xd3, &[rm(Dword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(4),
+    // REX.W + D3 /4, SHL r/m64, CL
+    InstructionDefinition::new(SHL, Legacy, 0xd3, &[rm(Qword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(4),
+    // C0 /4 ib, SHL r/m8, imm8
+    InstructionDefinition::new(SHL, Legacy, 0xc0, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(4),
+    // C1 /4 ib, SHL r/m16, imm8
+    InstructionDefinition::new(SHL, Legacy, 0xc1, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(4),
+    // C1 /4 ib, SHL r/m32, imm8
+    InstructionDefinition::new(SHL, Legacy, 0xc1, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(4),
+    // REX.W + C1 /4 ib, SHL r/m64, imm8
+    InstructionDefinition::new(SHL, Legacy, 0xc1, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(4),
+    // D0 /5, SHR r/m8, 1
+    InstructionDefinition::new(SHR, Legacy, 0xd0, &[rm(Byte, ReadWrite), one()])
+        .with_opcode_extension(5),
+    // D1 /5, SHR r/m16, 1
+    InstructionDefinition::new(SHR, Legacy, 0xd1, &[rm(Word, ReadWrite), one()])
+        .with_opcode_extension(5),
+    // D1 /5, SHR r/m32, 1
+    InstructionDefinition::new(SHR, Legacy, 0xd1, &[rm(Dword, ReadWrite), one()])
+        .with_opcode_extension(5),
+    // REX.W + D1 /5, SHR r/m64, 1
+    InstructionDefinition::new(SHR, Legacy, 0xd1, &[rm(Qword, ReadWrite), one()])
+        .with_opcode_extension(5),
+    // D2 /5, SHR r/m8, CL
+    InstructionDefinition::new(SHR, Legacy, 0xd2, &[rm(Byte, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(5),
+    // D3 /5, SHR r/m16, CL
+    InstructionDefinition::new(SHR, Legacy, 0xd3, &[rm(Word, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(5),
+    // D3 /5, SHR r/m32, CL
+    InstructionDefinition::new(SHR, Legacy, 0xd3, &[rm(Dword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(5),
+    // REX.W + D3 /5, SHR r/m64, CL
+    InstructionDefinition::new(SHR, Legacy, 0xd3, &[rm(Qword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(5),
+    // C0 /5 ib, SHR r/m8, imm8
+    InstructionDefinition::new(SHR, Legacy, 0xc0, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5),
+    // C1 /5 ib, SHR r/m16, imm8
+    InstructionDefinition::new(SHR, Legacy, 0xc1, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5),
+    // C1 /5 ib, SHR r/m32, imm8
+    InstructionDefinition::new(SHR, Legacy, 0xc1, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5),
+    // REX.W + C1 /5 ib, SHR r/m64, imm8
+    InstructionDefinition::new(SHR, Legacy, 0xc1, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5),
+    // D0 /7, SAR r/m8, 1
+    InstructionDefinition::new(SAR, Legacy, 0xd0, &[rm(Byte, ReadWrite), one()])
+        .with_opcode_extension(7),
+    // D1 /7, SAR r/m16, 1
+    InstructionDefinition::new(SAR, Legacy, 0xd1, &[rm(Word, ReadWrite), one()])
+        .with_opcode_extension(7),
+    // D1 /7, SAR r/m32, 1
+    InstructionDefinition::new(SAR, Legacy, 0xd1, &[rm(Dword, ReadWrite), one()])
+        .with_opcode_extension(7),
+    // REX.W + D1 /7, SAR r/m64, 1
+    InstructionDefinition::new(SAR, Legacy, 0xd1, &[rm(Qword, ReadWrite), one()])
+        .with_opcode_extension(7),
+    // D2 /7, SAR r/m8, CL
+    InstructionDefinition::new(SAR, Legacy, 0xd2, &[rm(Byte, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(7),
+    // D3 /7, SAR r/m16, CL
+    InstructionDefinition::new(SAR, Legacy, 0xd3, &[rm(Word, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(7),
+    // D3 /7, SAR r/m32, CL
+    InstructionDefinition::new(SAR, Legacy, 0xd3, &[rm(Dword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(7),
+    // REX.W + D3 /7, SAR r/m64, CL
+    InstructionDefinition::new(SAR, Legacy, 0xd3, &[rm(Qword, ReadWrite), fixed(CL, Read)])
+        .with_opcode_extension(7),
+    // C0 /7 ib, SAR r/m8, imm8
+    InstructionDefinition::new(SAR, Legacy, 0xc0, &[rm(Byte, ReadWrite), imm(Byte)])
+        .with_opcode_extension(7),
+    // C1 /7 ib, SAR r/m16, imm8
+    InstructionDefinition::new(SAR, Legacy, 0xc1, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(7),
+    // C1 /7 ib, SAR r/m32, imm8
+    InstructionDefinition::new(SAR, Legacy, 0xc1, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(7),
+    // REX.W + C1 /7 ib, SAR r/m64, imm8
+    InstructionDefinition::new(SAR, Legacy, 0xc1, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(7),
+    // NOT -- One's Complement Negation
+    //
+    // F6 /2, NOT r/m8
+    InstructionDefinition::new(NOT, Legacy, 0xf6, &[rm(Byte, ReadWrite)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // F7 /2, NOT r/m16
+    InstructionDefinition::new(NOT, Legacy, 0xf7, &[rm(Word, ReadWrite)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // F7 /2, NOT r/m32
+    InstructionDefinition::new(NOT, Legacy, 0xf7, &[rm(Dword, ReadWrite)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + F7 /2, NOT r/m64
+    InstructionDefinition::new(NOT, Legacy, 0xf7, &[rm(Qword, ReadWrite)])
+        .with_opcode_extension(2)
+        .with_allowed_prefixes(&[LOCK]),
+    // NEG -- Two's Complement Negation
+    //
+    // F6 /3, NEG r/m8
+    InstructionDefinition::new(NEG, Legacy, 0xf6, &[rm(Byte, ReadWrite)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // F7 /3, NEG r/m16
+    InstructionDefinition::new(NEG, Legacy, 0xf7, &[rm(Word, ReadWrite)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // F7 /3, NEG r/m32
+    InstructionDefinition::new(NEG, Legacy, 0xf7, &[rm(Dword, ReadWrite)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + F7 /3, NEG r/m64
+    InstructionDefinition::new(NEG, Legacy, 0xf7, &[rm(Qword, ReadWrite)])
+        .with_opcode_extension(3)
+        .with_allowed_prefixes(&[LOCK]),
+    // MUL -- Unsigned Multiply
+    //
+    // F6 /4, MUL r/m8
+    InstructionDefinition::new(MUL, Legacy, 0xf6, &[rm(Byte, Read)])
+        .with_opcode_extension(4)
+        .with_implicit_operands(IMPLICIT_MUL_8),
+    // F7 /4, MUL r/m16
+    InstructionDefinition::new(MUL, Legacy, 0xf7, &[rm(Word, Read)])
+        .with_opcode_extension(4)
+        .with_implicit_operands(IMPLICIT_MUL_16),
+    // F7 /4, MUL r/m32
+    InstructionDefinition::new(MUL, Legacy, 0xf7, &[rm(Dword, Read)])
+        .with_opcode_extension(4)
+        .with_implicit_operands(IMPLICIT_MUL_32),
+    // REX.W + F7 /4, MUL r/m64
+    InstructionDefinition::new(MUL, Legacy, 0xf7, &[rm(Qword, Read)])
+        .with_opcode_extension(4)
+        .with_implicit_operands(IMPLICIT_MUL_64),
+    // IMUL -- Signed Multiply
+    //
+    // F6 /5, IMUL r/m8
+    InstructionDefinition::new(IMUL, Legacy, 0xf6, &[rm(Byte, Read)])
+        .with_opcode_extension(5)
+        .with_implicit_operands(IMPLICIT_MUL_8),
+    // F7 /5, IMUL r/m16
+    InstructionDefinition::new(IMUL, Legacy, 0xf7, &[rm(Word, Read)])
+        .with_opcode_extension(5)
+        .with_implicit_operands(IMPLICIT_MUL_16),
+    // F7 /5, IMUL r/m32
+    InstructionDefinition::new(IMUL, Legacy, 0xf7, &[rm(Dword, Read)])
+        .with_opcode_extension(5)
+        .with_implicit_operands(IMPLICIT_MUL_32),
+    // REX.W + F7 /5, IMUL r/m64
+    InstructionDefinition::new(IMUL, Legacy, 0xf7, &[rm(Qword, Read)])
+        .with_opcode_extension(5)
+        .with_implicit_operands(IMPLICIT_MUL_64),
+    // 0F AF /r, IMUL r16, r/m16
+    InstructionDefinition::new(IMUL, Map0F, 0xaf, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F AF /r, IMUL r32, r/m32
+    InstructionDefinition::new(IMUL, Map0F, 0xaf, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F AF /r, IMUL r64, r/m64
+    InstructionDefinition::new(IMUL, Map0F, 0xaf, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    // 6B /r ib, IMUL r16, r/m16, imm8
+    InstructionDefinition::new(IMUL, Legacy, 0x6b, &[reg(Word, Write), rm(Word, Read), simm(Byte)]),
+    // 6B /r ib, IMUL r32, r/m32, imm8
+    InstructionDefinition::new(
+        IMUL,
+        Legacy,
+        0x6b,
+        &[reg(Dword, Write), rm(Dword, Read), simm(Byte)],
+    ),
+    // REX.W + 6B /r ib, IMUL r64, r/m64, imm8
+    InstructionDefinition::new(
+        IMUL,
+        Legacy,
+        0x6b,
+        &[reg(Qword, Write), rm(Qword, Read), simm(Byte)],
+    ),
+    // 69 /r iw, IMUL r16, r/m16, imm16
+    InstructionDefinition::new(IMUL, Legacy, 0x69, &[reg(Word, Write), rm(Word, Read), imm(Word)]),
+    // 69 /r id, IMUL r32, r/m32, imm32
+    InstructionDefinition::new(
+        IMUL,
+        Legacy,
+        0x69,
+        &[reg(Dword, Write), rm(Dword, Read), imm(Dword)],
+    ),
+    // REX.W + 69 /r id, IMUL r64, r/m64, imm32
+    InstructionDefinition::new(
+        IMUL,
+        Legacy,
+        0x69,
+        &[reg(Qword, Write), rm(Qword, Read), simm(Dword)],
+    ),
+    // DIV -- Unsigned Divide
+    //
+    // F6 /6, DIV r/m8
+    InstructionDefinition::new(DIV, Legacy, 0xf6, &[rm(Byte, Read)])
+        .with_opcode_extension(6)
+        .with_implicit_operands(IMPLICIT_DIV_8),
+    // F7 /6, DIV r/m16
+    InstructionDefinition::new(DIV, Legacy, 0xf7, &[rm(Word, Read)])
+        .with_opcode_extension(6)
+        .with_implicit_operands(IMPLICIT_DIV_16),
+    // F7 /6, DIV r/m32
+    InstructionDefinition::new(DIV, Legacy, 0xf7, &[rm(Dword, Read)])
+        .with_opcode_extension(6)
+        .with_implicit_operands(IMPLICIT_DIV_32),
+    // REX.W + F7 /6, DIV r/m64
+    InstructionDefinition::new(DIV, Legacy, 0xf7, &[rm(Qword, Read)])
+        .with_opcode_extension(6)
+        .with_implicit_operands(IMPLICIT_DIV_64),
+    // IDIV -- Signed Divide
+    //
+    // F6 /7, IDIV r/m8
+    InstructionDefinition::new(IDIV, Legacy, 0xf6, &[rm(Byte, Read)])
+        .with_opcode_extension(7)
+        .with_implicit_operands(IMPLICIT_DIV_8),
+    // F7 /7, IDIV r/m16
+    InstructionDefinition::new(IDIV, Legacy, 0xf7, &[rm(Word, Read)])
+        .with_opcode_extension(7)
+        .with_implicit_operands(IMPLICIT_DIV_16),
+    // F7 /7, IDIV r/m32
+    InstructionDefinition::new(IDIV, Legacy, 0xf7, &[rm(Dword, Read)])
+        .with_opcode_extension(7)
+        .with_implicit_operands(IMPLICIT_DIV_32),
+    // REX.W + F7 /7, IDIV r/m64
+    InstructionDefinition::new(IDIV, Legacy, 0xf7, &[rm(Qword, Read)])
+        .with_opcode_extension(7)
+        .with_implicit_operands(IMPLICIT_DIV_64),
+    // INC -- Increment by 1
+    //
+    // FE /0, INC r/m8
+    InstructionDefinition::new(INC, Legacy, 0xfe, &[rm(Byte, ReadWrite)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // FF /0, INC r/m16
+    InstructionDefinition::new(INC, Legacy, 0xff, &[rm(Word, ReadWrite)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // FF /0, INC r/m32
+    InstructionDefinition::new(INC, Legacy, 0xff, &[rm(Dword, ReadWrite)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + FF /0, INC r/m64
+    InstructionDefinition::new(INC, Legacy, 0xff, &[rm(Qword, ReadWrite)])
+        .with_opcode_extension(0)
+        .with_allowed_prefixes(&[LOCK]),
+    // DEC -- Decrement by 1
+    //
+    // FE /1, DEC r/m8
+    InstructionDefinition::new(DEC, Legacy, 0xfe, &[rm(Byte, ReadWrite)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // FF /1, DEC r/m16
+    InstructionDefinition::new(DEC, Legacy, 0xff, &[rm(Word, ReadWrite)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // FF /1, DEC r/m32
+    InstructionDefinition::new(DEC, Legacy, 0xff, &[rm(Dword, ReadWrite)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + FF /1, DEC r/m64
+    InstructionDefinition::new(DEC, Legacy, 0xff, &[rm(Qword, ReadWrite)])
+        .with_opcode_extension(1)
+        .with_allowed_prefixes(&[LOCK]),
+    // CBW/CWDE/CDQE -- Convert Byte to Word/Convert Word to Doubleword/Convert Doubleword to Quadword
+    //
+    // 98, CBW
+    InstructionDefinition::new(CBW, Legacy, 0x98, &[])
+        .with_operand_size(Word)
+        .with_implicit_operands(IMPLICIT_CBW),
+    // 98, CWDE
+    InstructionDefinition::new(CWDE, Legacy, 0x98, &[])
+        .with_operand_size(Dword)
+        .with_implicit_operands(IMPLICIT_CWDE),
+    // REX.W + 98, CDQE
+    InstructionDefinition::new(CDQE, Legacy, 0x98, &[])
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_CDQE),
+    // CWD/CDQ/CQO -- Convert Word to Doubleword/Convert Doubleword to Quadword
+    //
+    // 99, CWD
+    InstructionDefinition::new(CWD, Legacy, 0x99, &[])
+        .with_operand_size(Word)
+        .with_implicit_operands(IMPLICIT_CWD),
+    // 99, CDQ
+    InstructionDefinition::new(CDQ, Legacy, 0x99, &[])
+        .with_operand_size(Dword)
+        .with_implicit_operands(IMPLICIT_CDQ),
+    // REX.W + 99, CQO
+    InstructionDefinition::new(CQO, Legacy, 0x99, &[])
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_CQO),
+    // XCHG -- Exchange Register/Memory With Register
+    //
+    // XCHG with a memory operand is always atomic, the LOCK prefix is allowed but not needed.
+    //
+    // 86 /r, XCHG r/m8, r8
+    InstructionDefinition::new(XCHG, Legacy, 0x86, &[rm(Byte, ReadWrite), reg(Byte, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 86 /r, XCHG r8, r/m8
+    InstructionDefinition::new(XCHG, Legacy, 0x86, &[reg(Byte, ReadWrite), rm(Byte, ReadWrite)]),
+    // 87 /r, XCHG r/m16, r16
+    InstructionDefinition::new(XCHG, Legacy, 0x87, &[rm(Word, ReadWrite), reg(Word, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 87 /r, XCHG r16, r/m16
+    InstructionDefinition::new(XCHG, Legacy, 0x87, &[reg(Word, ReadWrite), rm(Word, ReadWrite)]),
+    // 87 /r, XCHG r/m32, r32
+    InstructionDefinition::new(XCHG, Legacy, 0x87, &[rm(Dword, ReadWrite), reg(Dword, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 87 /r, XCHG r32, r/m32
+    InstructionDefinition::new(XCHG, Legacy, 0x87, &[reg(Dword, ReadWrite), rm(Dword, ReadWrite)]),
+    // REX.W + 87 /r, XCHG r/m64, r64
+    InstructionDefinition::new(XCHG, Legacy, 0x87, &[rm(Qword, ReadWrite), reg(Qword, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 87 /r, XCHG r64, r/m64
+    InstructionDefinition::new(XCHG, Legacy, 0x87, &[reg(Qword, ReadWrite), rm(Qword, ReadWrite)]),
+    // 90+rw, XCHG AX, r16
+    InstructionDefinition::new(
+        XCHG,
+        Legacy,
+        0x90,
+        &[fixed(AX, ReadWrite), opcode_reg(Word, ReadWrite)],
+    ),
+    // 90+rw, XCHG r16, AX
+    InstructionDefinition::new(
+        XCHG,
+        Legacy,
+        0x90,
+        &[opcode_reg(Word, ReadWrite), fixed(AX, ReadWrite)],
+    ),
+    // 90+rd, XCHG EAX, r32
+    InstructionDefinition::new(
+        XCHG,
+        Legacy,
+        0x90,
+        &[fixed(EAX, ReadWrite), opcode_reg(Dword, ReadWrite)],
+    ),
+    // 90+rd, XCHG r32, EAX
+    InstructionDefinition::new(
+        XCHG,
+        Legacy,
+        0x90,
+        &[opcode_reg(Dword, ReadWrite), fixed(EAX, ReadWrite)],
+    ),
+    // REX.W + 90+rd, XCHG RAX, r64
+    InstructionDefinition::new(
+        XCHG,
+        Legacy,
+        0x90,
+        &[fixed(RAX, ReadWrite), opcode_reg(Qword, ReadWrite)],
+    ),
+    // REX.W + 90+rd, XCHG r64, RAX
+    InstructionDefinition::new(
+        XCHG,
+        Legacy,
+        0x90,
+        &[opcode_reg(Qword, ReadWrite), fixed(RAX, ReadWrite)],
+    ),
+    //
+    // CMPXCHG -- Compare and Exchange
+    //
+    // 0F B0 /r, CMPXCHG r/m8, r8
+    InstructionDefinition::new(CMPXCHG, Map0F, 0xb0, &[rm(Byte, ReadWrite), reg(Byte, Read)])
+        .with_implicit_operands(IMPLICIT_CMPXCHG_8)
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F B1 /r, CMPXCHG r/m16, r16
+    InstructionDefinition::new(CMPXCHG, Map0F, 0xb1, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_implicit_operands(IMPLICIT_CMPXCHG_16)
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F B1 /r, CMPXCHG r/m32, r32
+    InstructionDefinition::new(CMPXCHG, Map0F, 0xb1, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_implicit_operands(IMPLICIT_CMPXCHG_32)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F B1 /r, CMPXCHG r/m64, r64
+    InstructionDefinition::new(CMPXCHG, Map0F, 0xb1, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_implicit_operands(IMPLICIT_CMPXCHG_64)
+        .with_allowed_prefixes(&[LOCK]),
+    //
+    // CMPXCHG8B/CMPXCHG16B -- Compare and Exchange Bytes
+    //
+    // REX.W + 0F C7 /1, CMPXCHG16B m128
+    InstructionDefinition::new(CMPXCHG16B, Map0F, 0xc7, &[m128(ReadWrite)])
+        .with_opcode_extension(1)
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_CMPXCHG16B)
+        .with_allowed_prefixes(&[LOCK]),
+    //
+    // XADD -- Exchange and Add
+    //
+    // 0F C0 /r, XADD r/m8, r8
+    InstructionDefinition::new(XADD, Map0F, 0xc0, &[rm(Byte, ReadWrite), reg(Byte, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F C1 /r, XADD r/m16, r16
+    InstructionDefinition::new(XADD, Map0F, 0xc1, &[rm(Word, ReadWrite), reg(Word, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F C1 /r, XADD r/m32, r32
+    InstructionDefinition::new(XADD, Map0F, 0xc1, &[rm(Dword, ReadWrite), reg(Dword, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F C1 /r, XADD r/m64, r64
+    InstructionDefinition::new(XADD, Map0F, 0xc1, &[rm(Qword, ReadWrite), reg(Qword, ReadWrite)])
+        .with_allowed_prefixes(&[LOCK]),
+    //
+    // BT -- Bit Test
+    //
+    // 0F A3 /r, BT r/m16, r16
+    InstructionDefinition::new(BT, Map0F, 0xa3, &[rm(Word, Read), reg(Word, Read)]),
+    // 0F A3 /r, BT r/m32, r32
+    InstructionDefinition::new(BT, Map0F, 0xa3, &[rm(Dword, Read), reg(Dword, Read)]),
+    // REX.W + 0F A3 /r, BT r/m64, r64
+    InstructionDefinition::new(BT, Map0F, 0xa3, &[rm(Qword, Read), reg(Qword, Read)]),
+    // 0F BA /4 ib, BT r/m16, imm8
+    InstructionDefinition::new(BT, Map0F, 0xba, &[rm(Word, Read), imm(Byte)])
+        .with_opcode_extension(4),
+    // 0F BA /4 ib, BT r/m32, imm8
+    InstructionDefinition::new(BT, Map0F, 0xba, &[rm(Dword, Read), imm(Byte)])
+        .with_opcode_extension(4),
+    // REX.W + 0F BA /4 ib, BT r/m64, imm8
+    InstructionDefinition::new(BT, Map0F, 0xba, &[rm(Qword, Read), imm(Byte)])
+        .with_opcode_extension(4),
+    //
+    // BTS -- Bit Test and Set
+    //
+    // 0F AB /r, BTS r/m16, r16
+    InstructionDefinition::new(BTS, Map0F, 0xab, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F AB /r, BTS r/m32, r32
+    InstructionDefinition::new(BTS, Map0F, 0xab, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F AB /r, BTS r/m64, r64
+    InstructionDefinition::new(BTS, Map0F, 0xab, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F BA /5 ib, BTS r/m16, imm8
+    InstructionDefinition::new(BTS, Map0F, 0xba, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F BA /5 ib, BTS r/m32, imm8
+    InstructionDefinition::new(BTS, Map0F, 0xba, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F BA /5 ib, BTS r/m64, imm8
+    InstructionDefinition::new(BTS, Map0F, 0xba, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(5)
+        .with_allowed_prefixes(&[LOCK]),
+    //
+    // BTR -- Bit Test and Reset
+    //
+    // 0F B3 /r, BTR r/m16, r16
+    InstructionDefinition::new(BTR, Map0F, 0xb3, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F B3 /r, BTR r/m32, r32
+    InstructionDefinition::new(BTR, Map0F, 0xb3, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F B3 /r, BTR r/m64, r64
+    InstructionDefinition::new(BTR, Map0F, 0xb3, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F BA /6 ib, BTR r/m16, imm8
+    InstructionDefinition::new(BTR, Map0F, 0xba, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F BA /6 ib, BTR r/m32, imm8
+    InstructionDefinition::new(BTR, Map0F, 0xba, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F BA /6 ib, BTR r/m64, imm8
+    InstructionDefinition::new(BTR, Map0F, 0xba, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(6)
+        .with_allowed_prefixes(&[LOCK]),
+    //
+    // BTC -- Bit Test and Complement
+    //
+    // 0F BB /r, BTC r/m16, r16
+    InstructionDefinition::new(BTC, Map0F, 0xbb, &[rm(Word, ReadWrite), reg(Word, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F BB /r, BTC r/m32, r32
+    InstructionDefinition::new(BTC, Map0F, 0xbb, &[rm(Dword, ReadWrite), reg(Dword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F BB /r, BTC r/m64, r64
+    InstructionDefinition::new(BTC, Map0F, 0xbb, &[rm(Qword, ReadWrite), reg(Qword, Read)])
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F BA /7 ib, BTC r/m16, imm8
+    InstructionDefinition::new(BTC, Map0F, 0xba, &[rm(Word, ReadWrite), imm(Byte)])
+        .with_opcode_extension(7)
+        .with_allowed_prefixes(&[LOCK]),
+    // 0F BA /7 ib, BTC r/m32, imm8
+    InstructionDefinition::new(BTC, Map0F, 0xba, &[rm(Dword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(7)
+        .with_allowed_prefixes(&[LOCK]),
+    // REX.W + 0F BA /7 ib, BTC r/m64, imm8
+    InstructionDefinition::new(BTC, Map0F, 0xba, &[rm(Qword, ReadWrite), imm(Byte)])
+        .with_opcode_extension(7)
+        .with_allowed_prefixes(&[LOCK]),
+    //
+    // BSF -- Bit Scan Forward
+    //
+    // 0F BC /r, BSF r16, r/m16
+    InstructionDefinition::new(BSF, Map0F, 0xbc, &[reg(Word, Write), rm(Word, Read)]),
+    // 0F BC /r, BSF r32, r/m32
+    InstructionDefinition::new(BSF, Map0F, 0xbc, &[reg(Dword, Write), rm(Dword, Read)]),
+    // REX.W + 0F BC /r, BSF r64, r/m64
+    InstructionDefinition::new(BSF, Map0F, 0xbc, &[reg(Qword, Write), rm(Qword, Read)]),
+    //
+    // BSR -- Bit Scan Reverse
+    //
+    // 0F BD /r, BSR r16, r/m16
+    InstructionDefinition::new(BSR, Map0F, 0xbd, &[reg(Word, Write), rm(Word, Read)]),
+    // 0F BD /r, BSR r32, r/m32
+    InstructionDefinition::new(BSR, Map0F, 0xbd, &[reg(Dword, Write), rm(Dword, Read)]),
+    // REX.W + 0F BD /r, BSR r64, r/m64
+    InstructionDefinition::new(BSR, Map0F, 0xbd, &[reg(Qword, Write), rm(Qword, Read)]),
+    //
+    // POPCNT -- Return the Count of Number of Bits Set to 1
+    //
+    // F3 0F B8 /r, POPCNT r16, r/m16
+    InstructionDefinition::new(POPCNT, Map0F, 0xb8, &[reg(Word, Write), rm(Word, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 0F B8 /r, POPCNT r32, r/m32
+    InstructionDefinition::new(POPCNT, Map0F, 0xb8, &[reg(Dword, Write), rm(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 REX.W + 0F B8 /r, POPCNT r64, r/m64
+    InstructionDefinition::new(POPCNT, Map0F, 0xb8, &[reg(Qword, Write), rm(Qword, Read)])
+        .with_mandatory_prefix(0xf3),
+    //
+    // LZCNT -- Count the Number of Leading Zero Bits
+    //
+    // F3 0F BD /r, LZCNT r16, r/m16
+    InstructionDefinition::new(LZCNT, Map0F, 0xbd, &[reg(Word, Write), rm(Word, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 0F BD /r, LZCNT r32, r/m32
+    InstructionDefinition::new(LZCNT, Map0F, 0xbd, &[reg(Dword, Write), rm(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 REX.W + 0F BD /r, LZCNT r64, r/m64
+    InstructionDefinition::new(LZCNT, Map0F, 0xbd, &[reg(Qword, Write), rm(Qword, Read)])
+        .with_mandatory_prefix(0xf3),
+    //
+    // TZCNT -- Count the Number of Trailing Zero Bits
+    //
+    // F3 0F BC /r, TZCNT r16, r/m16
+    InstructionDefinition::new(TZCNT, Map0F, 0xbc, &[reg(Word, Write), rm(Word, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 0F BC /r, TZCNT r32, r/m32
+    InstructionDefinition::new(TZCNT, Map0F, 0xbc, &[reg(Dword, Write), rm(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 REX.W + 0F BC /r, TZCNT r64, r/m64
+    InstructionDefinition::new(TZCNT, Map0F, 0xbc, &[reg(Qword, Write), rm(Qword, Read)])
+        .with_mandatory_prefix(0xf3),
+    //
+    // BSWAP -- Byte Swap
+    //
+    // 0F C8+rd, BSWAP r32
+    InstructionDefinition::new(BSWAP, Map0F, 0xc8, &[opcode_reg(Dword, ReadWrite)]),
+    // REX.W + 0F C8+rd, BSWAP r64
+    InstructionDefinition::new(BSWAP, Map0F, 0xc8, &[opcode_reg(Qword, ReadWrite)]),
+    //
+    // MOVBE -- Move Data After Swapping Bytes
+    //
+    // 0F 38 F0 /r, MOVBE r16, m16
+    InstructionDefinition::new(MOVBE, Map0F38, 0xf0, &[reg(Word, Write), mem(Word, Read)]),
+    // 0F 38 F0 /r, MOVBE r32, m32
+    InstructionDefinition::new(MOVBE, Map0F38, 0xf0, &[reg(Dword, Write), mem(Dword, Read)]),
+    // REX.W + 0F 38 F0 /r, MOVBE r64, m64
+    InstructionDefinition::new(MOVBE, Map0F38, 0xf0, &[reg(Qword, Write), mem(Qword, Read)]),
+    // 0F 38 F1 /r, MOVBE m16, r16
+    InstructionDefinition::new(MOVBE, Map0F38, 0xf1, &[mem(Word, Write), reg(Word, Read)]),
+    // 0F 38 F1 /r, MOVBE m32, r32
+    InstructionDefinition::new(MOVBE, Map0F38, 0xf1, &[mem(Dword, Write), reg(Dword, Read)]),
+    // REX.W + 0F 38 F1 /r, MOVBE m64, r64
+    InstructionDefinition::new(MOVBE, Map0F38, 0xf1, &[mem(Qword, Write), reg(Qword, Read)]),
+    //
+    // BMI1/BMI2 -- Bit Manipulation Instructions
+    //
+    // They are encoded with the VEX prefix and operate on the 32-bit and 64-bit
+    // general-purpose registers, VEX.W selects the operand size like REX.W.
+    //
+    // ANDN -- Logical AND NOT
+    //
+    // VEX.LZ.0F38.W0 F2 /r, ANDN r32a, r32b, r/m32
+    InstructionDefinition::new(
+        ANDN,
+        Map0F38,
+        0xf2,
+        &[reg(Dword, Write), vex_reg(Dword, Read), rm(Dword, Read)],
+    )
+    .with_vex(),
+    // VEX.LZ.0F38.W1 F2 /r, ANDN r64a, r64b, r/m64
+    InstructionDefinition::new(
+        ANDN,
+        Map0F38,
+        0xf2,
+        &[reg(Qword, Write), vex_reg(Qword, Read), rm(Qword, Read)],
+    )
+    .with_vex(),
+    //
+    // BEXTR -- Bit Field Extract
+    //
+    // VEX.LZ.0F38.W0 F7 /r, BEXTR r32a, r/m32, r32b
+    InstructionDefinition::new(
+        BEXTR,
+        Map0F38,
+        0xf7,
+        &[reg(Dword, Write), rm(Dword, Read), vex_reg(Dword, Read)],
+    )
+    .with_vex(),
+    // VEX.LZ.0F38.W1 F7 /r, BEXTR r64a, r/m64, r64b
+    InstructionDefinition::new(
+        BEXTR,
+        Map0F38,
+        0xf7,
+        &[reg(Qword, Write), rm(Qword, Read), vex_reg(Qword, Read)],
+    )
+    .with_vex(),
+    //
+    // BLSI -- Extract Lowest Set Isolated Bit
+    //
+    // VEX.LZ.0F38.W0 F3 /3, BLSI r32, r/m32
+    InstructionDefinition::new(BLSI, Map0F38, 0xf3, &[vex_reg(Dword, Write), rm(Dword, Read)])
+        .with_opcode_extension(3)
+        .with_vex(),
+    // VEX.LZ.0F38.W1 F3 /3, BLSI r64, r/m64
+    InstructionDefinition::new(BLSI, Map0F38, 0xf3, &[vex_reg(Qword, Write), rm(Qword, Read)])
+        .with_opcode_extension(3)
+        .with_vex(),
+    //
+    // BLSMSK -- Get Mask Up to Lowest Set Bit
+    //
+    // VEX.LZ.0F38.W0 F3 /2, BLSMSK r32, r/m32
+    InstructionDefinition::new(BLSMSK, Map0F38, 0xf3, &[vex_reg(Dword, Write), rm(Dword, Read)])
+        .with_opcode_extension(2)
+        .with_vex(),
+    // VEX.LZ.0F38.W1 F3 /2, BLSMSK r64, r/m64
+    InstructionDefinition::new(BLSMSK, Map0F38, 0xf3, &[vex_reg(Qword, Write), rm(Qword, Read)])
+        .with_opcode_extension(2)
+        .with_vex(),
+    //
+    // BLSR -- Reset Lowest Set Bit
+    //
+    // VEX.LZ.0F38.W0 F3 /1, BLSR r32, r/m32
+    InstructionDefinition::new(BLSR, Map0F38, 0xf3, &[vex_reg(Dword, Write), rm(Dword, Read)])
+        .with_opcode_extension(1)
+        .with_vex(),
+    // VEX.LZ.0F38.W1 F3 /1, BLSR r64, r/m64
+    InstructionDefinition::new(BLSR, Map0F38, 0xf3, &[vex_reg(Qword, Write), rm(Qword, Read)])
+        .with_opcode_extension(1)
+        .with_vex(),
+    //
+    // BZHI -- Zero High Bits Starting with Specified Bit Position
+    //
+    // VEX.LZ.0F38.W0 F5 /r, BZHI r32a, r/m32, r32b
+    InstructionDefinition::new(
+        BZHI,
+        Map0F38,
+        0xf5,
+        &[reg(Dword, Write), rm(Dword, Read), vex_reg(Dword, Read)],
+    )
+    .with_vex(),
+    // VEX.LZ.0F38.W1 F5 /r, BZHI r64a, r/m64, r64b
+    InstructionDefinition::new(
+        BZHI,
+        Map0F38,
+        0xf5,
+        &[reg(Qword, Write), rm(Qword, Read), vex_reg(Qword, Read)],
+    )
+    .with_vex(),
+    //
+    // MULX -- Unsigned Multiply Without Affecting Flags
+    //
+    // VEX.LZ.F2.0F38.W0 F6 /r, MULX r32a, r32b, r/m32 (r32a:r32b := EDX/RDX * r/m32)
+    InstructionDefinition::new(
+        MULX,
+        Map0F38,
+        0xf6,
+        &[reg(Dword, Write), vex_reg(Dword, Write), rm(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_implicit_operands(IMPLICIT_MULX_32)
+    .with_vex(),
+    // VEX.LZ.F2.0F38.W1 F6 /r, MULX r64a, r64b, r/m64 (r64a:r64b := EDX/RDX * r/m64)
+    InstructionDefinition::new(
+        MULX,
+        Map0F38,
+        0xf6,
+        &[reg(Qword, Write), vex_reg(Qword, Write), rm(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_implicit_operands(IMPLICIT_MULX_64)
+    .with_vex(),
+    //
+    // PDEP -- Parallel Bits Deposit
+    //
+    // VEX.LZ.F2.0F38.W0 F5 /r, PDEP r32a, r32b, r/m32
+    InstructionDefinition::new(
+        PDEP,
+        Map0F38,
+        0xf5,
+        &[reg(Dword, Write), vex_reg(Dword, Read), rm(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    // VEX.LZ.F2.0F38.W1 F5 /r, PDEP r64a, r64b, r/m64
+    InstructionDefinition::new(
+        PDEP,
+        Map0F38,
+        0xf5,
+        &[reg(Qword, Write), vex_reg(Qword, Read), rm(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // PEXT -- Parallel Bits Extract
+    //
+    // VEX.LZ.F3.0F38.W0 F5 /r, PEXT r32a, r32b, r/m32
+    InstructionDefinition::new(
+        PEXT,
+        Map0F38,
+        0xf5,
+        &[reg(Dword, Write), vex_reg(Dword, Read), rm(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.LZ.F3.0F38.W1 F5 /r, PEXT r64a, r64b, r/m64
+    InstructionDefinition::new(
+        PEXT,
+        Map0F38,
+        0xf5,
+        &[reg(Qword, Write), vex_reg(Qword, Read), rm(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    //
+    // RORX -- Rotate Right Logical Without Affecting Flags
+    //
+    // VEX.LZ.F2.0F3A.W0 F0 /r ib, RORX r32, r/m32, imm8
+    InstructionDefinition::new(
+        RORX,
+        Map0F3A,
+        0xf0,
+        &[reg(Dword, Write), rm(Dword, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    // VEX.LZ.F2.0F3A.W1 F0 /r ib, RORX r64, r/m64, imm8
+    InstructionDefinition::new(
+        RORX,
+        Map0F3A,
+        0xf0,
+        &[reg(Qword, Write), rm(Qword, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // SARX/SHLX/SHRX -- Shift Without Affecting Flags
+    //
+    // VEX.LZ.F3.0F38.W0 F7 /r, SARX r32a, r/m32, r32b
+    InstructionDefinition::new(
+        SARX,
+        Map0F38,
+        0xf7,
+        &[reg(Dword, Write), rm(Dword, Read), vex_reg(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.LZ.F3.0F38.W1 F7 /r, SARX r64a, r/m64, r64b
+    InstructionDefinition::new(
+        SARX,
+        Map0F38,
+        0xf7,
+        &[reg(Qword, Write), rm(Qword, Read), vex_reg(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.LZ.66.0F38.W0 F7 /r, SHLX r32a, r/m32, r32b
+    InstructionDefinition::new(
+        SHLX,
+        Map0F38,
+        0xf7,
+        &[reg(Dword, Write), rm(Dword, Read), vex_reg(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LZ.66.0F38.W1 F7 /r, SHLX r64a, r/m64, r64b
+    InstructionDefinition::new(
+        SHLX,
+        Map0F38,
+        0xf7,
+        &[reg(Qword, Write), rm(Qword, Read), vex_reg(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LZ.F2.0F38.W0 F7 /r, SHRX r32a, r/m32, r32b
+    InstructionDefinition::new(
+        SHRX,
+        Map0F38,
+        0xf7,
+        &[reg(Dword, Write), rm(Dword, Read), vex_reg(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    // VEX.LZ.F2.0F38.W1 F7 /r, SHRX r64a, r/m64, r64b
+    InstructionDefinition::new(
+        SHRX,
+        Map0F38,
+        0xf7,
+        &[reg(Qword, Write), rm(Qword, Read), vex_reg(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // RDRAND/RDSEED -- Read Random Number/Read Random Seed
+    //
+    // The destination is a register, CF is set if the value is available.
+    //
+    // NFx 0F C7 /6, RDRAND r16
+    InstructionDefinition::new(RDRAND, Map0F, 0xc7, &[rm_reg(Word, Write)])
+        .with_opcode_extension(6),
+    // NFx 0F C7 /6, RDRAND r32
+    InstructionDefinition::new(RDRAND, Map0F, 0xc7, &[rm_reg(Dword, Write)])
+        .with_opcode_extension(6),
+    // NFx REX.W + 0F C7 /6, RDRAND r64
+    InstructionDefinition::new(RDRAND, Map0F, 0xc7, &[rm_reg(Qword, Write)])
+        .with_opcode_extension(6),
+    // NFx 0F C7 /7, RDSEED r16
+    InstructionDefinition::new(RDSEED, Map0F, 0xc7, &[rm_reg(Word, Write)])
+        .with_opcode_extension(7),
+    // NFx 0F C7 /7, RDSEED r32
+    InstructionDefinition::new(RDSEED, Map0F, 0xc7, &[rm_reg(Dword, Write)])
+        .with_opcode_extension(7),
+    // NFx REX.W + 0F C7 /7, RDSEED r64
+    InstructionDefinition::new(RDSEED, Map0F, 0xc7, &[rm_reg(Qword, Write)])
+        .with_opcode_extension(7),
+    //
+    // PUSH -- Push Word, Doubleword, or Quadword Onto the Stack
+    //
+    // 50+rw, PUSH r16
+    InstructionDefinition::new(PUSH, Legacy, 0x50, &[opcode_reg(Word, Read)])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 50+rd, PUSH r64
+    InstructionDefinition::new(PUSH, Legacy, 0x50, &[opcode_reg(Qword, Read)])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // FF /6, PUSH r/m16
+    InstructionDefinition::new(PUSH, Legacy, 0xff, &[rm(Word, Read)])
+        .with_opcode_extension(6)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // FF /6, PUSH r/m64
+    InstructionDefinition::new(PUSH, Legacy, 0xff, &[rm(Qword, Read)])
+        .with_opcode_extension(6)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 6A ib, PUSH imm8 (sign-extended to 16 bits)
+    InstructionDefinition::new(PUSH, Legacy, 0x6a, &[simm(Byte)])
+        .with_operand_size(Word)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 68 iw, PUSH imm16
+    InstructionDefinition::new(PUSH, Legacy, 0x68, &[imm(Word)])
+        .with_operand_size(Word)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 6A ib, PUSH imm8 (sign-extended to 64 bits)
+    InstructionDefinition::new(PUSH, Legacy, 0x6a, &[simm(Byte)])
+        .with_operand_size(Qword)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 68 id, PUSH imm32 (sign-extended to 64 bits)
+    InstructionDefinition::new(PUSH, Legacy, 0x68, &[simm(Dword)])
+        .with_operand_size(Qword)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    //
+    // POP -- Pop a Value From the Stack
+    //
+    // 58+rw, POP r16
+    InstructionDefinition::new(POP, Legacy, 0x58, &[opcode_reg(Word, Write)])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 58+rd, POP r64
+    InstructionDefinition::new(POP, Legacy, 0x58, &[opcode_reg(Qword, Write)])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 8F /0, POP r/m16
+    InstructionDefinition::new(POP, Legacy, 0x8f, &[rm(Word, Write)])
+        .with_opcode_extension(0)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    // 8F /0, POP r/m64
+    InstructionDefinition::new(POP, Legacy, 0x8f, &[rm(Qword, Write)])
+        .with_opcode_extension(0)
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    //
+    // PUSHF/PUSHFQ -- Push RFLAGS Register Onto the Stack
+    //
+    // 9C, PUSHFQ
+    InstructionDefinition::new(PUSHFQ, Legacy, 0x9c, &[])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    //
+    // POPF/POPFQ -- Pop Stack Into RFLAGS Register
+    //
+    // 9D, POPFQ
+    InstructionDefinition::new(POPFQ, Legacy, 0x9d, &[])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_STACK),
+    //
+    // ENTER -- Make Stack Frame for Procedure Parameters
+    //
+    // C8 iw ib, ENTER imm16, imm8
+    InstructionDefinition::new(ENTER, Legacy, 0xc8, &[imm(Word), imm(Byte)])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_FRAME),
+    //
+    // LEAVE -- High Level Procedure Exit
+    //
+    // C9, LEAVE
+    InstructionDefinition::new(LEAVE, Legacy, 0xc9, &[])
+        .with_default_64()
+        .with_implicit_operands(IMPLICIT_FRAME),
+    // CALL -- Call Procedure
+    //
+    // E8 cd, CALL rel32
+    InstructionDefinition::new(CALL, Legacy, 0xe8, &[rel(Dword)]),
+    // FF /2, CALL r/m64
+    InstructionDefinition::new(CALL, Legacy, 0xff, &[rm(Qword, Read)])
+        .with_opcode_extension(2)
+        .with_default_64(),
+    // JMP -- Jump
+    //
+    // EB cb, JMP rel8
+    InstructionDefinition::new(JMP, Legacy, 0xeb, &[rel(Byte)]),
+    // E9 cd, JMP rel32
+    InstructionDefinition::new(JMP, Legacy, 0xe9, &[rel(Dword)]),
+    // FF /4, JMP r/m64
+    InstructionDefinition::new(JMP, Legacy, 0xff, &[rm(Qword, Read)])
+        .with_opcode_extension(4)
+        .with_default_64(),
+    // RET -- Return From Procedure
+    //
+    // C3, RET
+    InstructionDefinition::new(RET, Legacy, 0xc3, &[]),
+    // C2 iw, RET imm16
+    InstructionDefinition::new(RET, Legacy, 0xc2, &[imm(Word)]),
+    // Jcc -- Jump if Condition Is Met
+    //
+    // the short form is `70+cc cb` and the near form is `0F 80+cc cd`.
+    //
+    // 70 cb, JO rel8
+    InstructionDefinition::new(Jcc(O), Legacy, 0x70, &[rel(Byte)]),
+    // 0F 80 cd, JO rel32
+    InstructionDefinition::new(Jcc(O), Map0F, 0x80, &[rel(Dword)]),
+    //
+    // 71 cb, JNO rel8
+    InstructionDefinition::new(Jcc(NO), Legacy, 0x71, &[rel(Byte)]),
+    // 0F 81 cd, JNO rel32
+    InstructionDefinition::new(Jcc(NO), Map0F, 0x81, &[rel(Dword)]),
+    //
+    // 72 cb, JB rel8
+    InstructionDefinition::new(Jcc(B), Legacy, 0x72, &[rel(Byte)]),
+    // 0F 82 cd, JB rel32
+    InstructionDefinition::new(Jcc(B), Map0F, 0x82, &[rel(Dword)]),
+    //
+    // 73 cb, JAE rel8
+    InstructionDefinition::new(Jcc(AE), Legacy, 0x73, &[rel(Byte)]),
+    // 0F 83 cd, JAE rel32
+    InstructionDefinition::new(Jcc(AE), Map0F, 0x83, &[rel(Dword)]),
+    //
+    // 74 cb, JE rel8
+    InstructionDefinition::new(Jcc(E), Legacy, 0x74, &[rel(Byte)]),
+    // 0F 84 cd, JE rel32
+    InstructionDefinition::new(Jcc(E), Map0F, 0x84, &[rel(Dword)]),
+    //
+    // 75 cb, JNE rel8
+    InstructionDefinition::new(Jcc(NE), Legacy, 0x75, &[rel(Byte)]),
+    // 0F 85 cd, JNE rel32
+    InstructionDefinition::new(Jcc(NE), Map0F, 0x85, &[rel(Dword)]),
+    //
+    // 76 cb, JBE rel8
+    InstructionDefinition::new(Jcc(BE), Legacy, 0x76, &[rel(Byte)]),
+    // 0F 86 cd, JBE rel32
+    InstructionDefinition::new(Jcc(BE), Map0F, 0x86, &[rel(Dword)]),
+    //
+    // 77 cb, JA rel8
+    InstructionDefinition::new(Jcc(A), Legacy, 0x77, &[rel(Byte)]),
+    // 0F 87 cd, JA rel32
+    InstructionDefinition::new(Jcc(A), Map0F, 0x87, &[rel(Dword)]),
+    //
+    // 78 cb, JS rel8
+    InstructionDefinition::new(Jcc(S), Legacy, 0x78, &[rel(Byte)]),
+    // 0F 88 cd, JS rel32
+    InstructionDefinition::new(Jcc(S), Map0F, 0x88, &[rel(Dword)]),
+    //
+    // 79 cb, JNS rel8
+    InstructionDefinition::new(Jcc(NS), Legacy, 0x79, &[rel(Byte)]),
+    // 0F 89 cd, JNS rel32
+    InstructionDefinition::new(Jcc(NS), Map0F, 0x89, &[rel(Dword)]),
+    //
+    // 7A cb, JP rel8
+    InstructionDefinition::new(Jcc(P), Legacy, 0x7a, &[rel(Byte)]),
+    // 0F 8A cd, JP rel32
+    InstructionDefinition::new(Jcc(P), Map0F, 0x8a, &[rel(Dword)]),
+    //
+    // 7B cb, JNP rel8
+    InstructionDefinition::new(Jcc(NP), Legacy, 0x7b, &[rel(Byte)]),
+    // 0F 8B cd, JNP rel32
+    InstructionDefinition::new(Jcc(NP), Map0F, 0x8b, &[rel(Dword)]),
+    //
+    // 7C cb, JL rel8
+    InstructionDefinition::new(Jcc(L), Legacy, 0x7c, &[rel(Byte)]),
+    // 0F 8C cd, JL rel32
+    InstructionDefinition::new(Jcc(L), Map0F, 0x8c, &[rel(Dword)]),
+    //
+    // 7D cb, JGE rel8
+    InstructionDefinition::new(Jcc(GE), Legacy, 0x7d, &[rel(Byte)]),
+    // 0F 8D cd, JGE rel32
+    InstructionDefinition::new(Jcc(GE), Map0F, 0x8d, &[rel(Dword)]),
+    //
+    // 7E cb, JLE rel8
+    InstructionDefinition::new(Jcc(LE), Legacy, 0x7e, &[rel(Byte)]),
+    // 0F 8E cd, JLE rel32
+    InstructionDefinition::new(Jcc(LE), Map0F, 0x8e, &[rel(Dword)]),
+    //
+    // 7F cb, JG rel8
+    InstructionDefinition::new(Jcc(G), Legacy, 0x7f, &[rel(Byte)]),
+    // 0F 8F cd, JG rel32
+    InstructionDefinition::new(Jcc(G), Map0F, 0x8f, &[rel(Dword)]),
+    //
+    // SETcc -- Set Byte on Condition
+    //
+    // `0F 90+cc`, the ModRM.reg is not used and it is encoded as 0.
+    //
+    // 0F 90, SETO r/m8
+    InstructionDefinition::new(SETcc(O), Map0F, 0x90, &[rm(Byte, Write)]),
+    // 0F 91, SETNO r/m8
+    InstructionDefinition::new(SETcc(NO), Map0F, 0x91, &[rm(Byte, Write)]),
+    // 0F 92, SETB r/m8
+    InstructionDefinition::new(SETcc(B), Map0F, 0x92, &[rm(Byte, Write)]),
+    // 0F 93, SETAE r/m8
+    InstructionDefinition::new(SETcc(AE), Map0F, 0x93, &[rm(Byte, Write)]),
+    // 0F 94, SETE r/m8
+    InstructionDefinition::new(SETcc(E), Map0F, 0x94, &[rm(Byte, Write)]),
+    // 0F 95, SETNE r/m8
+    InstructionDefinition::new(SETcc(NE), Map0F, 0x95, &[rm(Byte, Write)]),
+    // 0F 96, SETBE r/m8
+    InstructionDefinition::new(SETcc(BE), Map0F, 0x96, &[rm(Byte, Write)]),
+    // 0F 97, SETA r/m8
+    InstructionDefinition::new(SETcc(A), Map0F, 0x97, &[rm(Byte, Write)]),
+    // 0F 98, SETS r/m8
+    InstructionDefinition::new(SETcc(S), Map0F, 0x98, &[rm(Byte, Write)]),
+    // 0F 99, SETNS r/m8
+    InstructionDefinition::new(SETcc(NS), Map0F, 0x99, &[rm(Byte, Write)]),
+    // 0F 9A, SETP r/m8
+    InstructionDefinition::new(SETcc(P), Map0F, 0x9a, &[rm(Byte, Write)]),
+    // 0F 9B, SETNP r/m8
+    InstructionDefinition::new(SETcc(NP), Map0F, 0x9b, &[rm(Byte, Write)]),
+    // 0F 9C, SETL r/m8
+    InstructionDefinition::new(SETcc(L), Map0F, 0x9c, &[rm(Byte, Write)]),
+    // 0F 9D, SETGE r/m8
+    InstructionDefinition::new(SETcc(GE), Map0F, 0x9d, &[rm(Byte, Write)]),
+    // 0F 9E, SETLE r/m8
+    InstructionDefinition::new(SETcc(LE), Map0F, 0x9e, &[rm(Byte, Write)]),
+    // 0F 9F, SETG r/m8
+    InstructionDefinition::new(SETcc(G), Map0F, 0x9f, &[rm(Byte, Write)]),
+    //
+    // CMOVcc -- Conditional Move
+    //
+    // `0F 40+cc /r`, the destination is not changed if the condition is not met,
+    // except that the upper 32 bits are cleared in 32-bit operand size.
+    //
+    // 0F 40 /r, CMOVO r16, r/m16
+    InstructionDefinition::new(CMOVcc(O), Map0F, 0x40, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 40 /r, CMOVO r32, r/m32
+    InstructionDefinition::new(CMOVcc(O), Map0F, 0x40, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 40 /r, CMOVO r64, r/m64
+    InstructionDefinition::new(CMOVcc(O), Map0F, 0x40, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 41 /r, CMOVNO r16, r/m16
+    InstructionDefinition::new(CMOVcc(NO), Map0F, 0x41, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 41 /r, CMOVNO r32, r/m32
+    InstructionDefinition::new(CMOVcc(NO), Map0F, 0x41, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 41 /r, CMOVNO r64, r/m64
+    InstructionDefinition::new(CMOVcc(NO), Map0F, 0x41, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 42 /r, CMOVB r16, r/m16
+    InstructionDefinition::new(CMOVcc(B), Map0F, 0x42, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 42 /r, CMOVB r32, r/m32
+    InstructionDefinition::new(CMOVcc(B), Map0F, 0x42, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 42 /r, CMOVB r64, r/m64
+    InstructionDefinition::new(CMOVcc(B), Map0F, 0x42, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 43 /r, CMOVAE r16, r/m16
+    InstructionDefinition::new(CMOVcc(AE), Map0F, 0x43, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 43 /r, CMOVAE r32, r/m32
+    InstructionDefinition::new(CMOVcc(AE), Map0F, 0x43, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 43 /r, CMOVAE r64, r/m64
+    InstructionDefinition::new(CMOVcc(AE), Map0F, 0x43, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 44 /r, CMOVE r16, r/m16
+    InstructionDefinition::new(CMOVcc(E), Map0F, 0x44, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 44 /r, CMOVE r32, r/m32
+    InstructionDefinition::new(CMOVcc(E), Map0F, 0x44, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 44 /r, CMOVE r64, r/m64
+    InstructionDefinition::new(CMOVcc(E), Map0F, 0x44, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 45 /r, CMOVNE r16, r/m16
+    InstructionDefinition::new(CMOVcc(NE), Map0F, 0x45, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 45 /r, CMOVNE r32, r/m32
+    InstructionDefinition::new(CMOVcc(NE), Map0F, 0x45, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 45 /r, CMOVNE r64, r/m64
+    InstructionDefinition::new(CMOVcc(NE), Map0F, 0x45, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 46 /r, CMOVBE r16, r/m16
+    InstructionDefinition::new(CMOVcc(BE), Map0F, 0x46, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 46 /r, CMOVBE r32, r/m32
+    InstructionDefinition::new(CMOVcc(BE), Map0F, 0x46, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 46 /r, CMOVBE r64, r/m64
+    InstructionDefinition::new(CMOVcc(BE), Map0F, 0x46, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 47 /r, CMOVA r16, r/m16
+    InstructionDefinition::new(CMOVcc(A), Map0F, 0x47, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 47 /r, CMOVA r32, r/m32
+    InstructionDefinition::new(CMOVcc(A), Map0F, 0x47, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 47 /r, CMOVA r64, r/m64
+    InstructionDefinition::new(CMOVcc(A), Map0F, 0x47, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 48 /r, CMOVS r16, r/m16
+    InstructionDefinition::new(CMOVcc(S), Map0F, 0x48, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 48 /r, CMOVS r32, r/m32
+    InstructionDefinition::new(CMOVcc(S), Map0F, 0x48, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 48 /r, CMOVS r64, r/m64
+    InstructionDefinition::new(CMOVcc(S), Map0F, 0x48, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 49 /r, CMOVNS r16, r/m16
+    InstructionDefinition::new(CMOVcc(NS), Map0F, 0x49, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 49 /r, CMOVNS r32, r/m32
+    InstructionDefinition::new(CMOVcc(NS), Map0F, 0x49, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 49 /r, CMOVNS r64, r/m64
+    InstructionDefinition::new(CMOVcc(NS), Map0F, 0x49, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 4A /r, CMOVP r16, r/m16
+    InstructionDefinition::new(CMOVcc(P), Map0F, 0x4a, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 4A /r, CMOVP r32, r/m32
+    InstructionDefinition::new(CMOVcc(P), Map0F, 0x4a, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 4A /r, CMOVP r64, r/m64
+    InstructionDefinition::new(CMOVcc(P), Map0F, 0x4a, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 4B /r, CMOVNP r16, r/m16
+    InstructionDefinition::new(CMOVcc(NP), Map0F, 0x4b, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 4B /r, CMOVNP r32, r/m32
+    InstructionDefinition::new(CMOVcc(NP), Map0F, 0x4b, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 4B /r, CMOVNP r64, r/m64
+    InstructionDefinition::new(CMOVcc(NP), Map0F, 0x4b, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 4C /r, CMOVL r16, r/m16
+    InstructionDefinition::new(CMOVcc(L), Map0F, 0x4c, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 4C /r, CMOVL r32, r/m32
+    InstructionDefinition::new(CMOVcc(L), Map0F, 0x4c, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 4C /r, CMOVL r64, r/m64
+    InstructionDefinition::new(CMOVcc(L), Map0F, 0x4c, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 4D /r, CMOVGE r16, r/m16
+    InstructionDefinition::new(CMOVcc(GE), Map0F, 0x4d, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 4D /r, CMOVGE r32, r/m32
+    InstructionDefinition::new(CMOVcc(GE), Map0F, 0x4d, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 4D /r, CMOVGE r64, r/m64
+    InstructionDefinition::new(CMOVcc(GE), Map0F, 0x4d, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 4E /r, CMOVLE r16, r/m16
+    InstructionDefinition::new(CMOVcc(LE), Map0F, 0x4e, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 4E /r, CMOVLE r32, r/m32
+    InstructionDefinition::new(CMOVcc(LE), Map0F, 0x4e, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 4E /r, CMOVLE r64, r/m64
+    InstructionDefinition::new(CMOVcc(LE), Map0F, 0x4e, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // 0F 4F /r, CMOVG r16, r/m16
+    InstructionDefinition::new(CMOVcc(G), Map0F, 0x4f, &[reg(Word, ReadWrite), rm(Word, Read)]),
+    // 0F 4F /r, CMOVG r32, r/m32
+    InstructionDefinition::new(CMOVcc(G), Map0F, 0x4f, &[reg(Dword, ReadWrite), rm(Dword, Read)]),
+    // REX.W + 0F 4F /r, CMOVG r64, r/m64
+    InstructionDefinition::new(CMOVcc(G), Map0F, 0x4f, &[reg(Qword, ReadWrite), rm(Qword, Read)]),
+    //
+    // Flag manipulation
+    //
+    // F8, CLC (clear CF)
+    InstructionDefinition::new(CLC, Legacy, 0xf8, &[]),
+    // F9, STC (set CF)
+    InstructionDefinition::new(STC, Legacy, 0xf9, &[]),
+    // F5, CMC (complement CF)
+    InstructionDefinition::new(CMC, Legacy, 0xf5, &[]),
+    // FC, CLD (clear DF)
+    InstructionDefinition::new(CLD, Legacy, 0xfc, &[]),
+    // FD, STD (set DF)
+    InstructionDefinition::new(STD, Legacy, 0xfd, &[]),
+    // 9F, LAHF (AH := EFLAGS(SF:ZF:0:AF:0:PF:1:CF))
+    InstructionDefinition::new(LAHF, Legacy, 0x9f, &[])
+        .with_implicit_operands(IMPLICIT_LAHF),
+    // 9E, SAHF (EFLAGS(SF:ZF:0:AF:0:PF:1:CF) := AH)
+    InstructionDefinition::new(SAHF, Legacy, 0x9e, &[])
+        .with_implicit_operands(IMPLICIT_SAHF),
+    //
+    // MOVS/CMPS/STOS/LODS/SCAS -- String Operations
+    //
+    // The string operands are always [RSI] and [RDI], RSI and RDI are advanced
+    // (or decremented if DF is set) by the operand size. With a REP prefix the
+    // instruction is repeated RCX times, REPE/REPNE also stop on the comparison.
+    //
+    // A4, MOVSB, MOVS m8, m8 ([RDI] := [RSI])
+    InstructionDefinition::new(MOVSB, Legacy, 0xa4, &[])
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REP]),
+    // A5, MOVSW, MOVS m16, m16 ([RDI] := [RSI])
+    InstructionDefinition::new(MOVSW, Legacy, 0xa5, &[])
+        .with_operand_size(Word)
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REP]),
+    // A5, MOVSD, MOVS m32, m32 ([RDI] := [RSI])
+    InstructionDefinition::new(MOVSD, Legacy, 0xa5, &[])
+        .with_operand_size(Dword)
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REP]),
+    // REX.W + A5, MOVSQ, MOVS m64, m64 ([RDI] := [RSI])
+    InstructionDefinition::new(MOVSQ, Legacy, 0xa5, &[])
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REP]),
+    // A6, CMPSB, CMPS m8, m8 (compare [RSI] with [RDI])
+    InstructionDefinition::new(CMPSB, Legacy, 0xa6, &[])
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    // A7, CMPSW, CMPS m16, m16 (compare [RSI] with [RDI])
+    InstructionDefinition::new(CMPSW, Legacy, 0xa7, &[])
+        .with_operand_size(Word)
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    // A7, CMPSD, CMPS m32, m32 (compare [RSI] with [RDI])
+    InstructionDefinition::new(CMPSD, Legacy, 0xa7, &[])
+        .with_operand_size(Dword)
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    // REX.W + A7, CMPSQ, CMPS m64, m64 (compare [RSI] with [RDI])
+    InstructionDefinition::new(CMPSQ, Legacy, 0xa7, &[])
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_MOVS_CMPS)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    // AA, STOSB, STOS m8 ([RDI] := AL)
+    InstructionDefinition::new(STOSB, Legacy, 0xaa, &[])
+        .with_implicit_operands(IMPLICIT_STOS_8)
+        .with_allowed_prefixes(&[REP]),
+    // AB, STOSW, STOS m16 ([RDI] := AX)
+    InstructionDefinition::new(STOSW, Legacy, 0xab, &[])
+        .with_operand_size(Word)
+        .with_implicit_operands(IMPLICIT_STOS_16)
+        .with_allowed_prefixes(&[REP]),
+    // AB, STOSD, STOS m32 ([RDI] := EAX)
+    InstructionDefinition::new(STOSD, Legacy, 0xab, &[])
+        .with_operand_size(Dword)
+        .with_implicit_operands(IMPLICIT_STOS_32)
+        .with_allowed_prefixes(&[REP]),
+    // REX.W + AB, STOSQ, STOS m64 ([RDI] := RAX)
+    InstructionDefinition::new(STOSQ, Legacy, 0xab, &[])
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_STOS_64)
+        .with_allowed_prefixes(&[REP]),
+    // AC, LODSB, LODS m8 (AL := [RSI])
+    InstructionDefinition::new(LODSB, Legacy, 0xac, &[])
+        .with_implicit_operands(IMPLICIT_LODS_8)
+        .with_allowed_prefixes(&[REP]),
+    // AD, LODSW, LODS m16 (AX := [RSI])
+    InstructionDefinition::new(LODSW, Legacy, 0xad, &[])
+        .with_operand_size(Word)
+        .with_implicit_operands(IMPLICIT_LODS_16)
+        .with_allowed_prefixes(&[REP]),
+    // AD, LODSD, LODS m32 (EAX := [RSI])
+    InstructionDefinition::new(LODSD, Legacy, 0xad, &[])
+        .with_operand_size(Dword)
+        .with_implicit_operands(IMPLICIT_LODS_32)
+        .with_allowed_prefixes(&[REP]),
+    // REX.W + AD, LODSQ, LODS m64 (RAX := [RSI])
+    InstructionDefinition::new(LODSQ, Legacy, 0xad, &[])
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_LODS_64)
+        .with_allowed_prefixes(&[REP]),
+    // AE, SCASB, SCAS m8 (compare AL with [RDI])
+    InstructionDefinition::new(SCASB, Legacy, 0xae, &[])
+        .with_implicit_operands(IMPLICIT_SCAS_8)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    // AF, SCASW, SCAS m16 (compare AX with [RDI])
+    InstructionDefinition::new(SCASW, Legacy, 0xaf, &[])
+        .with_operand_size(Word)
+        .with_implicit_operands(IMPLICIT_SCAS_16)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    // AF, SCASD, SCAS m32 (compare EAX with [RDI])
+    InstructionDefinition::new(SCASD, Legacy, 0xaf, &[])
+        .with_operand_size(Dword)
+        .with_implicit_operands(IMPLICIT_SCAS_32)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    // REX.W + AF, SCASQ, SCAS m64 (compare RAX with [RDI])
+    InstructionDefinition::new(SCASQ, Legacy, 0xaf, &[])
+        .with_operand_size(Qword)
+        .with_implicit_operands(IMPLICIT_SCAS_64)
+        .with_allowed_prefixes(&[REPE, REPNE]),
+    //
+    // MFENCE/LFENCE/SFENCE -- Memory Fence
+    //
+    // NP 0F AE F0, MFENCE
+    InstructionDefinition::new(MFENCE, Map0F, 0xae, &[])
+        .with_modrm(0xf0),
+    // NP 0F AE E8, LFENCE
+    InstructionDefinition::new(LFENCE, Map0F, 0xae, &[])
+        .with_modrm(0xe8),
+    // NP 0F AE F8, SFENCE
+    InstructionDefinition::new(SFENCE, Map0F, 0xae, &[])
+        .with_modrm(0xf8),
+    //
+    // PAUSE -- Spin Loop Hint
+    //
+    // F3 90, PAUSE
+    InstructionDefinition::new(PAUSE, Legacy, 0x90, &[])
+        .with_mandatory_prefix(0xf3),
+    //
+    // NOP -- No Operation
+    //
+    // NP 90, NOP
+    InstructionDefinition::new(NOP, Legacy, 0x90, &[]),
+    //
+    // SYSCALL -- Fast System Call
+    //
+    // RCX holds the return address (RIP) and R11 holds RFLAGS after the call,
+    // the system call number and the arguments are passed by the calling convention.
+    //
+    // 0F 05, SYSCALL
+    InstructionDefinition::new(SYSCALL, Map0F, 0x05, &[])
+        .with_implicit_operands(IMPLICIT_SYSCALL),
+    //
+    // CPUID -- CPU Identification
+    //
+    // EAX is the leaf and ECX is the sub-leaf.
+    //
+    // 0F A2, CPUID
+    InstructionDefinition::new(CPUID, Map0F, 0xa2, &[])
+        .with_implicit_operands(IMPLICIT_CPUID),
+    //
+    // RDTSC/RDTSCP -- Read Time-Stamp Counter (and Processor ID)
+    //
+    // The counter is EDX:EAX, RDTSCP also reads IA32_TSC_AUX into ECX.
+    //
+    // 0F 31, RDTSC
+    InstructionDefinition::new(RDTSC, Map0F, 0x31, &[])
+        .with_implicit_operands(IMPLICIT_RDTSC),
+    // 0F 01 F9, RDTSCP
+    InstructionDefinition::new(RDTSCP, Map0F, 0x01, &[])
+        .with_modrm(0xf9)
+        .with_implicit_operands(IMPLICIT_RDTSCP),
+    //
+    // RDPID -- Read Processor ID
+    //
+    // Reads IA32_TSC_AUX, the operand size is always 64-bit in 64-bit mode.
+    //
+    // F3 0F C7 /7, RDPID r64
+    InstructionDefinition::new(RDPID, Map0F, 0xc7, &[rm_reg(Qword, Write)])
+        .with_mandatory_prefix(0xf3)
+        .with_opcode_extension(7)
+        .with_default_64(),
+    //
+    // XGETBV -- Get Value of Extended Control Register
+    //
+    // ECX selects the XCR, the value is EDX:EAX.
+    //
+    // NP 0F 01 D0, XGETBV
+    InstructionDefinition::new(XGETBV, Map0F, 0x01, &[])
+        .with_modrm(0xd0)
+        .with_implicit_operands(IMPLICIT_XGETBV),
+    //
+    // INT3/INT -- Call to Interrupt Procedure
+    //
+    // CC, INT3
+    InstructionDefinition::new(INT3, Legacy, 0xcc, &[]),
+    // CD ib, INT imm8
+    InstructionDefinition::new(INT, Legacy, 0xcd, &[imm(Byte)]),
+    //
+    // UD2 -- Undefined Instruction
+    //
+    // 0F 0B, UD2
+    InstructionDefinition::new(UD2, Map0F, 0x0b, &[]),
+    //
+    // HLT -- Halt
+    //
+    // F4, HLT
+    InstructionDefinition::new(HLT, Legacy, 0xf4, &[]),
+    //
+    // SSE/SSE2/SSE3 -- Streaming SIMD Extensions
+    //
+    // The XMM registers are encoded in ModRM like the general-purpose registers,
+    // XMM8-XMM15 require REX.R/REX.B. The size of `xmm/m32` and `xmm/m64` is
+    // the size of the memory operand, the register is always 128-bit.
+    //
+    // The 66/F2/F3 prefix selects the data type, e.g. `58 /r` is ADDPS with NP,
+    // ADDPD with 66, ADDSS with F3 and ADDSD with F2.
+    //
+    // MOVSS/MOVSD/MOVAPS/MOVUPS/MOVDQA/MOVDQU -- Move Data
+    //
+    // F3 0F 10 /r, MOVSS xmm1, xmm2/m32
+    InstructionDefinition::new(MOVSS, Map0F, 0x10, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 0F 11 /r, MOVSS xmm2/m32, xmm1
+    InstructionDefinition::new(MOVSS, Map0F, 0x11, &[xmm_m(Dword, ReadWrite), xmm(Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 10 /r, MOVSD xmm1, xmm2/m64
+    InstructionDefinition::new(MOVSD, Map0F, 0x10, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F2 0F 11 /r, MOVSD xmm2/m64, xmm1
+    InstructionDefinition::new(MOVSD, Map0F, 0x11, &[xmm_m(Qword, ReadWrite), xmm(Read)])
+        .with_mandatory_prefix(0xf2),
+    // NP 0F 28 /r, MOVAPS xmm1, xmm2/m128
+    InstructionDefinition::new(MOVAPS, Map0F, 0x28, &[xmm(Write), xmm_m(XMMWord, Read)]),
+    // NP 0F 29 /r, MOVAPS xmm2/m128, xmm1
+    InstructionDefinition::new(MOVAPS, Map0F, 0x29, &[xmm_m(XMMWord, Write), xmm(Read)]),
+    // NP 0F 10 /r, MOVUPS xmm1, xmm2/m128
+    InstructionDefinition::new(MOVUPS, Map0F, 0x10, &[xmm(Write), xmm_m(XMMWord, Read)]),
+    // NP 0F 11 /r, MOVUPS xmm2/m128, xmm1
+    InstructionDefinition::new(MOVUPS, Map0F, 0x11, &[xmm_m(XMMWord, Write), xmm(Read)]),
+    // 66 0F 6F /r, MOVDQA xmm1, xmm2/m128
+    InstructionDefinition::new(MOVDQA, Map0F, 0x6f, &[xmm(Write), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 7F /r, MOVDQA xmm2/m128, xmm1
+    InstructionDefinition::new(MOVDQA, Map0F, 0x7f, &[xmm_m(XMMWord, Write), xmm(Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 6F /r, MOVDQU xmm1, xmm2/m128
+    InstructionDefinition::new(MOVDQU, Map0F, 0x6f, &[xmm(Write), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 0F 7F /r, MOVDQU xmm2/m128, xmm1
+    InstructionDefinition::new(MOVDQU, Map0F, 0x7f, &[xmm_m(XMMWord, Write), xmm(Read)])
+        .with_mandatory_prefix(0xf3),
+    //
+    // ADDPS/ADDPD/ADDSS/ADDSD -- Add Floating-Point Values
+    //
+    // NP 0F 58 /r, ADDPS xmm1, xmm2/m128
+    InstructionDefinition::new(ADDPS, Map0F, 0x58, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // 66 0F 58 /r, ADDPD xmm1, xmm2/m128
+    InstructionDefinition::new(ADDPD, Map0F, 0x58, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 58 /r, ADDSS xmm1, xmm2/m32
+    InstructionDefinition::new(ADDSS, Map0F, 0x58, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 58 /r, ADDSD xmm1, xmm2/m64
+    InstructionDefinition::new(ADDSD, Map0F, 0x58, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // SUBPS/SUBPD/SUBSS/SUBSD -- Subtract Floating-Point Values
+    //
+    // NP 0F 5C /r, SUBPS xmm1, xmm2/m128
+    InstructionDefinition::new(SUBPS, Map0F, 0x5c, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // 66 0F 5C /r, SUBPD xmm1, xmm2/m128
+    InstructionDefinition::new(SUBPD, Map0F, 0x5c, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 5C /r, SUBSS xmm1, xmm2/m32
+    InstructionDefinition::new(SUBSS, Map0F, 0x5c, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 5C /r, SUBSD xmm1, xmm2/m64
+    InstructionDefinition::new(SUBSD, Map0F, 0x5c, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // MULPS/MULPD/MULSS/MULSD -- Multiply Floating-Point Values
+    //
+    // NP 0F 59 /r, MULPS xmm1, xmm2/m128
+    InstructionDefinition::new(MULPS, Map0F, 0x59, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // 66 0F 59 /r, MULPD xmm1, xmm2/m128
+    InstructionDefinition::new(MULPD, Map0F, 0x59, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 59 /r, MULSS xmm1, xmm2/m32
+    InstructionDefinition::new(MULSS, Map0F, 0x59, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 59 /r, MULSD xmm1, xmm2/m64
+    InstructionDefinition::new(MULSD, Map0F, 0x59, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // DIVPS/DIVPD/DIVSS/DIVSD -- Divide Floating-Point Values
+    //
+    // NP 0F 5E /r, DIVPS xmm1, xmm2/m128
+    InstructionDefinition::new(DIVPS, Map0F, 0x5e, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // 66 0F 5E /r, DIVPD xmm1, xmm2/m128
+    InstructionDefinition::new(DIVPD, Map0F, 0x5e, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 5E /r, DIVSS xmm1, xmm2/m32
+    InstructionDefinition::new(DIVSS, Map0F, 0x5e, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 5E /r, DIVSD xmm1, xmm2/m64
+    InstructionDefinition::new(DIVSD, Map0F, 0x5e, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // SQRTPS/SQRTPD/SQRTSS/SQRTSD -- Square Root Floating-Point Values
+    //
+    // NP 0F 51 /r, SQRTPS xmm1, xmm2/m128
+    InstructionDefinition::new(SQRTPS, Map0F, 0x51, &[xmm(Write), xmm_m(XMMWord, Read)]),
+    // 66 0F 51 /r, SQRTPD xmm1, xmm2/m128
+    InstructionDefinition::new(SQRTPD, Map0F, 0x51, &[xmm(Write), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 51 /r, SQRTSS xmm1, xmm2/m32
+    InstructionDefinition::new(SQRTSS, Map0F, 0x51, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 51 /r, SQRTSD xmm1, xmm2/m64
+    InstructionDefinition::new(SQRTSD, Map0F, 0x51, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // MINPS/MINPD/MINSS/MINSD -- Minimum Floating-Point Values
+    //
+    // NP 0F 5D /r, MINPS xmm1, xmm2/m128
+    InstructionDefinition::new(MINPS, Map0F, 0x5d, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // 66 0F 5D /r, MINPD xmm1, xmm2/m128
+    InstructionDefinition::new(MINPD, Map0F, 0x5d, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 5D /r, MINSS xmm1, xmm2/m32
+    InstructionDefinition::new(MINSS, Map0F, 0x5d, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 5D /r, MINSD xmm1, xmm2/m64
+    InstructionDefinition::new(MINSD, Map0F, 0x5d, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // MAXPS/MAXPD/MAXSS/MAXSD -- Maximum Floating-Point Values
+    //
+    // NP 0F 5F /r, MAXPS xmm1, xmm2/m128
+    InstructionDefinition::new(MAXPS, Map0F, 0x5f, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // 66 0F 5F /r, MAXPD xmm1, xmm2/m128
+    InstructionDefinition::new(MAXPD, Map0F, 0x5f, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F3 0F 5F /r, MAXSS xmm1, xmm2/m32
+    InstructionDefinition::new(MAXSS, Map0F, 0x5f, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 5F /r, MAXSD xmm1, xmm2/m64
+    InstructionDefinition::new(MAXSD, Map0F, 0x5f, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // UCOMISS/UCOMISD/COMISS/COMISD -- Compare Scalar Floating-Point Values and Set EFLAGS
+    //
+    // NP 0F 2E /r, UCOMISS xmm1, xmm2/m32
+    InstructionDefinition::new(UCOMISS, Map0F, 0x2e, &[xmm(Read), xmm_m(Dword, Read)]),
+    // 66 0F 2E /r, UCOMISD xmm1, xmm2/m64
+    InstructionDefinition::new(UCOMISD, Map0F, 0x2e, &[xmm(Read), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0x66),
+    // NP 0F 2F /r, COMISS xmm1, xmm2/m32
+    InstructionDefinition::new(COMISS, Map0F, 0x2f, &[xmm(Read), xmm_m(Dword, Read)]),
+    // 66 0F 2F /r, COMISD xmm1, xmm2/m64
+    InstructionDefinition::new(COMISD, Map0F, 0x2f, &[xmm(Read), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // CVTSI2SS/CVTSI2SD/CVT(T)SS2SI/CVT(T)SD2SI -- Convert Between Integer and Floating-Point
+    //
+    // F3 0F 2A /r, CVTSI2SS xmm1, r/m32
+    InstructionDefinition::new(CVTSI2SS, Map0F, 0x2a, &[xmm(ReadWrite), rm(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 REX.W 0F 2A /r, CVTSI2SS xmm1, r/m64
+    InstructionDefinition::new(CVTSI2SS, Map0F, 0x2a, &[xmm(ReadWrite), rm(Qword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 2A /r, CVTSI2SD xmm1, r/m32
+    InstructionDefinition::new(CVTSI2SD, Map0F, 0x2a, &[xmm(ReadWrite), rm(Dword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F2 REX.W 0F 2A /r, CVTSI2SD xmm1, r/m64
+    InstructionDefinition::new(CVTSI2SD, Map0F, 0x2a, &[xmm(ReadWrite), rm(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F3 0F 2D /r, CVTSS2SI r32, xmm1/m32
+    InstructionDefinition::new(CVTSS2SI, Map0F, 0x2d, &[reg(Dword, Write), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 REX.W 0F 2D /r, CVTSS2SI r64, xmm1/m32
+    InstructionDefinition::new(CVTSS2SI, Map0F, 0x2d, &[reg(Qword, Write), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 2D /r, CVTSD2SI r32, xmm1/m64
+    InstructionDefinition::new(CVTSD2SI, Map0F, 0x2d, &[reg(Dword, Write), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F2 REX.W 0F 2D /r, CVTSD2SI r64, xmm1/m64
+    InstructionDefinition::new(CVTSD2SI, Map0F, 0x2d, &[reg(Qword, Write), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F3 0F 2C /r, CVTTSS2SI r32, xmm1/m32
+    InstructionDefinition::new(CVTTSS2SI, Map0F, 0x2c, &[reg(Dword, Write), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 REX.W 0F 2C /r, CVTTSS2SI r64, xmm1/m32
+    InstructionDefinition::new(CVTTSS2SI, Map0F, 0x2c, &[reg(Qword, Write), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 2C /r, CVTTSD2SI r32, xmm1/m64
+    InstructionDefinition::new(CVTTSD2SI, Map0F, 0x2c, &[reg(Dword, Write), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F2 REX.W 0F 2C /r, CVTTSD2SI r64, xmm1/m64
+    InstructionDefinition::new(CVTTSD2SI, Map0F, 0x2c, &[reg(Qword, Write), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // CVTSS2SD/CVTSD2SS -- Convert Between Single and Double Precision
+    //
+    // F3 0F 5A /r, CVTSS2SD xmm1, xmm2/m32
+    InstructionDefinition::new(CVTSS2SD, Map0F, 0x5a, &[xmm(ReadWrite), xmm_m(Dword, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F 5A /r, CVTSD2SS xmm1, xmm2/m64
+    InstructionDefinition::new(CVTSD2SS, Map0F, 0x5a, &[xmm(ReadWrite), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // PADD/PSUB -- Add/Subtract Packed Integers
+    //
+    // 66 0F FC /r, PADDB xmm1, xmm2/m128
+    InstructionDefinition::new(PADDB, Map0F, 0xfc, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F FD /r, PADDW xmm1, xmm2/m128
+    InstructionDefinition::new(PADDW, Map0F, 0xfd, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F FE /r, PADDD xmm1, xmm2/m128
+    InstructionDefinition::new(PADDD, Map0F, 0xfe, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F D4 /r, PADDQ xmm1, xmm2/m128
+    InstructionDefinition::new(PADDQ, Map0F, 0xd4, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F F8 /r, PSUBB xmm1, xmm2/m128
+    InstructionDefinition::new(PSUBB, Map0F, 0xf8, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F F9 /r, PSUBW xmm1, xmm2/m128
+    InstructionDefinition::new(PSUBW, Map0F, 0xf9, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F FA /r, PSUBD xmm1, xmm2/m128
+    InstructionDefinition::new(PSUBD, Map0F, 0xfa, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F FB /r, PSUBQ xmm1, xmm2/m128
+    InstructionDefinition::new(PSUBQ, Map0F, 0xfb, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // PAND/PANDN/POR/PXOR -- Logical Operations on Packed Integers
+    //
+    // 66 0F DB /r, PAND xmm1, xmm2/m128
+    InstructionDefinition::new(PAND, Map0F, 0xdb, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F DF /r, PANDN xmm1, xmm2/m128
+    InstructionDefinition::new(PANDN, Map0F, 0xdf, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F EB /r, POR xmm1, xmm2/m128
+    InstructionDefinition::new(POR, Map0F, 0xeb, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F EF /r, PXOR xmm1, xmm2/m128
+    InstructionDefinition::new(PXOR, Map0F, 0xef, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // PCMPEQB/PCMPEQW/PCMPEQD -- Compare Packed Data for Equal
+    //
+    // 66 0F 74 /r, PCMPEQB xmm1, xmm2/m128
+    InstructionDefinition::new(PCMPEQB, Map0F, 0x74, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 75 /r, PCMPEQW xmm1, xmm2/m128
+    InstructionDefinition::new(PCMPEQW, Map0F, 0x75, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 76 /r, PCMPEQD xmm1, xmm2/m128
+    InstructionDefinition::new(PCMPEQD, Map0F, 0x76, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // PSHUFD -- Shuffle Packed Doublewords
+    //
+    // 66 0F 70 /r ib, PSHUFD xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(PSHUFD, Map0F, 0x70, &[xmm(Write), xmm_m(XMMWord, Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    //
+    // PUNPCKL*/PUNPCKH* -- Unpack Low/High Data
+    //
+    // 66 0F 60 /r, PUNPCKLBW xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKLBW, Map0F, 0x60, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 61 /r, PUNPCKLWD xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKLWD, Map0F, 0x61, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 62 /r, PUNPCKLDQ xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKLDQ, Map0F, 0x62, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 6C /r, PUNPCKLQDQ xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKLQDQ, Map0F, 0x6c, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 68 /r, PUNPCKHBW xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKHBW, Map0F, 0x68, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 69 /r, PUNPCKHWD xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKHWD, Map0F, 0x69, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 6A /r, PUNPCKHDQ xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKHDQ, Map0F, 0x6a, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 6D /r, PUNPCKHQDQ xmm1, xmm2/m128
+    InstructionDefinition::new(PUNPCKHQDQ, Map0F, 0x6d, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // ADDSUBPS/ADDSUBPD/HADDPS/HADDPD/HSUBPS/HSUBPD -- SSE3 Horizontal Arithmetic
+    //
+    // F2 0F D0 /r, ADDSUBPS xmm1, xmm2/m128
+    InstructionDefinition::new(ADDSUBPS, Map0F, 0xd0, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0xf2),
+    // 66 0F D0 /r, ADDSUBPD xmm1, xmm2/m128
+    InstructionDefinition::new(ADDSUBPD, Map0F, 0xd0, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F2 0F 7C /r, HADDPS xmm1, xmm2/m128
+    InstructionDefinition::new(HADDPS, Map0F, 0x7c, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0xf2),
+    // 66 0F 7C /r, HADDPD xmm1, xmm2/m128
+    InstructionDefinition::new(HADDPD, Map0F, 0x7c, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // F2 0F 7D /r, HSUBPS xmm1, xmm2/m128
+    InstructionDefinition::new(HSUBPS, Map0F, 0x7d, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0xf2),
+    // 66 0F 7D /r, HSUBPD xmm1, xmm2/m128
+    InstructionDefinition::new(HSUBPD, Map0F, 0x7d, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // MOVDDUP/MOVSHDUP/MOVSLDUP/LDDQU -- SSE3 Moves
+    //
+    // F2 0F 12 /r, MOVDDUP xmm1, xmm2/m64
+    InstructionDefinition::new(MOVDDUP, Map0F, 0x12, &[xmm(Write), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F3 0F 16 /r, MOVSHDUP xmm1, xmm2/m128
+    InstructionDefinition::new(MOVSHDUP, Map0F, 0x16, &[xmm(Write), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F3 0F 12 /r, MOVSLDUP xmm1, xmm2/m128
+    InstructionDefinition::new(MOVSLDUP, Map0F, 0x12, &[xmm(Write), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0xf3),
+    // F2 0F F0 /r, LDDQU xmm1, m128
+    InstructionDefinition::new(LDDQU, Map0F, 0xf0, &[xmm(Write), m128(Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // SSSE3/SSE4.1/SSE4.2 -- Supplemental Streaming SIMD Extensions 3 and SSE4
+    //
+    // Most of them use the 3-byte opcode maps, i.e. `0F 38` and `0F 3A`.
+    //
+    // PSHUFB/PALIGNR -- Packed Shuffle Bytes/Packed Align Right (SSSE3)
+    //
+    // 66 0F 38 00 /r, PSHUFB xmm1, xmm2/m128
+    InstructionDefinition::new(PSHUFB, Map0F38, 0x00, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 3A 0F /r ib, PALIGNR xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        PALIGNR,
+        Map0F3A,
+        0x0f,
+        &[xmm(ReadWrite), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    //
+    // PBLENDVB -- Variable Blend Packed Bytes
+    //
+    // The mask is the implicit XMM0, it is written as the third operand.
+    //
+    // 66 0F 38 10 /r, PBLENDVB xmm1, xmm2/m128, <XMM0>
+    InstructionDefinition::new(
+        PBLENDVB,
+        Map0F38,
+        0x10,
+        &[xmm(ReadWrite), xmm_m(XMMWord, Read), fixed(XMM0, Read)],
+    )
+    .with_mandatory_prefix(0x66),
+    //
+    // PMINUB/PMINUW/PMINUD/PMAXUB/PMAXUW/PMAXUD -- Minimum/Maximum of Packed Unsigned Integers
+    //
+    // PMINUB and PMAXUB are SSE2 and use the 2-byte opcode map.
+    //
+    // 66 0F DA /r, PMINUB xmm1, xmm2/m128
+    InstructionDefinition::new(PMINUB, Map0F, 0xda, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 3A /r, PMINUW xmm1, xmm2/m128
+    InstructionDefinition::new(PMINUW, Map0F38, 0x3a, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 3B /r, PMINUD xmm1, xmm2/m128
+    InstructionDefinition::new(PMINUD, Map0F38, 0x3b, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F DE /r, PMAXUB xmm1, xmm2/m128
+    InstructionDefinition::new(PMAXUB, Map0F, 0xde, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 3E /r, PMAXUW xmm1, xmm2/m128
+    InstructionDefinition::new(PMAXUW, Map0F38, 0x3e, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 3F /r, PMAXUD xmm1, xmm2/m128
+    InstructionDefinition::new(PMAXUD, Map0F38, 0x3f, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // PEXTRB/PEXTRW/PEXTRD/PEXTRQ -- Extract Byte/Word/Dword/Qword
+    //
+    // The memory forms of PEXTRW and PINSRW are 16-bit, the 66 is the mandatory prefix
+    // rather than the operand-size prefix, so the operand size is specified as 32-bit.
+    //
+    // 66 0F 3A 14 /r ib, PEXTRB r32, xmm2, imm8
+    InstructionDefinition::new(PEXTRB, Map0F3A, 0x14, &[rm_reg(Dword, Write), xmm(Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 3A 14 /r ib, PEXTRB m8, xmm2, imm8
+    InstructionDefinition::new(PEXTRB, Map0F3A, 0x14, &[mem(Byte, Write), xmm(Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F C5 /r ib, PEXTRW r32, xmm2, imm8
+    InstructionDefinition::new(PEXTRW, Map0F, 0xc5, &[reg(Dword, Write), xmm_rm(Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 3A 15 /r ib, PEXTRW m16, xmm2, imm8
+    InstructionDefinition::new(PEXTRW, Map0F3A, 0x15, &[mem(Word, Write), xmm(Read), imm(Byte)])
+        .with_mandatory_prefix(0x66)
+        .with_operand_size(Dword),
+    // 66 0F 3A 16 /r ib, PEXTRD r/m32, xmm2, imm8
+    InstructionDefinition::new(PEXTRD, Map0F3A, 0x16, &[rm(Dword, Write), xmm(Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    // 66 REX.W 0F 3A 16 /r ib, PEXTRQ r/m64, xmm2, imm8
+    InstructionDefinition::new(PEXTRQ, Map0F3A, 0x16, &[rm(Qword, Write), xmm(Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    //
+    // PINSRB/PINSRW/PINSRD/PINSRQ -- Insert Byte/Word/Dword/Qword
+    //
+    // 66 0F 3A 20 /r ib, PINSRB xmm1, r32, imm8
+    InstructionDefinition::new(
+        PINSRB,
+        Map0F3A,
+        0x20,
+        &[xmm(ReadWrite), rm_reg(Dword, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    // 66 0F 3A 20 /r ib, PINSRB xmm1, m8, imm8
+    InstructionDefinition::new(PINSRB, Map0F3A, 0x20, &[xmm(ReadWrite), mem(Byte, Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F C4 /r ib, PINSRW xmm1, r32, imm8
+    InstructionDefinition::new(
+        PINSRW,
+        Map0F,
+        0xc4,
+        &[xmm(ReadWrite), rm_reg(Dword, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    // 66 0F C4 /r ib, PINSRW xmm1, m16, imm8
+    InstructionDefinition::new(PINSRW, Map0F, 0xc4, &[xmm(ReadWrite), mem(Word, Read), imm(Byte)])
+        .with_mandatory_prefix(0x66)
+        .with_operand_size(Dword),
+    // 66 0F 3A 22 /r ib, PINSRD xmm1, r/m32, imm8
+    InstructionDefinition::new(PINSRD, Map0F3A, 0x22, &[xmm(ReadWrite), rm(Dword, Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    // 66 REX.W 0F 3A 22 /r ib, PINSRQ xmm1, r/m64, imm8
+    InstructionDefinition::new(PINSRQ, Map0F3A, 0x22, &[xmm(ReadWrite), rm(Qword, Read), imm(Byte)])
+        .with_mandatory_prefix(0x66),
+    //
+    // ROUNDPS/ROUNDPD/ROUNDSS/ROUNDSD -- Round Packed/Scalar Values
+    //
+    // The imm8 is the rounding mode, 0 = nearest, 1 = down, 2 = up, 3 = truncate,
+    // and bit 2 selects MXCSR.RC instead.
+    //
+    // 66 0F 3A 08 /r ib, ROUNDPS xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        ROUNDPS,
+        Map0F3A,
+        0x08,
+        &[xmm(Write), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    // 66 0F 3A 09 /r ib, ROUNDPD xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        ROUNDPD,
+        Map0F3A,
+        0x09,
+        &[xmm(Write), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    // 66 0F 3A 0A /r ib, ROUNDSS xmm1, xmm2/m32, imm8
+    InstructionDefinition::new(
+        ROUNDSS,
+        Map0F3A,
+        0x0a,
+        &[xmm(ReadWrite), xmm_m(Dword, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    // 66 0F 3A 0B /r ib, ROUNDSD xmm1, xmm2/m64, imm8
+    InstructionDefinition::new(
+        ROUNDSD,
+        Map0F3A,
+        0x0b,
+        &[xmm(ReadWrite), xmm_m(Qword, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    //
+    // PTEST -- Logical Compare
+    //
+    // 66 0F 38 17 /r, PTEST xmm1, xmm2/m128
+    InstructionDefinition::new(PTEST, Map0F38, 0x17, &[xmm(Read), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    //
+    // PCMPESTRI/PCMPESTRM/PCMPISTRI/PCMPISTRM -- Packed Compare Strings (SSE4.2)
+    //
+    // The explicit-length forms (E) read the lengths from EAX and EDX, the implicit-length
+    // forms (I) stop at the null element. The index forms (I) write ECX and the mask
+    // forms (M) write XMM0.
+    //
+    // 66 0F 3A 60 /r ib, PCMPESTRM xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        PCMPESTRM,
+        Map0F3A,
+        0x60,
+        &[xmm(Read), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_implicit_operands(IMPLICIT_PCMPESTRM),
+    // 66 0F 3A 61 /r ib, PCMPESTRI xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        PCMPESTRI,
+        Map0F3A,
+        0x61,
+        &[xmm(Read), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_implicit_operands(IMPLICIT_PCMPESTRI),
+    // 66 0F 3A 62 /r ib, PCMPISTRM xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        PCMPISTRM,
+        Map0F3A,
+        0x62,
+        &[xmm(Read), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_implicit_operands(IMPLICIT_PCMPISTRM),
+    // 66 0F 3A 63 /r ib, PCMPISTRI xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        PCMPISTRI,
+        Map0F3A,
+        0x63,
+        &[xmm(Read), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_implicit_operands(IMPLICIT_PCMPISTRI),
+    //
+    // CRC32 -- Accumulate CRC32 Value (SSE4.2)
+    //
+    // The destination is always 32-bit or 64-bit, the operand-size prefix (66) of
+    // `CRC32 r32, r/m16` is specified by the operand size.
+    //
+    // F2 0F 38 F0 /r, CRC32 r32, r/m8
+    InstructionDefinition::new(CRC32, Map0F38, 0xf0, &[reg(Dword, ReadWrite), rm(Byte, Read)])
+        .with_mandatory_prefix(0xf2),
+    // 66 F2 0F 38 F1 /r, CRC32 r32, r/m16
+    InstructionDefinition::new(CRC32, Map0F38, 0xf1, &[reg(Dword, ReadWrite), rm(Word, Read)])
+        .with_mandatory_prefix(0xf2)
+        .with_operand_size(Word),
+    // F2 0F 38 F1 /r, CRC32 r32, r/m32
+    InstructionDefinition::new(CRC32, Map0F38, 0xf1, &[reg(Dword, ReadWrite), rm(Dword, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F2 REX.W 0F 38 F0 /r, CRC32 r64, r/m8
+    InstructionDefinition::new(CRC32, Map0F38, 0xf0, &[reg(Qword, ReadWrite), rm(Byte, Read)])
+        .with_mandatory_prefix(0xf2),
+    // F2 REX.W 0F 38 F1 /r, CRC32 r64, r/m64
+    InstructionDefinition::new(CRC32, Map0F38, 0xf1, &[reg(Qword, ReadWrite), rm(Qword, Read)])
+        .with_mandatory_prefix(0xf2),
+    //
+    // AESENC/AESENCLAST/AESDEC/AESDECLAST/AESIMC/AESKEYGENASSIST -- AES Round Instructions
+    //
+    // 66 0F 38 DC /r, AESENC xmm1, xmm2/m128
+    InstructionDefinition::new(AESENC, Map0F38, 0xdc, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 DD /r, AESENCLAST xmm1, xmm2/m128
+    InstructionDefinition::new(AESENCLAST, Map0F38, 0xdd, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 DE /r, AESDEC xmm1, xmm2/m128
+    InstructionDefinition::new(AESDEC, Map0F38, 0xde, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 DF /r, AESDECLAST xmm1, xmm2/m128
+    InstructionDefinition::new(AESDECLAST, Map0F38, 0xdf, &[xmm(ReadWrite), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 38 DB /r, AESIMC xmm1, xmm2/m128
+    InstructionDefinition::new(AESIMC, Map0F38, 0xdb, &[xmm(Write), xmm_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66),
+    // 66 0F 3A DF /r ib, AESKEYGENASSIST xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        AESKEYGENASSIST,
+        Map0F3A,
+        0xdf,
+        &[xmm(Write), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    //
+    // PCLMULQDQ/VPCLMULQDQ -- Carry-Less Multiplication Quadword
+    //
+    // The imm8 selects the quadwords, bit 0 for the first source and bit 4 for the second.
+    //
+    // 66 0F 3A 44 /r ib, PCLMULQDQ xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        PCLMULQDQ,
+        Map0F3A,
+        0x44,
+        &[xmm(ReadWrite), xmm_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66),
+    // VEX.128.66.0F3A.WIG 44 /r ib, VPCLMULQDQ xmm1, xmm2, xmm3/m128, imm8
+    InstructionDefinition::new(
+        VPCLMULQDQ,
+        Map0F3A,
+        0x44,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F3A.WIG 44 /r ib, VPCLMULQDQ ymm1, ymm2, ymm3/m256, imm8
+    InstructionDefinition::new(
+        VPCLMULQDQ,
+        Map0F3A,
+        0x44,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    //
+    // SHA1RNDS4/SHA1NEXTE/SHA1MSG1/SHA1MSG2/SHA256RNDS2/SHA256MSG1/SHA256MSG2 -- SHA Extensions
+    //
+    // NP 0F 3A CC /r ib, SHA1RNDS4 xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        SHA1RNDS4,
+        Map0F3A,
+        0xcc,
+        &[xmm(ReadWrite), xmm_m(XMMWord, Read), imm(Byte)],
+    ),
+    // NP 0F 38 C8 /r, SHA1NEXTE xmm1, xmm2/m128
+    InstructionDefinition::new(SHA1NEXTE, Map0F38, 0xc8, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // NP 0F 38 C9 /r, SHA1MSG1 xmm1, xmm2/m128
+    InstructionDefinition::new(SHA1MSG1, Map0F38, 0xc9, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // NP 0F 38 CA /r, SHA1MSG2 xmm1, xmm2/m128
+    InstructionDefinition::new(SHA1MSG2, Map0F38, 0xca, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // The implicit XMM0 is the sum of the message and the round constants.
+    // NP 0F 38 CB /r, SHA256RNDS2 xmm1, xmm2/m128, <XMM0>
+    InstructionDefinition::new(
+        SHA256RNDS2,
+        Map0F38,
+        0xcb,
+        &[xmm(ReadWrite), xmm_m(XMMWord, Read), fixed(XMM0, Read)],
+    ),
+    // NP 0F 38 CC /r, SHA256MSG1 xmm1, xmm2/m128
+    InstructionDefinition::new(SHA256MSG1, Map0F38, 0xcc, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    // NP 0F 38 CD /r, SHA256MSG2 xmm1, xmm2/m128
+    InstructionDefinition::new(SHA256MSG2, Map0F38, 0xcd, &[xmm(ReadWrite), xmm_m(XMMWord, Read)]),
+    //
+    // AVX/AVX2 -- Advanced Vector Extensions
+    //
+    // They are encoded with the VEX prefix, the 128-bit form (VEX.128) uses XMM
+    // registers and the 256-bit form (VEX.256) uses YMM registers. Most of them are
+    // non-destructive, the first source is encoded in VEX.vvvv,
+    // e.g. `VADDPS ymm1, ymm2, ymm3/m256`.
+    //
+    // VMOVAPS/VMOVUPS/VMOVAPD/VMOVUPD/VMOVDQA/VMOVDQU -- Move Aligned/Unaligned Values
+    //
+    // VEX.128.0F.WIG 28 /r, VMOVAPS xmm1, xmm2/m128
+    InstructionDefinition::new(VMOVAPS, Map0F, 0x28, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_vex(),
+    // VEX.128.0F.WIG 29 /r, VMOVAPS xmm2/m128, xmm1
+    InstructionDefinition::new(VMOVAPS, Map0F, 0x29, &[avx_m(XMMWord, Write), avx(XMMWord, Read)])
+        .with_vex(),
+    // VEX.256.0F.WIG 28 /r, VMOVAPS ymm1, ymm2/m256
+    InstructionDefinition::new(VMOVAPS, Map0F, 0x28, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_vex()
+        .with_l(1),
+    // VEX.256.0F.WIG 29 /r, VMOVAPS ymm2/m256, ymm1
+    InstructionDefinition::new(VMOVAPS, Map0F, 0x29, &[avx_m(YMMWord, Write), avx(YMMWord, Read)])
+        .with_vex()
+        .with_l(1),
+    // VEX.128.0F.WIG 10 /r, VMOVUPS xmm1, xmm2/m128
+    InstructionDefinition::new(VMOVUPS, Map0F, 0x10, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_vex(),
+    // VEX.128.0F.WIG 11 /r, VMOVUPS xmm2/m128, xmm1
+    InstructionDefinition::new(VMOVUPS, Map0F, 0x11, &[avx_m(XMMWord, Write), avx(XMMWord, Read)])
+        .with_vex(),
+    // VEX.256.0F.WIG 10 /r, VMOVUPS ymm1, ymm2/m256
+    InstructionDefinition::new(VMOVUPS, Map0F, 0x10, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_vex()
+        .with_l(1),
+    // VEX.256.0F.WIG 11 /r, VMOVUPS ymm2/m256, ymm1
+    InstructionDefinition::new(VMOVUPS, Map0F, 0x11, &[avx_m(YMMWord, Write), avx(YMMWord, Read)])
+        .with_vex()
+        .with_l(1),
+    // VEX.128.66.0F.WIG 28 /r, VMOVAPD xmm1, xmm2/m128
+    InstructionDefinition::new(VMOVAPD, Map0F, 0x28, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.128.66.0F.WIG 29 /r, VMOVAPD xmm2/m128, xmm1
+    InstructionDefinition::new(VMOVAPD, Map0F, 0x29, &[avx_m(XMMWord, Write), avx(XMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.256.66.0F.WIG 28 /r, VMOVAPD ymm1, ymm2/m256
+    InstructionDefinition::new(VMOVAPD, Map0F, 0x28, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex()
+        .with_l(1),
+    // VEX.256.66.0F.WIG 29 /r, VMOVAPD ymm2/m256, ymm1
+    InstructionDefinition::new(VMOVAPD, Map0F, 0x29, &[avx_m(YMMWord, Write), avx(YMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex()
+        .with_l(1),
+    // VEX.128.66.0F.WIG 10 /r, VMOVUPD xmm1, xmm2/m128
+    InstructionDefinition::new(VMOVUPD, Map0F, 0x10, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.128.66.0F.WIG 11 /r, VMOVUPD xmm2/m128, xmm1
+    InstructionDefinition::new(VMOVUPD, Map0F, 0x11, &[avx_m(XMMWord, Write), avx(XMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.256.66.0F.WIG 10 /r, VMOVUPD ymm1, ymm2/m256
+    InstructionDefinition::new(VMOVUPD, Map0F, 0x10, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex()
+        .with_l(1),
+    // VEX.256.66.0F.WIG 11 /r, VMOVUPD ymm2/m256, ymm1
+    InstructionDefinition::new(VMOVUPD, Map0F, 0x11, &[avx_m(YMMWord, Write), avx(YMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex()
+        .with_l(1),
+    // VEX.128.66.0F.WIG 6F /r, VMOVDQA xmm1, xmm2/m128
+    InstructionDefinition::new(VMOVDQA, Map0F, 0x6f, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.128.66.0F.WIG 7F /r, VMOVDQA xmm2/m128, xmm1
+    InstructionDefinition::new(VMOVDQA, Map0F, 0x7f, &[avx_m(XMMWord, Write), avx(XMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.256.66.0F.WIG 6F /r, VMOVDQA ymm1, ymm2/m256
+    InstructionDefinition::new(VMOVDQA, Map0F, 0x6f, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex()
+        .with_l(1),
+    // VEX.256.66.0F.WIG 7F /r, VMOVDQA ymm2/m256, ymm1
+    InstructionDefinition::new(VMOVDQA, Map0F, 0x7f, &[avx_m(YMMWord, Write), avx(YMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex()
+        .with_l(1),
+    // VEX.128.F3.0F.WIG 6F /r, VMOVDQU xmm1, xmm2/m128
+    InstructionDefinition::new(VMOVDQU, Map0F, 0x6f, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_mandatory_prefix(0xf3)
+        .with_vex(),
+    // VEX.128.F3.0F.WIG 7F /r, VMOVDQU xmm2/m128, xmm1
+    InstructionDefinition::new(VMOVDQU, Map0F, 0x7f, &[avx_m(XMMWord, Write), avx(XMMWord, Read)])
+        .with_mandatory_prefix(0xf3)
+        .with_vex(),
+    // VEX.256.F3.0F.WIG 6F /r, VMOVDQU ymm1, ymm2/m256
+    InstructionDefinition::new(VMOVDQU, Map0F, 0x6f, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_mandatory_prefix(0xf3)
+        .with_vex()
+        .with_l(1),
+    // VEX.256.F3.0F.WIG 7F /r, VMOVDQU ymm2/m256, ymm1
+    InstructionDefinition::new(VMOVDQU, Map0F, 0x7f, &[avx_m(YMMWord, Write), avx(YMMWord, Read)])
+        .with_mandatory_prefix(0xf3)
+        .with_vex()
+        .with_l(1),
+    //
+    // VADDPS/VADDPD/VADDSS/VADDSD -- Add Floating-Point Values
+    //
+    // VEX.128.0F.WIG 58 /r, VADDPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VADDPS,
+        Map0F,
+        0x58,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 58 /r, VADDPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VADDPS,
+        Map0F,
+        0x58,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 58 /r, VADDPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VADDPD,
+        Map0F,
+        0x58,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 58 /r, VADDPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VADDPD,
+        Map0F,
+        0x58,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.F3.0F.WIG 58 /r, VADDSS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VADDSS,
+        Map0F,
+        0x58,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.128.F2.0F.WIG 58 /r, VADDSD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VADDSD,
+        Map0F,
+        0x58,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // VSUBPS/VSUBPD/VSUBSS/VSUBSD -- Subtract Floating-Point Values
+    //
+    // VEX.128.0F.WIG 5C /r, VSUBPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VSUBPS,
+        Map0F,
+        0x5c,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 5C /r, VSUBPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VSUBPS,
+        Map0F,
+        0x5c,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 5C /r, VSUBPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VSUBPD,
+        Map0F,
+        0x5c,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 5C /r, VSUBPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VSUBPD,
+        Map0F,
+        0x5c,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.F3.0F.WIG 5C /r, VSUBSS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VSUBSS,
+        Map0F,
+        0x5c,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.128.F2.0F.WIG 5C /r, VSUBSD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VSUBSD,
+        Map0F,
+        0x5c,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // VMULPS/VMULPD/VMULSS/VMULSD -- Multiply Floating-Point Values
+    //
+    // VEX.128.0F.WIG 59 /r, VMULPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VMULPS,
+        Map0F,
+        0x59,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 59 /r, VMULPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VMULPS,
+        Map0F,
+        0x59,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 59 /r, VMULPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VMULPD,
+        Map0F,
+        0x59,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 59 /r, VMULPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VMULPD,
+        Map0F,
+        0x59,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.F3.0F.WIG 59 /r, VMULSS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VMULSS,
+        Map0F,
+        0x59,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.128.F2.0F.WIG 59 /r, VMULSD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VMULSD,
+        Map0F,
+        0x59,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // VDIVPS/VDIVPD/VDIVSS/VDIVSD -- Divide Floating-Point Values
+    //
+    // VEX.128.0F.WIG 5E /r, VDIVPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VDIVPS,
+        Map0F,
+        0x5e,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 5E /r, VDIVPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VDIVPS,
+        Map0F,
+        0x5e,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 5E /r, VDIVPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VDIVPD,
+        Map0F,
+        0x5e,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 5E /r, VDIVPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VDIVPD,
+        Map0F,
+        0x5e,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.F3.0F.WIG 5E /r, VDIVSS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VDIVSS,
+        Map0F,
+        0x5e,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.128.F2.0F.WIG 5E /r, VDIVSD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VDIVSD,
+        Map0F,
+        0x5e,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // VMINPS/VMINPD/VMINSS/VMINSD -- Minimum Floating-Point Values
+    //
+    // VEX.128.0F.WIG 5D /r, VMINPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VMINPS,
+        Map0F,
+        0x5d,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 5D /r, VMINPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VMINPS,
+        Map0F,
+        0x5d,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 5D /r, VMINPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VMINPD,
+        Map0F,
+        0x5d,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 5D /r, VMINPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VMINPD,
+        Map0F,
+        0x5d,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.F3.0F.WIG 5D /r, VMINSS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VMINSS,
+        Map0F,
+        0x5d,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.128.F2.0F.WIG 5D /r, VMINSD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VMINSD,
+        Map0F,
+        0x5d,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // VMAXPS/VMAXPD/VMAXSS/VMAXSD -- Maximum Floating-Point Values
+    //
+    // VEX.128.0F.WIG 5F /r, VMAXPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VMAXPS,
+        Map0F,
+        0x5f,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 5F /r, VMAXPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VMAXPS,
+        Map0F,
+        0x5f,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 5F /r, VMAXPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VMAXPD,
+        Map0F,
+        0x5f,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 5F /r, VMAXPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VMAXPD,
+        Map0F,
+        0x5f,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.F3.0F.WIG 5F /r, VMAXSS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VMAXSS,
+        Map0F,
+        0x5f,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.128.F2.0F.WIG 5F /r, VMAXSD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VMAXSD,
+        Map0F,
+        0x5f,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // VSQRTPS/VSQRTPD/VSQRTSS/VSQRTSD -- Square Root of Floating-Point Values
+    //
+    // VEX.128.0F.WIG 51 /r, VSQRTPS xmm1, xmm2/m128
+    InstructionDefinition::new(VSQRTPS, Map0F, 0x51, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_vex(),
+    // VEX.256.0F.WIG 51 /r, VSQRTPS ymm1, ymm2/m256
+    InstructionDefinition::new(VSQRTPS, Map0F, 0x51, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_vex()
+        .with_l(1),
+    // VEX.128.66.0F.WIG 51 /r, VSQRTPD xmm1, xmm2/m128
+    InstructionDefinition::new(VSQRTPD, Map0F, 0x51, &[avx(XMMWord, Write), avx_m(XMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.256.66.0F.WIG 51 /r, VSQRTPD ymm1, ymm2/m256
+    InstructionDefinition::new(VSQRTPD, Map0F, 0x51, &[avx(YMMWord, Write), avx_m(YMMWord, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex()
+        .with_l(1),
+    // VEX.128.F3.0F.WIG 51 /r, VSQRTSS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VSQRTSS,
+        Map0F,
+        0x51,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0xf3)
+    .with_vex(),
+    // VEX.128.F2.0F.WIG 51 /r, VSQRTSD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VSQRTSD,
+        Map0F,
+        0x51,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0xf2)
+    .with_vex(),
+    //
+    // VANDPS/VANDPD/VANDNPS/VANDNPD/VORPS/VORPD/VXORPS/VXORPD -- Bitwise Logical Operations
+    //
+    // VEX.128.0F.WIG 54 /r, VANDPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VANDPS,
+        Map0F,
+        0x54,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 54 /r, VANDPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VANDPS,
+        Map0F,
+        0x54,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 54 /r, VANDPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VANDPD,
+        Map0F,
+        0x54,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 54 /r, VANDPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VANDPD,
+        Map0F,
+        0x54,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.0F.WIG 55 /r, VANDNPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VANDNPS,
+        Map0F,
+        0x55,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 55 /r, VANDNPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VANDNPS,
+        Map0F,
+        0x55,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 55 /r, VANDNPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VANDNPD,
+        Map0F,
+        0x55,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 55 /r, VANDNPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VANDNPD,
+        Map0F,
+        0x55,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.0F.WIG 56 /r, VORPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VORPS,
+        Map0F,
+        0x56,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 56 /r, VORPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VORPS,
+        Map0F,
+        0x56,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 56 /r, VORPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VORPD,
+        Map0F,
+        0x56,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 56 /r, VORPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VORPD,
+        Map0F,
+        0x56,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.0F.WIG 57 /r, VXORPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VXORPS,
+        Map0F,
+        0x57,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_vex(),
+    // VEX.256.0F.WIG 57 /r, VXORPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VXORPS,
+        Map0F,
+        0x57,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 57 /r, VXORPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VXORPD,
+        Map0F,
+        0x57,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 57 /r, VXORPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VXORPD,
+        Map0F,
+        0x57,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    //
+    // VBROADCASTSS/VBROADCASTSD/VPBROADCASTB/W/D/Q -- Broadcast a Value
+    //
+    // The register source (e.g. `VBROADCASTSS xmm1, xmm2`) requires AVX2.
+    //
+    // VEX.128.66.0F38.W0 18 /r, VBROADCASTSS xmm1, xmm2/m32
+    InstructionDefinition::new(
+        VBROADCASTSS,
+        Map0F38,
+        0x18,
+        &[avx(XMMWord, Write), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 18 /r, VBROADCASTSS ymm1, xmm2/m32
+    InstructionDefinition::new(
+        VBROADCASTSS,
+        Map0F38,
+        0x18,
+        &[avx(YMMWord, Write), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F38.W0 19 /r, VBROADCASTSD ymm1, xmm2/m64
+    InstructionDefinition::new(
+        VBROADCASTSD,
+        Map0F38,
+        0x19,
+        &[avx(YMMWord, Write), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 78 /r, VPBROADCASTB xmm1, xmm2/m8
+    InstructionDefinition::new(
+        VPBROADCASTB,
+        Map0F38,
+        0x78,
+        &[avx(XMMWord, Write), avx_m(Byte, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 78 /r, VPBROADCASTB ymm1, xmm2/m8
+    InstructionDefinition::new(
+        VPBROADCASTB,
+        Map0F38,
+        0x78,
+        &[avx(YMMWord, Write), avx_m(Byte, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 79 /r, VPBROADCASTW xmm1, xmm2/m16
+    InstructionDefinition::new(
+        VPBROADCASTW,
+        Map0F38,
+        0x79,
+        &[avx(XMMWord, Write), avx_m(Word, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 79 /r, VPBROADCASTW ymm1, xmm2/m16
+    InstructionDefinition::new(
+        VPBROADCASTW,
+        Map0F38,
+        0x79,
+        &[avx(YMMWord, Write), avx_m(Word, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 58 /r, VPBROADCASTD xmm1, xmm2/m32
+    InstructionDefinition::new(
+        VPBROADCASTD,
+        Map0F38,
+        0x58,
+        &[avx(XMMWord, Write), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 58 /r, VPBROADCASTD ymm1, xmm2/m32
+    InstructionDefinition::new(
+        VPBROADCASTD,
+        Map0F38,
+        0x58,
+        &[avx(YMMWord, Write), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 59 /r, VPBROADCASTQ xmm1, xmm2/m64
+    InstructionDefinition::new(
+        VPBROADCASTQ,
+        Map0F38,
+        0x59,
+        &[avx(XMMWord, Write), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 59 /r, VPBROADCASTQ ymm1, xmm2/m64
+    InstructionDefinition::new(
+        VPBROADCASTQ,
+        Map0F38,
+        0x59,
+        &[avx(YMMWord, Write), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    //
+    // VINSERTF128/VEXTRACTF128/VINSERTI128/VEXTRACTI128 -- Insert/Extract Packed Values
+    //
+    // VEX.256.66.0F3A.W0 18 /r ib, VINSERTF128 ymm1, ymm2, xmm3/m128, imm8
+    InstructionDefinition::new(
+        VINSERTF128,
+        Map0F3A,
+        0x18,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F3A.W0 38 /r ib, VINSERTI128 ymm1, ymm2, xmm3/m128, imm8
+    InstructionDefinition::new(
+        VINSERTI128,
+        Map0F3A,
+        0x38,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F3A.W0 19 /r ib, VEXTRACTF128 xmm1/m128, ymm2, imm8
+    InstructionDefinition::new(
+        VEXTRACTF128,
+        Map0F3A,
+        0x19,
+        &[avx_m(XMMWord, Write), avx(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F3A.W0 39 /r ib, VEXTRACTI128 xmm1/m128, ymm2, imm8
+    InstructionDefinition::new(
+        VEXTRACTI128,
+        Map0F3A,
+        0x39,
+        &[avx_m(XMMWord, Write), avx(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    //
+    // VPERMILPS/VPERMILPD/VPERM2F128/VPERM2I128/VPERMD/VPERMPS/VPERMQ/VPERMPD -- Permute Values
+    //
+    // VEX.128.66.0F38.W0 0C /r, VPERMILPS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPERMILPS,
+        Map0F38,
+        0x0c,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 0C /r, VPERMILPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPERMILPS,
+        Map0F38,
+        0x0c,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F3A.W0 04 /r ib, VPERMILPS xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        VPERMILPS,
+        Map0F3A,
+        0x04,
+        &[avx(XMMWord, Write), avx_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F3A.W0 04 /r ib, VPERMILPS ymm1, ymm2/m256, imm8
+    InstructionDefinition::new(
+        VPERMILPS,
+        Map0F3A,
+        0x04,
+        &[avx(YMMWord, Write), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 0D /r, VPERMILPD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPERMILPD,
+        Map0F38,
+        0x0d,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 0D /r, VPERMILPD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPERMILPD,
+        Map0F38,
+        0x0d,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F3A.W0 05 /r ib, VPERMILPD xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        VPERMILPD,
+        Map0F3A,
+        0x05,
+        &[avx(XMMWord, Write), avx_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F3A.W0 05 /r ib, VPERMILPD ymm1, ymm2/m256, imm8
+    InstructionDefinition::new(
+        VPERMILPD,
+        Map0F3A,
+        0x05,
+        &[avx(YMMWord, Write), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F3A.W0 06 /r ib, VPERM2F128 ymm1, ymm2, ymm3/m256, imm8
+    InstructionDefinition::new(
+        VPERM2F128,
+        Map0F3A,
+        0x06,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F3A.W0 46 /r ib, VPERM2I128 ymm1, ymm2, ymm3/m256, imm8
+    InstructionDefinition::new(
+        VPERM2I128,
+        Map0F3A,
+        0x46,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F38.W0 36 /r, VPERMD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPERMD,
+        Map0F38,
+        0x36,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F38.W0 16 /r, VPERMPS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPERMPS,
+        Map0F38,
+        0x16,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.256.66.0F3A.W1 00 /r ib, VPERMQ ymm1, ymm2/m256, imm8
+    InstructionDefinition::new(
+        VPERMQ,
+        Map0F3A,
+        0x00,
+        &[avx(YMMWord, Write), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.256.66.0F3A.W1 01 /r ib, VPERMPD ymm1, ymm2/m256, imm8
+    InstructionDefinition::new(
+        VPERMPD,
+        Map0F3A,
+        0x01,
+        &[avx(YMMWord, Write), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    //
+    // VPADD/VPSUB/VPAND/VPANDN/VPOR/VPXOR/VPCMPEQ/VPMULLD/VPSHUFB -- Packed Integer Operations
+    //
+    // The 256-bit forms require AVX2.
+    //
+    // VEX.128.66.0F.WIG FC /r, VPADDB xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPADDB,
+        Map0F,
+        0xfc,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG FC /r, VPADDB ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPADDB,
+        Map0F,
+        0xfc,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG FD /r, VPADDW xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPADDW,
+        Map0F,
+        0xfd,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG FD /r, VPADDW ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPADDW,
+        Map0F,
+        0xfd,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG FE /r, VPADDD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPADDD,
+        Map0F,
+        0xfe,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG FE /r, VPADDD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPADDD,
+        Map0F,
+        0xfe,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG D4 /r, VPADDQ xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPADDQ,
+        Map0F,
+        0xd4,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG D4 /r, VPADDQ ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPADDQ,
+        Map0F,
+        0xd4,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG F8 /r, VPSUBB xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPSUBB,
+        Map0F,
+        0xf8,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG F8 /r, VPSUBB ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPSUBB,
+        Map0F,
+        0xf8,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG F9 /r, VPSUBW xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPSUBW,
+        Map0F,
+        0xf9,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG F9 /r, VPSUBW ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPSUBW,
+        Map0F,
+        0xf9,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG FA /r, VPSUBD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPSUBD,
+        Map0F,
+        0xfa,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG FA /r, VPSUBD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPSUBD,
+        Map0F,
+        0xfa,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG FB /r, VPSUBQ xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPSUBQ,
+        Map0F,
+        0xfb,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG FB /r, VPSUBQ ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPSUBQ,
+        Map0F,
+        0xfb,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG DB /r, VPAND xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPAND,
+        Map0F,
+        0xdb,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG DB /r, VPAND ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPAND,
+        Map0F,
+        0xdb,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG DF /r, VPANDN xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPANDN,
+        Map0F,
+        0xdf,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG DF /r, VPANDN ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPANDN,
+        Map0F,
+        0xdf,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG EB /r, VPOR xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPOR,
+        Map0F,
+        0xeb,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG EB /r, VPOR ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPOR,
+        Map0F,
+        0xeb,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG EF /r, VPXOR xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPXOR,
+        Map0F,
+        0xef,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG EF /r, VPXOR ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPXOR,
+        Map0F,
+        0xef,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 74 /r, VPCMPEQB xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPCMPEQB,
+        Map0F,
+        0x74,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 74 /r, VPCMPEQB ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPCMPEQB,
+        Map0F,
+        0x74,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 75 /r, VPCMPEQW xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPCMPEQW,
+        Map0F,
+        0x75,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 75 /r, VPCMPEQW ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPCMPEQW,
+        Map0F,
+        0x75,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 76 /r, VPCMPEQD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPCMPEQD,
+        Map0F,
+        0x76,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 76 /r, VPCMPEQD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPCMPEQD,
+        Map0F,
+        0x76,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.WIG 29 /r, VPCMPEQQ xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPCMPEQQ,
+        Map0F38,
+        0x29,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.WIG 29 /r, VPCMPEQQ ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPCMPEQQ,
+        Map0F38,
+        0x29,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.WIG 40 /r, VPMULLD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPMULLD,
+        Map0F38,
+        0x40,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.WIG 40 /r, VPMULLD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPMULLD,
+        Map0F38,
+        0x40,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.WIG 00 /r, VPSHUFB xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPSHUFB,
+        Map0F38,
+        0x00,
+        &[avx(XMMWord, Write), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.WIG 00 /r, VPSHUFB ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPSHUFB,
+        Map0F38,
+        0x00,
+        &[avx(YMMWord, Write), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F.WIG 70 /r ib, VPSHUFD xmm1, xmm2/m128, imm8
+    InstructionDefinition::new(
+        VPSHUFD,
+        Map0F,
+        0x70,
+        &[avx(XMMWord, Write), avx_m(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F.WIG 70 /r ib, VPSHUFD ymm1, ymm2/m256, imm8
+    InstructionDefinition::new(
+        VPSHUFD,
+        Map0F,
+        0x70,
+        &[avx(YMMWord, Write), avx_m(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    //
+    // VGATHER*/VPGATHER* -- Gather Packed Values Using Signed Dword/Qword Indices
+    //
+    // The elements are loaded from [base + index[i]*scale] (VSIB) if the highest bit of
+    // the corresponding element of the mask (VEX.vvvv) is set, the mask is cleared
+    // after the load.
+    //
+    // VEX.128.66.0F38.W0 92 /r, VGATHERDPS xmm1, vm32x, xmm2
+    InstructionDefinition::new(
+        VGATHERDPS,
+        Map0F38,
+        0x92,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 92 /r, VGATHERDPS ymm1, vm32y, ymm2
+    InstructionDefinition::new(
+        VGATHERDPS,
+        Map0F38,
+        0x92,
+        &[avx(YMMWord, ReadWrite), vsib_y(), avx_vex(YMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 93 /r, VGATHERQPS xmm1, vm64x, xmm2
+    InstructionDefinition::new(
+        VGATHERQPS,
+        Map0F38,
+        0x93,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 93 /r, VGATHERQPS xmm1, vm64y, xmm2
+    InstructionDefinition::new(
+        VGATHERQPS,
+        Map0F38,
+        0x93,
+        &[avx(XMMWord, ReadWrite), vsib_y(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 92 /r, VGATHERDPD xmm1, vm32x, xmm2
+    InstructionDefinition::new(
+        VGATHERDPD,
+        Map0F38,
+        0x92,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 92 /r, VGATHERDPD ymm1, vm32x, ymm2
+    InstructionDefinition::new(
+        VGATHERDPD,
+        Map0F38,
+        0x92,
+        &[avx(YMMWord, ReadWrite), vsib_x(), avx_vex(YMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.128.66.0F38.W1 93 /r, VGATHERQPD xmm1, vm64x, xmm2
+    InstructionDefinition::new(
+        VGATHERQPD,
+        Map0F38,
+        0x93,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 93 /r, VGATHERQPD ymm1, vm64y, ymm2
+    InstructionDefinition::new(
+        VGATHERQPD,
+        Map0F38,
+        0x93,
+        &[avx(YMMWord, ReadWrite), vsib_y(), avx_vex(YMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.128.66.0F38.W0 90 /r, VPGATHERDD xmm1, vm32x, xmm2
+    InstructionDefinition::new(
+        VPGATHERDD,
+        Map0F38,
+        0x90,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 90 /r, VPGATHERDD ymm1, vm32y, ymm2
+    InstructionDefinition::new(
+        VPGATHERDD,
+        Map0F38,
+        0x90,
+        &[avx(YMMWord, ReadWrite), vsib_y(), avx_vex(YMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 91 /r, VPGATHERQD xmm1, vm64x, xmm2
+    InstructionDefinition::new(
+        VPGATHERQD,
+        Map0F38,
+        0x91,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 91 /r, VPGATHERQD xmm1, vm64y, xmm2
+    InstructionDefinition::new(
+        VPGATHERQD,
+        Map0F38,
+        0x91,
+        &[avx(XMMWord, ReadWrite), vsib_y(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 90 /r, VPGATHERDQ xmm1, vm32x, xmm2
+    InstructionDefinition::new(
+        VPGATHERDQ,
+        Map0F38,
+        0x90,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 90 /r, VPGATHERDQ ymm1, vm32x, ymm2
+    InstructionDefinition::new(
+        VPGATHERDQ,
+        Map0F38,
+        0x90,
+        &[avx(YMMWord, ReadWrite), vsib_x(), avx_vex(YMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.128.66.0F38.W1 91 /r, VPGATHERQQ xmm1, vm64x, xmm2
+    InstructionDefinition::new(
+        VPGATHERQQ,
+        Map0F38,
+        0x91,
+        &[avx(XMMWord, ReadWrite), vsib_x(), avx_vex(XMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 91 /r, VPGATHERQQ ymm1, vm64y, ymm2
+    InstructionDefinition::new(
+        VPGATHERQQ,
+        Map0F38,
+        0x91,
+        &[avx(YMMWord, ReadWrite), vsib_y(), avx_vex(YMMWord, ReadWrite)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    //
+    // VZEROUPPER/VZEROALL -- Zero Upper Bits of YMM Registers/Zero All YMM Registers
+    //
+    // VEX.128.0F.WIG 77, VZEROUPPER
+    InstructionDefinition::new(VZEROUPPER, Map0F, 0x77, &[])
+        .with_vex(),
+    // VEX.256.0F.WIG 77, VZEROALL
+    InstructionDefinition::new(VZEROALL, Map0F, 0x77, &[])
+        .with_vex()
+        .with_l(1),
+    //
+    // VFMADD132/VFMADD213/VFMADD231 -- Fused Multiply-Add of Packed/Scalar Values
+    //
+    // The digits are the order of the operands, e.g. `132` multiplies the operands 1 and 3
+    // and then adds the operand 2:
+    //
+    // - 132: op1 := op1 * op3 + op2
+    // - 213: op1 := op2 * op1 + op3
+    // - 231: op1 := op2 * op3 + op1
+    //
+    // The operands are always encoded as op1 = ModRM:reg, op2 = VEX.vvvv and
+    // op3 = ModRM:r/m, only the opcode differs.
+    //
+    // VEX.128.66.0F38.W0 98 /r, VFMADD132PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMADD132PS,
+        Map0F38,
+        0x98,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 98 /r, VFMADD132PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMADD132PS,
+        Map0F38,
+        0x98,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 98 /r, VFMADD132PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMADD132PD,
+        Map0F38,
+        0x98,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 98 /r, VFMADD132PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMADD132PD,
+        Map0F38,
+        0x98,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 99 /r, VFMADD132SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFMADD132SS,
+        Map0F38,
+        0x99,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 99 /r, VFMADD132SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFMADD132SD,
+        Map0F38,
+        0x99,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 A8 /r, VFMADD213PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMADD213PS,
+        Map0F38,
+        0xa8,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 A8 /r, VFMADD213PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMADD213PS,
+        Map0F38,
+        0xa8,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 A8 /r, VFMADD213PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMADD213PD,
+        Map0F38,
+        0xa8,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 A8 /r, VFMADD213PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMADD213PD,
+        Map0F38,
+        0xa8,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 A9 /r, VFMADD213SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFMADD213SS,
+        Map0F38,
+        0xa9,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 A9 /r, VFMADD213SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFMADD213SD,
+        Map0F38,
+        0xa9,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 B8 /r, VFMADD231PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMADD231PS,
+        Map0F38,
+        0xb8,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 B8 /r, VFMADD231PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMADD231PS,
+        Map0F38,
+        0xb8,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 B8 /r, VFMADD231PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMADD231PD,
+        Map0F38,
+        0xb8,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 B8 /r, VFMADD231PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMADD231PD,
+        Map0F38,
+        0xb8,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 B9 /r, VFMADD231SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFMADD231SS,
+        Map0F38,
+        0xb9,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 B9 /r, VFMADD231SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFMADD231SD,
+        Map0F38,
+        0xb9,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    //
+    // VFMSUB132/VFMSUB213/VFMSUB231 -- Fused Multiply-Subtract of Packed/Scalar Values
+    //
+    // VEX.128.66.0F38.W0 9A /r, VFMSUB132PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMSUB132PS,
+        Map0F38,
+        0x9a,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 9A /r, VFMSUB132PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMSUB132PS,
+        Map0F38,
+        0x9a,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 9A /r, VFMSUB132PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMSUB132PD,
+        Map0F38,
+        0x9a,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 9A /r, VFMSUB132PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMSUB132PD,
+        Map0F38,
+        0x9a,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 9B /r, VFMSUB132SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFMSUB132SS,
+        Map0F38,
+        0x9b,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 9B /r, VFMSUB132SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFMSUB132SD,
+        Map0F38,
+        0x9b,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 AA /r, VFMSUB213PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMSUB213PS,
+        Map0F38,
+        0xaa,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 AA /r, VFMSUB213PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMSUB213PS,
+        Map0F38,
+        0xaa,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 AA /r, VFMSUB213PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMSUB213PD,
+        Map0F38,
+        0xaa,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 AA /r, VFMSUB213PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMSUB213PD,
+        Map0F38,
+        0xaa,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 AB /r, VFMSUB213SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFMSUB213SS,
+        Map0F38,
+        0xab,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 AB /r, VFMSUB213SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFMSUB213SD,
+        Map0F38,
+        0xab,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 BA /r, VFMSUB231PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMSUB231PS,
+        Map0F38,
+        0xba,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 BA /r, VFMSUB231PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMSUB231PS,
+        Map0F38,
+        0xba,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 BA /r, VFMSUB231PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFMSUB231PD,
+        Map0F38,
+        0xba,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 BA /r, VFMSUB231PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFMSUB231PD,
+        Map0F38,
+        0xba,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 BB /r, VFMSUB231SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFMSUB231SS,
+        Map0F38,
+        0xbb,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 BB /r, VFMSUB231SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFMSUB231SD,
+        Map0F38,
+        0xbb,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    //
+    // VFNMADD132/VFNMADD213/VFNMADD231 -- Fused Negative Multiply-Add of Packed/Scalar Values
+    //
+    // VEX.128.66.0F38.W0 9C /r, VFNMADD132PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMADD132PS,
+        Map0F38,
+        0x9c,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 9C /r, VFNMADD132PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMADD132PS,
+        Map0F38,
+        0x9c,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 9C /r, VFNMADD132PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMADD132PD,
+        Map0F38,
+        0x9c,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 9C /r, VFNMADD132PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMADD132PD,
+        Map0F38,
+        0x9c,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 9D /r, VFNMADD132SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFNMADD132SS,
+        Map0F38,
+        0x9d,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 9D /r, VFNMADD132SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFNMADD132SD,
+        Map0F38,
+        0x9d,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 AC /r, VFNMADD213PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMADD213PS,
+        Map0F38,
+        0xac,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 AC /r, VFNMADD213PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMADD213PS,
+        Map0F38,
+        0xac,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 AC /r, VFNMADD213PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMADD213PD,
+        Map0F38,
+        0xac,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 AC /r, VFNMADD213PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMADD213PD,
+        Map0F38,
+        0xac,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 AD /r, VFNMADD213SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFNMADD213SS,
+        Map0F38,
+        0xad,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 AD /r, VFNMADD213SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFNMADD213SD,
+        Map0F38,
+        0xad,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 BC /r, VFNMADD231PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMADD231PS,
+        Map0F38,
+        0xbc,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 BC /r, VFNMADD231PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMADD231PS,
+        Map0F38,
+        0xbc,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 BC /r, VFNMADD231PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMADD231PD,
+        Map0F38,
+        0xbc,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 BC /r, VFNMADD231PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMADD231PD,
+        Map0F38,
+        0xbc,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 BD /r, VFNMADD231SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFNMADD231SS,
+        Map0F38,
+        0xbd,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 BD /r, VFNMADD231SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFNMADD231SD,
+        Map0F38,
+        0xbd,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    //
+    // VFNMSUB132/VFNMSUB213/VFNMSUB231 -- Fused Negative Multiply-Subtract of Packed/Scalar Values
+    //
+    // VEX.128.66.0F38.W0 9E /r, VFNMSUB132PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMSUB132PS,
+        Map0F38,
+        0x9e,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 9E /r, VFNMSUB132PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMSUB132PS,
+        Map0F38,
+        0x9e,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 9E /r, VFNMSUB132PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMSUB132PD,
+        Map0F38,
+        0x9e,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 9E /r, VFNMSUB132PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMSUB132PD,
+        Map0F38,
+        0x9e,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 9F /r, VFNMSUB132SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFNMSUB132SS,
+        Map0F38,
+        0x9f,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 9F /r, VFNMSUB132SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFNMSUB132SD,
+        Map0F38,
+        0x9f,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 AE /r, VFNMSUB213PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMSUB213PS,
+        Map0F38,
+        0xae,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 AE /r, VFNMSUB213PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMSUB213PS,
+        Map0F38,
+        0xae,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 AE /r, VFNMSUB213PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMSUB213PD,
+        Map0F38,
+        0xae,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 AE /r, VFNMSUB213PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMSUB213PD,
+        Map0F38,
+        0xae,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 AF /r, VFNMSUB213SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFNMSUB213SS,
+        Map0F38,
+        0xaf,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 AF /r, VFNMSUB213SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFNMSUB213SD,
+        Map0F38,
+        0xaf,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.128.66.0F38.W0 BE /r, VFNMSUB231PS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMSUB231PS,
+        Map0F38,
+        0xbe,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 BE /r, VFNMSUB231PS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMSUB231PS,
+        Map0F38,
+        0xbe,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W1 BE /r, VFNMSUB231PD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VFNMSUB231PD,
+        Map0F38,
+        0xbe,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    // VEX.256.66.0F38.W1 BE /r, VFNMSUB231PD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VFNMSUB231PD,
+        Map0F38,
+        0xbe,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1)
+    .with_w(),
+    // VEX.LIG.66.0F38.W0 BF /r, VFNMSUB231SS xmm1, xmm2, xmm3/m32
+    InstructionDefinition::new(
+        VFNMSUB231SS,
+        Map0F38,
+        0xbf,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Dword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.LIG.66.0F38.W1 BF /r, VFNMSUB231SD xmm1, xmm2, xmm3/m64
+    InstructionDefinition::new(
+        VFNMSUB231SD,
+        Map0F38,
+        0xbf,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(Qword, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_w(),
+    //
+    // VCVTPH2PS/VCVTPS2PH -- Convert 16-Bit FP Values to/from Single-Precision FP Values
+    //
+    // VEX.128.66.0F38.W0 13 /r, VCVTPH2PS xmm1, xmm2/m64
+    InstructionDefinition::new(VCVTPH2PS, Map0F38, 0x13, &[avx(XMMWord, Write), xmm_m(Qword, Read)])
+        .with_mandatory_prefix(0x66)
+        .with_vex(),
+    // VEX.256.66.0F38.W0 13 /r, VCVTPH2PS ymm1, xmm2/m128
+    InstructionDefinition::new(
+        VCVTPH2PS,
+        Map0F38,
+        0x13,
+        &[avx(YMMWord, Write), xmm_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F3A.W0 1D /r ib, VCVTPS2PH xmm1/m64, xmm2, imm8
+    InstructionDefinition::new(
+        VCVTPS2PH,
+        Map0F3A,
+        0x1d,
+        &[xmm_m(Qword, Write), avx(XMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F3A.W0 1D /r ib, VCVTPS2PH xmm1/m128, ymm2, imm8
+    InstructionDefinition::new(
+        VCVTPS2PH,
+        Map0F3A,
+        0x1d,
+        &[xmm_m(XMMWord, Write), avx(YMMWord, Read), imm(Byte)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    //
+    // VPDPBUSD/VPDPBUSDS/VPDPWSSD/VPDPWSSDS -- Multiply and Add Packed Integers (AVX-VNNI)
+    //
+    // Only the VEX-encoded forms, the AVX512-VNNI forms are EVEX-encoded.
+    //
+    // VEX.128.66.0F38.W0 50 /r, VPDPBUSD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPDPBUSD,
+        Map0F38,
+        0x50,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 50 /r, VPDPBUSD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPDPBUSD,
+        Map0F38,
+        0x50,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 51 /r, VPDPBUSDS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPDPBUSDS,
+        Map0F38,
+        0x51,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 51 /r, VPDPBUSDS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPDPBUSDS,
+        Map0F38,
+        0x51,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 52 /r, VPDPWSSD xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPDPWSSD,
+        Map0F38,
+        0x52,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 52 /r, VPDPWSSD ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPDPWSSD,
+        Map0F38,
+        0x52,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+    // VEX.128.66.0F38.W0 53 /r, VPDPWSSDS xmm1, xmm2, xmm3/m128
+    InstructionDefinition::new(
+        VPDPWSSDS,
+        Map0F38,
+        0x53,
+        &[avx(XMMWord, ReadWrite), avx_vex(XMMWord, Read), avx_m(XMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex(),
+    // VEX.256.66.0F38.W0 53 /r, VPDPWSSDS ymm1, ymm2, ymm3/m256
+    InstructionDefinition::new(
+        VPDPWSSDS,
+        Map0F38,
+        0x53,
+        &[avx(YMMWord, ReadWrite), avx_vex(YMMWord, Read), avx_m(YMMWord, Read)],
+    )
+    .with_mandatory_prefix(0x66)
+    .with_vex()
+    .with_l(1),
+];
+
+// The implicit operands of multiply and divide, e.g. `MUL r/m64` is RDX:RAX := RAX * r/m64,
+// and `DIV r/m64` is RAX := RDX:RAX / r/m64, RDX := remainder.
+const IMPLICIT_MUL_8: &[ImplicitOperand] = &[implicit(AL, Read), implicit(AX, Write)];
+const IMPLICIT_MUL_16: &[ImplicitOperand] = &[implicit(AX, ReadWrite), implicit(DX, Write)];
+const IMPLICIT_MUL_32: &[ImplicitOperand] = &[implicit(EAX, ReadWrite), implicit(EDX, Write)];
+const IMPLICIT_MUL_64: &[ImplicitOperand] = &[implicit(RAX, ReadWrite), implicit(RDX, Write)];
+const IMPLICIT_DIV_8: &[ImplicitOperand] = &[implicit(AX, ReadWrite)];
+const IMPLICIT_DIV_16: &[ImplicitOperand] = &[implicit(AX, ReadWrite), implicit(DX, ReadWrite)];
+const IMPLICIT_DIV_32: &[ImplicitOperand] = &[implicit(EAX, ReadWrite), implicit(EDX, ReadWrite)];
+const IMPLICIT_DIV_64: &[ImplicitOperand] = &[implicit(RAX, ReadWrite), implicit(RDX, ReadWrite)];
+
+// The implicit operands of sign-extension, e.g. `CQO` is RDX:RAX := sign-extend of RAX,
+// it is usually used to prepare the dividend of `IDIV`.
+const IMPLICIT_CBW: &[ImplicitOperand] = &[implicit(AL, Read), implicit(AX, Write)];
+const IMPLICIT_CWDE: &[ImplicitOperand] = &[implicit(AX, Read), implicit(EAX, Write)];
+const IMPLICIT_CDQE: &[ImplicitOperand] = &[implicit(EAX, Read), implicit(RAX, Write)];
+const IMPLICIT_CWD: &[ImplicitOperand] = &[implicit(AX, Read), implicit(DX, Write)];
+const IMPLICIT_CDQ: &[ImplicitOperand] = &[implicit(EAX, Read), implicit(EDX, Write)];
+const IMPLICIT_CQO: &[ImplicitOperand] = &[implicit(RAX, Read), implicit(RDX, Write)];
+
+// The stack pointer is adjusted by PUSH and POP, and the frame pointer is
+// saved and restored by ENTER and LEAVE.
+const IMPLICIT_STACK: &[ImplicitOperand] = &[implicit(RSP, ReadWrite)];
+const IMPLICIT_FRAME: &[ImplicitOperand] = &[implicit(RSP, ReadWrite), implicit(RBP, ReadWrite)];
+
+// The flags are not modelled as registers, only AH is listed.
+const IMPLICIT_LAHF: &[ImplicitOperand] = &[implicit(AH, Write)];
+const IMPLICIT_SAHF: &[ImplicitOperand] = &[implicit(AH, Read)];
+
+// CMPXCHG compares the accumulator with the destination, and loads the destination
+// into the accumulator if they are not equal. CMPXCHG16B compares RDX:RAX with m128,
+// and stores RCX:RBX if they are equal.
+const IMPLICIT_CMPXCHG_8: &[ImplicitOperand] = &[implicit(AL, ReadWrite)];
+const IMPLICIT_CMPXCHG_16: &[ImplicitOperand] = &[implicit(AX, ReadWrite)];
+const IMPLICIT_CMPXCHG_32: &[ImplicitOperand] = &[implicit(EAX, ReadWrite)];
+const IMPLICIT_CMPXCHG_64: &[ImplicitOperand] = &[implicit(RAX, ReadWrite)];
+const IMPLICIT_CMPXCHG16B: &[ImplicitOperand] = &[
+    implicit(RAX, ReadWrite),
+    implicit(RDX, ReadWrite),
+    implicit(RBX, Read),
+    implicit(RCX, Read),
+];
+
+// The string operations read and/or write the memory at [RSI] and [RDI], and advance
+// the pointers, the counter RCX is only used with a REP prefix, so it is not listed.
+const IMPLICIT_MOVS_CMPS: &[ImplicitOperand] = &[implicit(RSI, ReadWrite), implicit(RDI, ReadWrite)];
+const IMPLICIT_STOS_8: &[ImplicitOperand] = &[implicit(AL, Read), implicit(RDI, ReadWrite)];
+const IMPLICIT_STOS_16: &[ImplicitOperand] = &[implicit(AX, Read), implicit(RDI, ReadWrite)];
+const IMPLICIT_STOS_32: &[ImplicitOperand] = &[implicit(EAX, Read), implicit(RDI, ReadWrite)];
+const IMPLICIT_STOS_64: &[ImplicitOperand] = &[implicit(RAX, Read), implicit(RDI, ReadWrite)];
+const IMPLICIT_LODS_8: &[ImplicitOperand] = &[implicit(AL, Write), implicit(RSI, ReadWrite)];
+const IMPLICIT_LODS_16: &[ImplicitOperand] = &[implicit(AX, Write), implicit(RSI, ReadWrite)];
+const IMPLICIT_LODS_32: &[ImplicitOperand] = &[implicit(EAX, Write), implicit(RSI, ReadWrite)];
+const IMPLICIT_LODS_64: &[ImplicitOperand] = &[implicit(RAX, Write), implicit(RSI, ReadWrite)];
+const IMPLICIT_SCAS_8: &[ImplicitOperand] = &[implicit(AL, Read), implicit(RDI, ReadWrite)];
+const IMPLICIT_SCAS_16: &[ImplicitOperand] = &[implicit(AX, Read), implicit(RDI, ReadWrite)];
+const IMPLICIT_SCAS_32: &[ImplicitOperand] = &[implicit(EAX, Read), implicit(RDI, ReadWrite)];
+const IMPLICIT_SCAS_64: &[ImplicitOperand] = &[implicit(RAX, Read), implicit(RDI, ReadWrite)];
+
+// MULX multiplies EDX/RDX by the source, the flags are not affected.
+const IMPLICIT_MULX_32: &[ImplicitOperand] = &[implicit(EDX, Read)];
+const IMPLICIT_MULX_64: &[ImplicitOperand] = &[implicit(RDX, Read)];
+
+// PCMPESTRx reads the lengths from EAX and EDX, PCMPxSTRI writes the index to ECX
+// and PCMPxSTRM writes the mask to XMM0.
+const IMPLICIT_PCMPESTRI: &[ImplicitOperand] =
+    &[implicit(EAX, Read), implicit(EDX, Read), implicit(ECX, Write)];
+const IMPLICIT_PCMPESTRM: &[ImplicitOperand] =
+    &[implicit(EAX, Read), implicit(EDX, Read), implicit(XMM0, Write)];
+const IMPLICIT_PCMPISTRI: &[ImplicitOperand] = &[implicit(ECX, Write)];
+const IMPLICIT_PCMPISTRM: &[ImplicitOperand] = &[implicit(XMM0, Write)];
+
+// The registers that are accessed by the system instructions, e.g. CPUID reads the leaf
+// from EAX and the sub-leaf from ECX, and returns the information in EAX, EBX, ECX and EDX.
+const IMPLICIT_SYSCALL: &[ImplicitOperand] = &[implicit(RCX, Write), implicit(R11, Write)];
+const IMPLICIT_CPUID: &[ImplicitOperand] = &[
+    implicit(EAX, ReadWrite),
+    implicit(EBX, Write),
+    implicit(ECX, ReadWrite),
+    implicit(EDX, Write),
+];
+const IMPLICIT_RDTSC: &[ImplicitOperand] = &[implicit(EAX, Write), implicit(EDX, Write)];
+const IMPLICIT_RDTSCP: &[ImplicitOperand] =
+    &[implicit(EAX, Write), implicit(ECX, Write), implicit(EDX, Write)];
+const IMPLICIT_XGETBV: &[ImplicitOperand] =
+    &[implicit(ECX, Read), implicit(EAX, Write), implicit(EDX, Write)];
+
+const fn implicit(register: Register, access: OperandAccess) -> ImplicitOperand {
+    ImplicitOperand::new(register, access)
+}
+
+/// ModRM:reg, i.e. `r8`, `r16`, `r32` and `r64`.
+const fn reg(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmReg,
+        access,
+        size,
+        OperandType::Register(RegisterType::General),
+    )
+}
+
+/// ModRM:r/m, i.e. `r/m8`, `r/m16`, `r/m32` and `r/m64`.
+const fn rm(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        access,
+        size,
+        OperandType::RegisterOrMem(RegisterType::General),
+    )
+}
+
+/// ModRM:r/m that only accepts a register, e.g. `r32` in `PEXTRB r32/m8, xmm2, imm8`,
+/// the memory form is defined separately because its size is different.
+const fn rm_reg(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        access,
+        size,
+        OperandType::Register(RegisterType::General),
+    )
+}
+
+/// ModRM:reg that accepts XMM0-XMM15, i.e. `xmm1` in SSE instructions.
+const fn xmm(access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmReg,
+        access,
+        OperandSize::XMMWord,
+        OperandType::Register(RegisterType::SSE),
+    )
+}
+
+/// ModRM:r/m that only accepts XMM0-XMM15, e.g. `xmm2` in `PEXTRW r32, xmm2, imm8`.
+const fn xmm_rm(access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        access,
+        OperandSize::XMMWord,
+        OperandType::Register(RegisterType::SSE),
+    )
+}
+
+/// ModRM:r/m that accepts XMM0-XMM15 or memory, i.e. `xmm2/m32`, `xmm2/m64` and
+/// `xmm2/m128`, the size is the size of the memory operand.
+const fn xmm_m(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        access,
+        size,
+        OperandType::RegisterOrMem(RegisterType::SSE),
+    )
+}
+
+/// ModRM:reg that accepts XMM0-XMM15 or YMM0-YMM15, i.e. `xmm1` and `ymm1` in AVX instructions.
+const fn avx(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmReg,
+        access,
+        size,
+        OperandType::Register(RegisterType::AVX),
+    )
+}
+
+/// ModRM:r/m that accepts XMM, YMM or memory, i.e. `xmm2/m128`, `ymm2/m256`
+/// and `xmm2/m32`, the size is the size of the memory operand.
+const fn avx_m(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        access,
+        size,
+        OperandType::RegisterOrMem(RegisterType::AVX),
+    )
+}
+
+/// VEX.vvvv that accepts XMM or YMM, e.g. `ymm2` in `VADDPS ymm1, ymm2, ymm3/m256`.
+const fn avx_vex(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::VexVvvv,
+        access,
+        size,
+        OperandType::Register(RegisterType::AVX),
+    )
+}
+
+/// VSIB memory with XMM index, i.e. `vm32x` and `vm64x`.
+const fn vsib_x() -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        OperandAccess::Read,
+        OperandSize::Unsized,
+        OperandType::VectorMem(RegisterClass::XMM),
+    )
+}
+
+/// VSIB memory with YMM index, i.e. `vm32y` and `vm64y`.
+const fn vsib_y() -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        OperandAccess::Read,
+        OperandSize::Unsized,
+        OperandType::VectorMem(RegisterClass::YMM),
+    )
+}
+
+/// ModRM:r/m that only accepts memory, the size is not relevant, e.g. `m` in `LEA r64, m`.
+const fn m() -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        OperandAccess::Read,
+        OperandSize::Unsized,
+        OperandType::Mem,
+    )
+}
+
+/// A 128-bit memory operand, e.g. `m128` in `CMPXCHG16B m128`.
+const fn m128(access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmRm,
+        access,
+        OperandSize::XMMWord,
+        OperandType::Mem,
+    )
+}
+
+/// ModRM:r/m that only accepts memory, i.e. `m16`, `m32` and `m64`, e.g. `MOVBE r32, m32`.
+const fn mem(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(OperandEncoding::ModRmRm, access, size, OperandType::Mem)
+}
+
+/// VEX.vvvv, i.e. the extra register operand of the VEX-encoded instruction,
+/// e.g. the second operand of `ANDN r32a, r32b, r/m32`.
+const fn vex_reg(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::VexVvvv,
+        access,
+        size,
+        OperandType::Register(RegisterType::General),
+    )
+}
+
+/// The register encoded in the opcode, i.e. `+rb`, `+rw`, `+rd` and `+ro`.
+const fn opcode_reg(size: OperandSize, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::Opcode,
+        access,
+        size,
+        OperandType::Register(RegisterType::General),
+    )
+}
+
+/// ModRM:reg that accepts DR0-DR7, i.e. `DR0-DR7` in `MOV r64, DR0-DR7`.
+const fn dr(access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::ModRmReg,
+        access,
+        OperandSize::Qword,
+        OperandType::Register(RegisterType::Debug),
+    )
+}
+
+/// The register that is implied by the opcode, e.g. `AL` in `ADD AL, imm8`.
+const fn fixed(register: Register, access: OperandAccess) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::Implicit,
+        access,
+        register.size(),
+        OperandType::FixedRegister(register),
+    )
+}
+
+/// The constant 1, e.g. `SHL r/m32, 1`.
+const fn one() -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::Implicit,
+        OperandAccess::Read,
+        OperandSize::Byte,
+        OperandType::FixedImmediate(1),
+    )
+}
+
+/// `imm8`, `imm16`, `imm32` and `imm64`.
+const fn imm(size: OperandSize) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::Immediate,
+        OperandAccess::Read,
+        size,
+        OperandType::Immediate,
+    )
+}
+
+/// `imm8` and `imm32` that is sign-extended to the operand size.
+const fn simm(size: OperandSize) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::Immediate,
+        OperandAccess::Read,
+        size,
+        OperandType::SignExtendedImmediate,
+    )
+}
+
+/// `rel8` and `rel32`, the offset of the label relative to the next instruction.
+const fn rel(size: OperandSize) -> OperandDefinition {
+    OperandDefinition::new(
+        OperandEncoding::Immediate,
+        OperandAccess::Read,
+        size,
+        OperandType::Relative,
+    )
+}
+
+/// Finds all forms of the instruction that match the types and sizes (and the
+/// values of immediate) of the operands, in the order of the table.
+///
+/// Returns an empty list if no form matches, or if the size of a memory operand is not
+/// specified and it can not be inferred from the other operands,
+/// e.g. `mov [rax], 0x10`.
+pub fn find_definitions(instruction: &Instruction) -> Vec<&'static InstructionDefinition> {
+    let mut candidates = match_definitions(instruction);
+
+    // the operand size of PUSH and POP defaults to 64-bit, so does the size of
+    // the unsized memory operand, e.g. `push [rax]` is `push qword [rax]`.
+    if unsized_memory_operand(instruction, &candidates).is_some() {
+        candidates.retain(|definition| {
+            definition.default_64 && definition.operand_size() == OperandSize::Qword
+        });
+    }
+
+    // all candidates must agree on the size of the unsized memory operand
+    if candidates.is_empty() || unsized_memory_operand(instruction, &candidates).is_some() {
+        return vec![];
+    }
+
+    candidates
+}
+
+/// Finds all forms of the instruction that match the operands, without checking
+/// whether the size of the unsized memory operand is ambiguous.
+pub fn match_definitions(instruction: &Instruction) -> Vec<&'static InstructionDefinition> {
+    INSTRUCTION_DEFINITIONS
+        .iter()
+        .filter(|definition| {
+            let operand_size = definition.operand_size();
+            definition.mnemonic == instruction.mnemonic
+                && match_immediate_size(instruction, definition)
+                && instruction
+                    .operands
+                    .iter()
+                    .zip(definition.operands.iter())
+                    .all(|(operand, operand_definition)| {
+                        match (operand, operand_definition) {
+                            (Some(operand), Some(operand_definition)) => {
+                                match_operand(operand, operand_definition, &operand_size)
+                            }
+                            (None, None) => true,
+                            _ => false,
+                        }
+                    })
+        })
+        .collect()
+}
+
+/// The forms that only have an immediate operand (i.e. `PUSH imm`) take the operand size
+/// from the immediate, `Immediate16` (i.e. `push word 1`) selects the 16-bit form,
+/// and the others select the 64-bit form.
+fn match_immediate_size(instruction: &Instruction, definition: &InstructionDefinition) -> bool {
+    let is_immediate_only = definition.explicit_operand_size.is_some()
+        && definition.operands[0].is_some()
+        && definition.operands.iter().flatten().all(|operand_definition| {
+            matches!(
+                operand_definition.operand_type,
+                OperandType::Immediate | OperandType::SignExtendedImmediate
+            )
+        });
+
+    !is_immediate_only
+        || matches!(instruction.operands[0], Some(Operand::Immediate16(_)))
+            == (definition.operand_size() == OperandSize::Word)
+}
+
+/// Returns the index of the memory operand whose size is not specified and
+/// the candidates disagree on, e.g. the first operand of `mov [rax], 0x10`.
+pub fn unsized_memory_operand(
+    instruction: &Instruction,
+    candidates: &[&'static InstructionDefinition],
+) -> Option<usize> {
+    let first = candidates.first()?;
+
+    instruction
+        .operands
+        .iter()
+        .enumerate()
+        .position(|(idx, operand)| {
+            if let Some(Operand::Memory(memory)) = operand
+                && memory.size == OperandSize::Unsized
+            {
+                let size = first.operands[idx].map(|item| item.size);
+                candidates
+                    .iter()
+                    .any(|candidate| candidate.operands[idx].map(|item| item.size) != size)
+            } else {
+                false
+            }
+        })
+}
+
+fn match_operand(
+    operand: &Operand,
+    operand_definition: &OperandDefinition,
+    operand_size: &OperandSize,
+) -> bool {
+    match (&operand_definition.operand_type, operand) {
+        (
+            OperandType::Register(register_type) | OperandType::RegisterOrMem(register_type),
+            Operand::Register(register),
+        ) => {
+            // the size of `xmm/m32` is the size of memory operand, the register is XMM
+            let size = match operand_definition.size {
+                size if size.bits() < 128
+                    && !matches!(register_type, RegisterType::General | RegisterType::Debug) =>
+                {
+                    OperandSize::XMMWord
+                }
+                size => size,
+            };
+            register_type.accepts(register) && register.size() == size
+        }
+        (OperandType::Mem | OperandType::RegisterOrMem(_), Operand::Memory(memory)) => {
+            !memory.is_vsib()
+                && (memory.size == operand_definition.size
+                    || memory.size == OperandSize::Unsized
+                    || operand_definition.size == OperandSize::Unsized)
+        }
+        (OperandType::VectorMem(class), Operand::Memory(memory)) => {
+            memory.index.is_some_and(|index| index.class() == *class)
+        }
+        (OperandType::Immediate, _) => operand
+            .immediate_value()
+            .is_some_and(|value| fits_in(value, &operand_definition.size)),
+        (OperandType::Relative, Operand::Label(_)) => true,
+        (OperandType::FixedRegister(expected), Operand::Register(register)) => register == expected,
+        (OperandType::FixedImmediate(expected), _) => {
+            operand.immediate_value() == Some(*expected as i64)
+        }
+        (OperandType::SignExtendedImmediate, _) => operand
+            .immediate_value()
+            .is_some_and(|value| fits_in_sign_extended(value, &operand_definition.size, operand_size)),
+        _ => false,
+    }
+}
+
+/// Checks whether the value can be represented by the given size,
+/// either as a signed or an unsigned integer, e.g. both -1 and 255 fit in a byte.
+fn fits_in(value: i64, size: &OperandSize) -> bool {
+    let bits = size.bits();
+    bits >= 64 || (value >= -(1 << (bits - 1)) && value < (1 << bits))
+}
+
+/// Checks whether the value of operand size can be represented by an immediate
+/// that is sign-extended from the given size, e.g. 0xffff_fff0 (i.e. -16) in
+/// 32-bit operand size can be represented by imm8 0xf0.
+fn fits_in_sign_extended(value: i64, size: &OperandSize, operand_size: &OperandSize) -> bool {
+    if !fits_in(value, operand_size) {
+        return false;
+    }
+
+    // the signed value in the operand size
+    let shift = 64 - operand_size.bits().clamp(8, 64);
+    let signed_value = (value << shift) >> shift;
+
+    let bits = size.bits();
+    signed_value >= -(1 << (bits - 1)) && signed_value < (1 << (bits - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        instruction::{
+            ImplicitOperand, Instruction, MemoryOperand, OpcodeMap, Operand, OperandAccess,
+            OperandSize, Register,
+        },
+        mnemonic::Mnemonic,
+    };
+
+    use super::find_definitions;
+
+    #[test]
+    fn test_find_definition() {
+        // mov rax, rcx -> MOV r/m64, r64
+        let definition = find_definitions(&Instruction::new(
+            Mnemonic::MOV,
+            &[Register::RAX.into(), Register::RCX.into()],
+        ))[0];
+        assert_eq!(definition.opcode, 0x89);
+        assert_eq!(definition.operands[0].unwrap().size, OperandSize::Qword);
+
+        // mov ax, word [rbx] -> MOV r16, r/m16
+        let definition = find_definitions(&Instruction::new(
+            Mnemonic::MOV,
+            &[
+                Register::AX.into(),
+                MemoryOperand::new(OperandSize::Word, Register::RBX).into(),
+            ],
+        ))[0];
+        assert_eq!(definition.opcode, 0x8b);
+        assert_eq!(definition.operands[0].unwrap().size, OperandSize::Word);
+
+        // mov rax, -1 -> MOV r64, imm64 and MOV r/m64, imm32 (sign-extended)
+        let definitions = find_definitions(&Instruction::new(
+            Mnemonic::MOV,
+            &[Register::RAX.into(), Operand::Immediate8(0xff)],
+        ));
+        assert_eq!(definitions.len(), 2);
+        assert_eq!(definitions[0].opcode, 0xb8);
+        assert_eq!(definitions[1].opcode, 0xc7);
+
+        // mov rax, 0x8000_0000 -> MOV r64, imm64 only
+        let definitions = find_definitions(&Instruction::new(
+            Mnemonic::MOV,
+            &[Register::RAX.into(), Operand::Immediate64(0x8000_0000)],
+        ));
+        assert_eq!(definitions.len(), 1);
+        assert_eq!(definitions[0].opcode, 0xb8);
+        assert!(definitions[0].register_in_opcode);
+
+        // mov al, 0x100 -> exceeds the operand size
+        assert!(
+            find_definitions(&Instruction::new(
+                Mnemonic::MOV,
+                &[Register::AL.into(), Operand::Immediate16(0x100)],
+            ))
+            .is_empty()
+        );
+
+        // mov [rbx], eax -> MOV r/m32, r32, the memory size is inferred from the register
+        let definition = find_definitions(&Instruction::new(
+            Mnemonic::MOV,
+            &[
+                MemoryOperand::new(OperandSize::Unsized, Register::RBX).into(),
+                Register::EAX.into(),
+            ],
+        ))[0];
+        assert_eq!(definition.opcode, 0x89);
+        assert_eq!(definition.operands[0].unwrap().size, OperandSize::Dword);
+
+        // movzx rax, cl -> MOVZX r64, r/m8
+        let definition = find_definitions(&Instruction::new(
+            Mnemonic::MOVZX,
+            &[Register::RAX.into(), Register::CL.into()],
+        ))[0];
+        assert_eq!(definition.map, OpcodeMap::Map0F);
+        assert_eq!(definition.opcode, 0xb6);
+        assert!(!definition.register_in_opcode);
+
+        // movbe eax, [rcx] -> MOVBE r32, m32, the opcode map is 0F 38
+        let definition = find_definitions(&Instruction::new(
+            Mnemonic::MOVBE,
+            &[Register::EAX.into(), MemoryOperand::new(OperandSize::Dword, Register::RCX).into()],
+        ))[0];
+        assert_eq!(definition.map, OpcodeMap::Map0F38);
+        assert_eq!(definition.opcode, 0xf0);
+
+        // mov [rax], 0x10 -> ambiguous operand size
+        assert!(
+            find_definitions(&Instruction::new(
+                Mnemonic::MOV,
+                &[
+                    MemoryOperand::new(OperandSize::Unsized, Register::RAX).into(),
+                    Operand::Immediate32(0x10),
+                ],
+            ))
+            .is_empty()
+        );
+
+        // mov eax, cx -> operand size mismatch
+        assert!(
+            find_definitions(&Instruction::new(
+                Mnemonic::MOV,
+                &[Register::EAX.into(), Register::CX.into()],
+            ))
+            .is_empty()
+        );
+
+        // add rax, [rbx] -> the destination is read and written, CMP only reads it
+        let definition = find_definitions(&Instruction::new(
+            Mnemonic::ADD,
+            &[
+                Register::RAX.into(),
+                MemoryOperand::new(OperandSize::Qword, Register::RBX).into(),
+            ],
+        ))[0];
+        assert_eq!(definition.operands[0].unwrap().access, OperandAccess::ReadWrite);
+        assert_eq!(definition.operands[1].unwrap().access, OperandAccess::Read);
+
+        let definition = find_definitions(&Instruction::new(
+            Mnemonic::CMP,
+            &[Register::AL.into(), Operand::Immediate8(1)],
+        ))[0];
+        assert_eq!(definition.opcode, 0x3c);
+        assert_eq!(definition.operands[0].unwrap().access, OperandAccess::Read);
+
+        // movzx eax, ecx -> no such form
+        assert!(
+            find_definitions(&Instruction::new(
+                Mnemonic::MOVZX,
+                &[Register::EAX.into(), Register::ECX.into()],
+            ))
+            .is_empty()
+        );
+
+        // div rcx -> RAX := RDX:RAX / RCX, RDX := remainder
+        let definition =
+            find_definitions(&Instruction::new(Mnemonic::DIV, &[Register::RCX.into()]))[0];
+        assert_eq!(
+            definition.implicit_operands,
+            [
+                ImplicitOperand::new(Register::RAX, OperandAccess::ReadWrite),
+                ImplicitOperand::new(Register::RDX, OperandAccess::ReadWrite)
+            ]
+        );
+
+        // push rbx -> RSP is adjusted
+        let definition =
+            find_definitions(&Instruction::new(Mnemonic::PUSH, &[Register::RBX.into()]))[0];
+        assert_eq!(definition.opcode, 0x50);
+        assert_eq!(
+            definition.implicit_operands,
+            [ImplicitOperand::new(Register::RSP, OperandAccess::ReadWrite)]
+        );
+
+        // cqo -> RDX:RAX := sign-extend of RAX
+        let definition = find_definitions(&Instruction::new(Mnemonic::CQO, &[]))[0];
+        assert_eq!(definition.operand_size(), OperandSize::Qword);
+        assert_eq!(
+            definition.implicit_operands,
+            [
+                ImplicitOperand::new(Register::RAX, OperandAccess::Read),
+                ImplicitOperand::new(Register::RDX, OperandAccess::Write)
+            ]
+        );
+
+        // cpuid -> reads EAX/ECX, writes EAX/EBX/ECX/EDX
+        let definition = find_definitions(&Instruction::new(Mnemonic::CPUID, &[]))[0];
+        assert_eq!(
+            definition.implicit_operands,
+            [
+                ImplicitOperand::new(Register::EAX, OperandAccess::ReadWrite),
+                ImplicitOperand::new(Register::EBX, OperandAccess::Write),
+                ImplicitOperand::new(Register::ECX, OperandAccess::ReadWrite),
+                ImplicitOperand::new(Register::EDX, OperandAccess::Write)
+            ]
+        );
+
+        // syscall -> RCX := RIP, R11 := RFLAGS
+        let definition = find_definitions(&Instruction::new(Mnemonic::SYSCALL, &[]))[0];
+        assert_eq!(
+            definition.implicit_operands,
+            [
+                ImplicitOperand::new(Register::RCX, OperandAccess::Write),
+                ImplicitOperand::new(Register::R11, OperandAccess::Write)
+            ]
+        );
+    }
+}
@@ -4,13 +4,27 @@
 // the Mozilla Public License version 2.0 and additional exceptions.
 // For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
 
-use crate::instruction::Instruction;
+use std::fmt::Display;
+
+use crate::{
+    definition::{
+        INSTRUCTION_DEFINITIONS, find_definitions, match_definitions, unsized_memory_operand,
+    },
+    instruction::{
+        EncodingScheme, Instruction, InstructionDefinition, MemoryOperand, MemoryOperandError,
+        ModRM, OpcodeMap, Operand, OperandEncoding, OperandSize, Prefix, REX, Register,
+        RegisterClass, SIB,
+    },
+    mnemonic::Mnemonic,
+};
 
 /* *
  *
  * Instruction Format:
  * - legacy prefix: 0-4 bytes (group 1-4)
  * - REX: 0,1 byte
+ * - VEX: 0,2,3 bytes (C5/C4, replaces the mandatory prefix, REX and the 0F/0F 38/0F 3A)
+ * - EVEX: 0,4 bytes (62, like VEX, and the displacement is compressed as disp8*N)
  * - Opcode: 1,2,3 bytes (3 bytes = 0F + 2 bytes)
  * - ModRM: 0,1 byte (Mod: 2-bit, Reg: 3-bit, R/M: 3-bit)
  * - SIB: 0,1 byte (Scale: 2-bit, Index: 3-bit, Base: 3-bit)
@@ -45,14 +59,1011 @@ pub fn encode(
     current_address: u64,
 
     // lable address list
-    lable_address_list: &Vec<(&str, u64)>,
-) -> Vec<u8> {
-    // Encoding logic will be implemented here
-    todo!()
+    lable_address_list: &[(&str, u64)],
+) -> Result<EncodedInstruction, EncodeError> {
+    encode_with_options(
+        instruction,
+        current_address,
+        lable_address_list,
+        &EncodeOptions::default(),
+    )
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EncodeOptions {
+    /// By default the encoder selects the shortest form, e.g. disp8 instead of disp32,
+    /// imm8 (sign-extended) instead of imm32, and `mov r32, imm32` instead of `mov r64, imm64`
+    /// if the immediate can be zero-extended.
+    ///
+    /// When this option is enabled, the length of the encoding does not depend on the values
+    /// of displacement and immediate, i.e. the displacement is always disp32, and the form
+    /// with the widest immediate is selected. It is useful for generating patchable code.
+    pub fixed_size: bool,
+
+    /// Use rel32 for the branch even if the label is in the range of rel8.
+    ///
+    /// It is used by the assembler to prevent a branch that has grown from shrinking
+    /// back, so that the layout of program converges.
+    pub long_branch: bool,
+}
+
+pub fn encode_with_options(
+    instruction: &Instruction,
+
+    // current address (for the new generated code)
+    current_address: u64,
+
+    // lable address list
+    lable_address_list: &[(&str, u64)],
+
+    options: &EncodeOptions,
+) -> Result<EncodedInstruction, EncodeError> {
+    for (idx, operand) in instruction.operands.iter().enumerate() {
+        if let Some(Operand::Memory(memory)) = operand {
+            memory
+                .validate()
+                .map_err(|error| EncodeError::InvalidMemoryOperand {
+                    operand: idx,
+                    error,
+                })?;
+        }
+    }
+
+    let definitions = find_definitions(instruction);
+    if definitions.is_empty() {
+        return Err(diagnose(instruction));
+    }
+
+    let definitions = match instruction.prefix {
+        Some(prefix) => accept_prefix(instruction, prefix, definitions)?,
+        None => definitions,
+    };
+
+    // both `min_by_key` and `rev().max_by_key` return the first one in the table order
+    // when there are more than one candidates with the same key.
+    if options.fixed_size {
+        let definition = definitions
+            .iter()
+            .rev()
+            .max_by_key(|definition| immediate_width(definition))
+            .unwrap();
+        let encoding = encode_by_definition(instruction, definition, options)?;
+        return resolve(encoding, current_address, lable_address_list);
+    }
+
+    let mut candidates: Vec<Result<UnresolvedEncoding, EncodeError>> = definitions
+        .iter()
+        .filter(|definition| !is_nop_alias(instruction, definition))
+        .map(|definition| encode_by_definition(instruction, definition, options))
+        .collect();
+
+    if let Some(alternative) = zero_extended_move(instruction) {
+        candidates.extend(
+            find_definitions(&alternative)
+                .iter()
+                .map(|definition| encode_by_definition(&alternative, definition, options)),
+        );
+    }
+
+    // rel8 is not used if the label is not resolved (i.e. the distance is unknown),
+    // or if the long branch is required.
+    let is_allowed = |encoding: &UnresolvedEncoding| match &encoding.relative {
+        Some(field) if field.size != OperandSize::Dword => {
+            !options.long_branch
+                && lable_address_list
+                    .iter()
+                    .any(|(name, _)| *name == field.label)
+        }
+        _ => true,
+    };
+
+    // the shortest encoding, or the error of the first form if none of them can be encoded
+    let mut first_error = None;
+    let mut encodings = vec![];
+    for candidate in candidates {
+        let result = candidate.and_then(|encoding| {
+            if is_allowed(&encoding) {
+                resolve(encoding, current_address, lable_address_list).map(Some)
+            } else {
+                Ok(None)
+            }
+        });
+
+        match result {
+            Ok(Some(encoded)) => encodings.push(encoded),
+            Ok(None) => {}
+            Err(error) => {
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+
+    match encodings
+        .into_iter()
+        .min_by_key(|encoded| encoded.bytes.len())
+    {
+        Some(encoded) => Ok(encoded),
+        None => Err(first_error.unwrap()),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct EncodedInstruction {
+    pub bytes: Vec<u8>,
+
+    /// The fields that refer to the labels that are not in the label list,
+    /// they are filled with zero and should be patched once the addresses
+    /// of the labels are known.
+    pub fixups: Vec<Fixup>,
+}
+
+/// A PC-relative field that refers to an unresolved label.
+///
+/// The value of the field is `address of label + addend - address of the field`,
+/// i.e. the same as the ELF relocation `R_X86_64_PC32`, e.g. the addend of
+/// `call label` (e8 00000000) is -4, because the offset is relative to
+/// the next instruction, which is 4 bytes after the field.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Fixup {
+    pub offset: usize,     // the offset of the field in the instruction
+    pub size: OperandSize, // Byte for rel8, Dword for rel32 and disp32
+    pub label: String,
+    pub addend: i64,
+}
+
+/// The error of encoding, `operand` is the index of the operand (starts from 0).
+#[derive(Debug, PartialEq, Clone)]
+pub enum EncodeError {
+    /// The mnemonic has no forms in the instruction definition table yet.
+    UnsupportedInstruction(Mnemonic),
+
+    /// None of the forms of the instruction accepts the combination of operands,
+    /// e.g. `mov eax, cx` (operand sizes mismatch) and `movzx eax, ecx`.
+    InvalidOperands(Mnemonic),
+
+    /// The memory operand is not a valid 64-bit effective address,
+    /// e.g. `[bx]`, `[ebx]`, `[rax + rsp*2]` and `[rax + 0x1_0000_0000]`.
+    InvalidMemoryOperand {
+        operand: usize,
+        error: MemoryOperandError,
+    },
+
+    /// The size of the memory operand is not specified and can not be inferred
+    /// from the other operands, e.g. `mov [rax], 0x10`.
+    AmbiguousOperandSize { operand: usize },
+
+    /// The immediate can not be represented by any form of the instruction,
+    /// e.g. `mov al, 0x100` and `mov qword [rax], 0x1234567890abcdef`.
+    ImmediateOutOfRange { operand: usize, value: i64 },
+
+    /// AH, CH, DH and BH can not be used in an instruction that requires the REX prefix,
+    /// e.g. `mov ah, sil` and `movzx rax, ah`.
+    HighByteRegisterWithRex { operand: usize, register: Register },
+
+    /// The prefix can not be used with the instruction or the operands,
+    /// e.g. `lock mov [rax], ecx` and `lock add eax, ecx` (the destination is not memory).
+    InvalidPrefix { prefix: Prefix, mnemonic: Mnemonic },
+
+    /// The label is too far to be reached by the relative field,
+    /// e.g. the distance exceeds the range of a signed 32-bit integer.
+    RelativeOutOfRange { label: String, distance: i64 },
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncodeError::UnsupportedInstruction(mnemonic) => {
+                write!(f, "instruction \"{}\" is not supported", mnemonic.name())
+            }
+            EncodeError::InvalidOperands(mnemonic) => {
+                write!(f, "invalid combination of operands for \"{}\"", mnemonic.name())
+            }
+            EncodeError::InvalidMemoryOperand { operand, error } => {
+                write!(f, "operand {}: invalid memory operand, {:?}", operand + 1, error)
+            }
+            EncodeError::AmbiguousOperandSize { operand } => {
+                write!(f, "operand {}: operand size is not specified", operand + 1)
+            }
+            EncodeError::ImmediateOutOfRange { operand, value } => {
+                write!(f, "operand {}: immediate {} is out of range", operand + 1, value)
+            }
+            EncodeError::HighByteRegisterWithRex { operand, register } => write!(
+                f,
+                "operand {}: {:?} can not be used in an instruction that requires the REX prefix",
+                operand + 1,
+                register
+            ),
+            EncodeError::InvalidPrefix { prefix, mnemonic } => write!(
+                f,
+                "prefix \"{}\" can not be used with \"{}\" and these operands",
+                prefix.name(),
+                mnemonic.name()
+            ),
+            EncodeError::RelativeOutOfRange { label, distance } => {
+                write!(f, "label \"{}\" is out of range, distance {}", label, distance)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Finds out why none of the forms of the instruction matches the operands.
+fn diagnose(instruction: &Instruction) -> EncodeError {
+    let mnemonic = instruction.mnemonic;
+
+    if !INSTRUCTION_DEFINITIONS
+        .iter()
+        .any(|definition| definition.mnemonic == mnemonic)
+    {
+        return EncodeError::UnsupportedInstruction(mnemonic);
+    }
+
+    if let Some(idx) = unsized_memory_operand(instruction, &match_definitions(instruction)) {
+        return EncodeError::AmbiguousOperandSize { operand: idx };
+    }
+
+    // the immediate is out of range if the instruction matches after replacing
+    // it with 0, which fits in any size.
+    for (idx, operand) in instruction.operands.iter().enumerate() {
+        if let Some(value) = operand.as_ref().and_then(Operand::immediate_value) {
+            let mut probe = instruction.clone();
+            probe.operands[idx] = Some(Operand::Immediate8(0));
+            if !match_definitions(&probe).is_empty() {
+                return EncodeError::ImmediateOutOfRange {
+                    operand: idx,
+                    value,
+                };
+            }
+        }
+    }
+
+    EncodeError::InvalidOperands(mnemonic)
+}
+
+/// Selects the forms that accept the prefix.
+///
+/// LOCK is only allowed when the destination (i.e. the first operand) is memory,
+/// e.g. `lock add [rax], ecx`, since the atomic read-modify-write of a register
+/// is meaningless and raises #UD. The REP prefixes are only listed by the string
+/// instructions, e.g. `rep movsb` and `repne scasb`.
+fn accept_prefix(
+    instruction: &Instruction,
+    prefix: Prefix,
+    definitions: Vec<&'static InstructionDefinition>,
+) -> Result<Vec<&'static InstructionDefinition>, EncodeError> {
+    let memory_destination = matches!(instruction.operands[0], Some(Operand::Memory(_)));
+
+    let accepted: Vec<&'static InstructionDefinition> = definitions
+        .into_iter()
+        .filter(|definition| definition.allowed_prefixes.contains(&prefix))
+        .filter(|_| prefix != Prefix::LOCK || memory_destination)
+        .collect();
+
+    if accepted.is_empty() {
+        Err(EncodeError::InvalidPrefix {
+            prefix,
+            mnemonic: instruction.mnemonic,
+        })
+    } else {
+        Ok(accepted)
+    }
+}
+
+/// `90` is `NOP` in 64-bit mode rather than `XCHG EAX, EAX`, the latter clears
+/// the upper 32 bits of RAX, so it must be encoded as `87 C0`.
+fn is_nop_alias(instruction: &Instruction, definition: &InstructionDefinition) -> bool {
+    definition.mnemonic == Mnemonic::XCHG
+        && definition.opcode == 0x90
+        && instruction.operands[0] == Some(Operand::Register(Register::EAX))
+        && instruction.operands[1] == Some(Operand::Register(Register::EAX))
+}
+
+/// The total width of the immediate operands of the form, it is used to select
+/// the form that can hold any value of the immediate in fixed-size mode.
+fn immediate_width(definition: &InstructionDefinition) -> u16 {
+    definition
+        .operands
+        .iter()
+        .flatten()
+        .filter(|operand_definition| operand_definition.encoding == OperandEncoding::Immediate)
+        .map(|operand_definition| operand_definition.size.bits())
+        .sum()
+}
+
+/// `mov r64, imm64` can be replaced by `mov r32, imm32` when the immediate is
+/// in the range of 0 to 0xffff_ffff, because the upper 32 bits of the destination
+/// register are set to zero automatically.
+///
+/// e.g. `mov rax, 1` -> `mov eax, 1` (b8 01000000)
+fn zero_extended_move(instruction: &Instruction) -> Option<Instruction> {
+    match &instruction.operands {
+        [Some(Operand::Register(register)), Some(immediate), None, None]
+            if instruction.mnemonic == Mnemonic::MOV
+                && register.class() == RegisterClass::GeneralPurpose
+                && register.size() == OperandSize::Qword =>
+        {
+            let value = immediate.immediate_value()?;
+            if (0..=u32::MAX as i64).contains(&value) {
+                let register_32 = Register::general_purpose(register.number(), OperandSize::Dword)?;
+                Some(Instruction::new(
+                    Mnemonic::MOV,
+                    &[register_32.into(), Operand::Immediate32(value as u32)],
+                ))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+fn encode_by_definition(
+    instruction: &Instruction,
+    definition: &InstructionDefinition,
+    options: &EncodeOptions,
+) -> Result<UnresolvedEncoding, EncodeError> {
+    let mut encoding = Encoding::new(definition.operand_size(), definition.map, definition.opcode);
+    encoding.prefix = instruction.prefix;
+    encoding.scheme = definition.scheme;
+    encoding.mandatory_prefix = definition.mandatory_prefix;
+    encoding.modrm = definition.modrm;
+    encoding.w = definition.w;
+    encoding.l = definition.l;
+    encoding.default_64 = definition.default_64;
+    encoding.fixed_size = options.fixed_size;
+
+    if let Some(opcode_extension) = definition.opcode_extension {
+        encoding = encoding.with_modrm_reg(ModRmReg::Extension(opcode_extension));
+    }
+
+    for (operand, operand_definition) in instruction.operands.iter().zip(definition.operands.iter())
+    {
+        let (Some(operand), Some(operand_definition)) = (operand, operand_definition) else {
+            continue;
+        };
+
+        encoding = match (&operand_definition.encoding, operand) {
+            (OperandEncoding::ModRmReg, Operand::Register(register)) => {
+                encoding.with_modrm_reg(ModRmReg::Register(*register))
+            }
+            (OperandEncoding::ModRmRm, _) => {
+                // EVEX scales disp8 by the size of the memory operand, i.e. disp8*N
+                if definition.scheme == EncodingScheme::Evex {
+                    encoding.disp8_scale = (operand_definition.size.bits() / 8) as i32;
+                }
+                encoding.with_modrm_rm(operand)
+            }
+            (OperandEncoding::Opcode, Operand::Register(register)) => {
+                encoding.with_opcode_register(*register)
+            }
+            (OperandEncoding::Immediate, Operand::Label(label)) => {
+                encoding.with_label(label, operand_definition.size)
+            }
+            (OperandEncoding::Immediate, _) => {
+                encoding.with_immediate(immediate_bytes(operand, &operand_definition.size))
+            }
+            (OperandEncoding::VexVvvv, Operand::Register(register)) => {
+                encoding.with_vex_register(*register)
+            }
+            (OperandEncoding::Implicit, _) => encoding,
+            _ => unreachable!(),
+        };
+    }
+
+    // AH, CH, DH and BH are encoded as SPL, BPL, SIL and DIL when REX presents
+    if encoding.rex().is_some() {
+        for (idx, operand) in instruction.operands.iter().enumerate() {
+            if let Some(Operand::Register(register)) = operand
+                && register.conflicts_with_rex()
+            {
+                return Err(EncodeError::HighByteRegisterWithRex {
+                    operand: idx,
+                    register: *register,
+                });
+            }
+        }
+    }
+
+    Ok(encoding.to_bytes())
+}
+
+/// Fills the relative field with the distance from the next instruction to the label,
+/// or leaves it zero and records a fixup if the label is not in the list.
+fn resolve(
+    encoding: UnresolvedEncoding,
+    current_address: u64,
+    lable_address_list: &[(&str, u64)],
+) -> Result<EncodedInstruction, EncodeError> {
+    let UnresolvedEncoding {
+        mut bytes,
+        relative,
+    } = encoding;
+
+    let Some(field) = relative else {
+        return Ok(EncodedInstruction {
+            bytes,
+            fixups: vec![],
+        });
+    };
+
+    let length = (field.size.bits() / 8) as usize;
+
+    match lable_address_list
+        .iter()
+        .find(|(name, _)| *name == field.label)
+    {
+        Some((_, address)) => {
+            let next_address = current_address + bytes.len() as u64;
+            let distance = address.wrapping_sub(next_address) as i64 + field.displacement;
+
+            let bits = field.size.bits();
+            if distance < -(1 << (bits - 1)) || distance >= (1 << (bits - 1)) {
+                return Err(EncodeError::RelativeOutOfRange {
+                    label: field.label,
+                    distance,
+                });
+            }
+
+            bytes[field.offset..field.offset + length]
+                .copy_from_slice(&distance.to_le_bytes()[..length]);
+
+            Ok(EncodedInstruction {
+                bytes,
+                fixups: vec![],
+            })
+        }
+        None => {
+            let addend = field.displacement - (bytes.len() - field.offset) as i64;
+            Ok(EncodedInstruction {
+                bytes,
+                fixups: vec![Fixup {
+                    offset: field.offset,
+                    size: field.size,
+                    label: field.label,
+                    addend,
+                }],
+            })
+        }
+    }
+}
+
+/// The little-endian bytes of the immediate, truncated to the given size.
+fn immediate_bytes(operand: &Operand, size: &OperandSize) -> Vec<u8> {
+    let value = operand.immediate_value().unwrap();
+    let length = (size.bits() / 8) as usize;
+    value.to_le_bytes()[..length].to_vec()
+}
+
+/// The value of ModRM.reg field.
+enum ModRmReg {
+    Register(Register),
+    Extension(u8), // the "/digit" in the opcode column, e.g. "C7 /0"
+}
+
+/// The encoding whose relative field (if any) is not filled yet, the field is resolved
+/// after the form is selected, since its value depends on the length of the instruction.
+struct UnresolvedEncoding {
+    bytes: Vec<u8>,
+    relative: Option<RelativeField>,
+}
+
+/// A field that holds the distance from the next instruction to the label,
+/// i.e. the displacement of `[rel label]` and the `rel32` of `call label`.
+struct RelativeField {
+    offset: usize, // the offset of the field in the instruction
+    size: OperandSize,
+    label: String,
+    displacement: i64, // e.g. 4 in `[rel label + 4]`
+}
+
+/// The parts of an instruction, they are emitted in the following order:
+///
+/// legacy prefix, REX (or VEX/EVEX), opcode, ModRM, SIB, displacement and immediate.
+struct Encoding<'a> {
+    // the prefix that is written in the assembly, e.g. LOCK
+    prefix: Option<Prefix>,
+    // the 66 prefix and REX.W are determined by the operand size
+    operand_size: OperandSize,
+    // the mandatory prefix, REX and the escape bytes are replaced by VEX/EVEX
+    scheme: EncodingScheme,
+    mandatory_prefix: Option<u8>,
+    // the register that encoded in VEX.vvvv/EVEX.vvvv
+    vex_register: Option<Register>,
+    // W is also set by the 64-bit operand size
+    w: bool,
+    l: u8,
+    map: OpcodeMap,
+    opcode: u8,
+    // the register that encoded in the low 3 bits of the opcode, e.g. "B8+ rd"
+    opcode_register: Option<Register>,
+    modrm_reg: Option<ModRmReg>,
+    modrm_rm: Option<&'a Operand>,
+    // the ModRM byte that is a part of the opcode, e.g. `NP 0F AE F0` (MFENCE)
+    modrm: Option<u8>,
+    // the N of EVEX disp8*N, it is 1 for the legacy and VEX encodings
+    disp8_scale: i32,
+    immediate: Vec<u8>,
+    // the label of `rel8` or `rel32`, it takes the place of immediate
+    label: Option<(&'a str, OperandSize)>,
+    // 64-bit operand size does not require REX.W
+    default_64: bool,
+    // always use disp32 for memory operand
+    fixed_size: bool,
+}
+
+impl<'a> Encoding<'a> {
+    fn new(operand_size: OperandSize, map: OpcodeMap, opcode: u8) -> Self {
+        Self {
+            prefix: None,
+            operand_size,
+            scheme: EncodingScheme::Legacy,
+            mandatory_prefix: None,
+            vex_register: None,
+            w: false,
+            l: 0,
+            map,
+            opcode,
+            opcode_register: None,
+            modrm_reg: None,
+            modrm_rm: None,
+            modrm: None,
+            disp8_scale: 1,
+            immediate: vec![],
+            label: None,
+            default_64: false,
+            fixed_size: false,
+        }
+    }
+
+    fn with_opcode_register(mut self, register: Register) -> Self {
+        self.opcode_register = Some(register);
+        self
+    }
+
+    fn with_vex_register(mut self, register: Register) -> Self {
+        self.vex_register = Some(register);
+        self
+    }
+
+    fn with_modrm_reg(mut self, modrm_reg: ModRmReg) -> Self {
+        self.modrm_reg = Some(modrm_reg);
+        self
+    }
+
+    fn with_modrm_rm(mut self, operand: &'a Operand) -> Self {
+        self.modrm_rm = Some(operand);
+        self
+    }
+
+    // the immediates are appended in order, e.g. `ENTER imm16, imm8` has two immediates
+    fn with_immediate(mut self, immediate: Vec<u8>) -> Self {
+        self.immediate.extend(immediate);
+        self
+    }
+
+    fn with_label(mut self, label: &'a str, size: OperandSize) -> Self {
+        self.label = Some((label, size));
+        self
+    }
+
+    /// The REX prefix, or `None` if it is not required.
+    fn rex(&self) -> Option<u8> {
+        let mut rex: u8 = 0;
+        let mut rex_required = false;
+
+        if self.w || (self.operand_size == OperandSize::Qword && !self.default_64) {
+            rex |= REX::W as u8;
+        }
+
+        if let Some(ModRmReg::Register(register)) = &self.modrm_reg {
+            rex_required |= register.requires_rex();
+        }
+
+        if self.reg_code() & 0b1000 != 0 {
+            rex |= REX::R as u8;
+        }
+
+        match self.modrm_rm {
+            Some(Operand::Register(register)) => {
+                rex_required |= register.requires_rex();
+                if register.is_extended() {
+                    rex |= REX::B as u8;
+                }
+            }
+            Some(Operand::Memory(memory)) => {
+                if let Some(base) = &memory.base
+                    && base.is_extended()
+                {
+                    rex |= REX::B as u8;
+                }
+                if let Some(index) = &memory.index
+                    && index.is_extended()
+                {
+                    rex |= REX::X as u8;
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(register) = &self.opcode_register {
+            rex_required |= register.requires_rex();
+            if register.is_extended() {
+                rex |= REX::B as u8;
+            }
+        }
+
+        if rex != 0 || rex_required {
+            Some(0b0100_0000 | rex)
+        } else {
+            None
+        }
+    }
+
+    /// The VEX prefix.
+    ///
+    /// The 2-byte form (C5) is used when VEX.X, VEX.B and VEX.W are not required
+    /// and the opcode map is 0F, otherwise the 3-byte form (C4) is used.
+    ///
+    /// ```text
+    /// C4: [11000100] [R X B m-mmmm] [W vvvv L pp]
+    /// C5: [11000101] [R vvvv L pp]
+    /// ```
+    ///
+    /// R, X, B and vvvv are stored in inverted (1's complement) form.
+    fn vex_prefix(&self) -> Vec<u8> {
+        let map = self.map.number();
+        let rex = self.rex().unwrap_or(0);
+        let inverted_rxb = !rex & 0b111;
+        let w = u8::from(rex & REX::W as u8 != 0);
+        let vvvv = self.vex_register.map_or(0, |register| register.number());
+        let vvvv_l_pp = ((!vvvv & 0b1111) << 3) | ((self.l & 0b1) << 2) | self.pp();
+
+        if w == 0 && rex & (REX::X as u8 | REX::B as u8) == 0 && map == 0b00001 {
+            vec![0xc5, ((inverted_rxb & 0b100) << 5) | vvvv_l_pp]
+        } else {
+            vec![0xc4, (inverted_rxb << 5) | map, (w << 7) | vvvv_l_pp]
+        }
+    }
+
+    /// The EVEX prefix.
+    ///
+    /// ```text
+    /// 62: [01100010] [R X B R' 0 m m m] [W vvvv 1 pp] [z L'L b V' aaa]
+    /// ```
+    ///
+    /// R, X, B, R', vvvv and V' are stored in inverted form. R' and V' extend the
+    /// registers to 16-31, z and aaa are the opmask, and b is the broadcast,
+    /// they are not supported yet.
+    fn evex_prefix(&self) -> Vec<u8> {
+        let rex = self.rex().unwrap_or(0);
+        let inverted_rxb = !rex & 0b111;
+        let w = u8::from(rex & REX::W as u8 != 0);
+        let vvvv = self.vex_register.map_or(0, |register| register.number());
+
+        vec![
+            0x62,
+            (inverted_rxb << 5) | 0b0001_0000 | self.map.number(),
+            (w << 7) | ((!vvvv & 0b1111) << 3) | 0b100 | self.pp(),
+            ((self.l & 0b11) << 5) | 0b0000_1000,
+        ]
+    }
+
+    /// VEX.pp/EVEX.pp, the mandatory prefix.
+    fn pp(&self) -> u8 {
+        match self.mandatory_prefix {
+            None => 0b00,
+            Some(0x66) => 0b01,
+            Some(0xf3) => 0b10,
+            Some(0xf2) => 0b11,
+            Some(_) => unreachable!(),
+        }
+    }
+
+    fn reg_code(&self) -> u8 {
+        match &self.modrm_reg {
+            Some(ModRmReg::Register(register)) => register.number(),
+            Some(ModRmReg::Extension(digit)) => *digit,
+            None => 0,
+        }
+    }
+
+    fn to_bytes(&self) -> UnresolvedEncoding {
+        let mut bytes = vec![];
+        let mut relative = None;
+
+        // legacy prefix
+        if let Some(Operand::Memory(memory)) = self.modrm_rm {
+            match memory.segment {
+                Some(Register::FS) => bytes.push(0x64),
+                Some(Register::GS) => bytes.push(0x65),
+                _ => {}
+            }
+        }
+
+        if self.operand_size == OperandSize::Word {
+            bytes.push(0x66);
+        }
+
+        // the same order as GAS, e.g. `lock dec word [rax]` -> 66 f0 ff 08
+        if let Some(prefix) = self.prefix {
+            bytes.push(prefix.byte());
+        }
+
+        match self.scheme {
+            EncodingScheme::Legacy => {
+                if let Some(mandatory_prefix) = self.mandatory_prefix {
+                    bytes.push(mandatory_prefix);
+                }
+
+                // REX prefix
+                if let Some(rex) = self.rex() {
+                    bytes.push(rex);
+                }
+
+                bytes.extend_from_slice(self.map.escape());
+            }
+            EncodingScheme::Vex => bytes.extend(self.vex_prefix()),
+            EncodingScheme::Evex => bytes.extend(self.evex_prefix()),
+        }
+
+        let reg_code = self.reg_code();
+
+        // opcode
+        match &self.opcode_register {
+            Some(register) => bytes.push(self.opcode + (register.number() & 0b111)),
+            None => bytes.push(self.opcode),
+        }
+
+        // ModRM, SIB and displacement
+        match self.modrm_rm {
+            Some(Operand::Register(register)) => {
+                let modrm = ModRM {
+                    mode: 0b11,
+                    register: reg_code,
+                    reg_or_memory: register.number(),
+                };
+                bytes.push(modrm.to_byte());
+            }
+            Some(Operand::Memory(memory)) => {
+                relative = encode_memory(
+                    reg_code,
+                    memory,
+                    self.fixed_size,
+                    self.disp8_scale,
+                    &mut bytes,
+                );
+            }
+            _ => {
+                if let Some(modrm) = self.modrm {
+                    bytes.push(modrm);
+                }
+            }
+        }
+
+        // immediate
+        if let Some((label, size)) = self.label {
+            relative = Some(RelativeField {
+                offset: bytes.len(),
+                size,
+                label: label.to_owned(),
+                displacement: 0,
+            });
+            bytes.resize(bytes.len() + (size.bits() / 8) as usize, 0);
+        }
+
+        bytes.extend_from_slice(&self.immediate);
+
+        UnresolvedEncoding { bytes, relative }
+    }
+}
+
+/// Encodes the ModRM, SIB and displacement of a memory operand.
+///
+/// Special cases:
+/// - ModRM.r/m=100 indicates that a SIB byte follows, so RSP/R12 as the base
+///   always requires the SIB byte.
+/// - ModRM.mod=00 and ModRM.r/m=101 indicates RIP+disp32, so RBP/R13 as the base
+///   always requires a displacement (disp8 = 0).
+/// - SIB.index=100 indicates no index, so RSP can not be used as the index.
+///
+/// The memory operand must have been validated.
+///
+/// The disp8 of EVEX is scaled by `disp8_scale` (i.e. disp8*N), e.g. `[rax + 0x40]`
+/// with a 512-bit memory operand is disp8 = 1. The displacement that is not a
+/// multiple of N requires disp32.
+///
+/// Returns the relative field if the memory operand refers to a symbol, e.g. `[rel num1]`.
+fn encode_memory(
+    reg_code: u8,
+    memory: &MemoryOperand,
+    fixed_size: bool,
+    disp8_scale: i32,
+    bytes: &mut Vec<u8>,
+) -> Option<RelativeField> {
+    let displacement = memory.displacement as i32;
+
+    if memory.rip_relative {
+        let modrm = ModRM {
+            mode: 0b00,
+            register: reg_code,
+            reg_or_memory: 0b101,
+        };
+
+        bytes.push(modrm.to_byte());
+
+        // the displacement of symbol is filled after the length of instruction is known
+        return match &memory.symbol {
+            Some(symbol) => {
+                let field = RelativeField {
+                    offset: bytes.len(),
+                    size: OperandSize::Dword,
+                    label: symbol.clone(),
+                    displacement: memory.displacement,
+                };
+                bytes.extend_from_slice(&[0; 4]);
+                Some(field)
+            }
+            None => {
+                bytes.extend_from_slice(&displacement.to_le_bytes());
+                None
+            }
+        };
+    }
+
+    let Some(base) = &memory.base else {
+        // absolute address with segment override, e.g. `fs:[0x28]`,
+        // ModRM.r/m=100 and SIB.base=101 indicate no base register and disp32.
+        let index_code = memory.index.as_ref().map_or(0b100, Register::number);
+
+        let modrm = ModRM {
+            mode: 0b00,
+            register: reg_code,
+            reg_or_memory: 0b100,
+        };
+
+        let sib = SIB {
+            scale: scale_code(memory.scale),
+            index: index_code,
+            base: 0b101,
+        };
+
+        bytes.push(modrm.to_byte());
+        bytes.push(sib.to_byte());
+        bytes.extend_from_slice(&displacement.to_le_bytes());
+        return None;
+    };
+
+    let base_code = base.number();
+
+    let mode = if fixed_size {
+        0b10
+    } else if displacement == 0 && base_code & 0b111 != 0b101 {
+        0b00
+    } else if displacement % disp8_scale == 0 && i8::try_from(displacement / disp8_scale).is_ok() {
+        0b01
+    } else {
+        0b10
+    };
+
+    if memory.index.is_some() || base_code & 0b111 == 0b100 {
+        let index_code = memory.index.as_ref().map_or(0b100, Register::number);
+
+        let modrm = ModRM {
+            mode,
+            register: reg_code,
+            reg_or_memory: 0b100,
+        };
+
+        let sib = SIB {
+            scale: scale_code(memory.scale),
+            index: index_code,
+            base: base_code,
+        };
+
+        bytes.push(modrm.to_byte());
+        bytes.push(sib.to_byte());
+    } else {
+        let modrm = ModRM {
+            mode,
+            register: reg_code,
+            reg_or_memory: base_code,
+        };
+
+        bytes.push(modrm.to_byte());
+    }
+
+    match mode {
+        0b01 => bytes.push((displacement / disp8_scale) as i8 as u8),
+        0b10 => bytes.extend_from_slice(&displacement.to_le_bytes()),
+        _ => {}
+    }
+
+    None
+}
+
+fn scale_code(scale: u8) -> u8 {
+    match scale {
+        1 => 0b00,
+        2 => 0b01,
+        4 => 0b10,
+        _ => 0b11,
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        instruction::{
+            Instruction, MemoryOperand, MemoryOperandError, Operand,
+            OperandSize::{self, *},
+            Prefix,
+            Register::{self, *},
+        },
+        mnemonic::{
+            Condition,
+            Mnemonic::{self, *},
+        },
+    };
+
+    use super::{EncodeError, EncodeOptions, Fixup, encode, encode_with_options};
+
+    // in the order of condition code
+    const CONDITIONS: [Condition; 16] = [
+        Condition::O,
+        Condition::NO,
+        Condition::B,
+        Condition::AE,
+        Condition::E,
+        Condition::NE,
+        Condition::BE,
+        Condition::A,
+        Condition::S,
+        Condition::NS,
+        Condition::P,
+        Condition::NP,
+        Condition::L,
+        Condition::GE,
+        Condition::LE,
+        Condition::G,
+    ];
+
+    fn encode_instruction(mnemonic: Mnemonic, operands: &[Operand]) -> Vec<u8> {
+        encode(&Instruction::new(mnemonic, operands), 0, &[])
+            .unwrap()
+            .bytes
+    }
+
+    fn encode_instruction_fixed_size(mnemonic: Mnemonic, operands: &[Operand]) -> Vec<u8> {
+        let options = EncodeOptions {
+            fixed_size: true,
+            ..Default::default()
+        };
+        encode_with_options(&Instruction::new(mnemonic, operands), 0, &[], &options)
+            .unwrap()
+            .bytes
+    }
+
+    fn encode_instruction_at(
+        mnemonic: Mnemonic,
+        operands: &[Operand],
+        current_address: u64,
+        labels: &[(&str, u64)],
+    ) -> Vec<u8> {
+        let encoded = encode(&Instruction::new(mnemonic, operands), current_address, labels)
+            .unwrap();
+        assert!(encoded.fixups.is_empty());
+        encoded.bytes
+    }
+
+    fn mem(size: OperandSize, base: Register) -> MemoryOperand {
+        MemoryOperand::new(size, base)
+    }
+
+    fn rel(size: OperandSize, symbol: &str) -> MemoryOperand {
+        MemoryOperand::new_rip_relative(size).with_symbol(symbol)
+    }
+
     #[test]
     fn test_encode_mov() {
 
@@ -78,7 +1089,11 @@ mod tests {
         // mov ax, cx   -> 66    89 c8
         // mov al, cl   ->       88 c8
 
-        // todo
+        assert_eq!(encode_instruction(MOV, &[RAX.into(), RCX.into()]), [0x48, 0x89, 0xc8]);
+        assert_eq!(encode_instruction(MOV, &[RDX.into(), RBX.into()]), [0x48, 0x89, 0xda]);
+        assert_eq!(encode_instruction(MOV, &[EAX.into(), ECX.into()]), [0x89, 0xc8]);
+        assert_eq!(encode_instruction(MOV, &[AX.into(), CX.into()]), [0x66, 0x89, 0xc8]);
+        assert_eq!(encode_instruction(MOV, &[AL.into(), CL.into()]), [0x88, 0xc8]);
 
         // Test: extended registers (R8-R15) access
         //
@@ -88,13 +1103,23 @@ mod tests {
         // mov r9b, cl  ->    41 88 c9
         // mov ecx, r9d ->    44 89 c9 (REX 44 = 0100 0100, W=0, R=1, X=0, B=0) (ModRM byte c9 = 11 001 001, mod=11, reg=001, r/m=001)
 
-        // todo
+        assert_eq!(encode_instruction(MOV, &[R9.into(), RCX.into()]), [0x49, 0x89, 0xc9]);
+        assert_eq!(encode_instruction(MOV, &[R9D.into(), ECX.into()]), [0x41, 0x89, 0xc9]);
+        assert_eq!(encode_instruction(MOV, &[R9W.into(), CX.into()]), [0x66, 0x41, 0x89, 0xc9]);
+        assert_eq!(encode_instruction(MOV, &[R9B.into(), CL.into()]), [0x41, 0x88, 0xc9]);
+        assert_eq!(encode_instruction(MOV, &[ECX.into(), R9D.into()]), [0x44, 0x89, 0xc9]);
+
+        // SPL, BPL, SIL and DIL require the REX prefix
+        //
+        // mov sil, dil -> 40 88 fe
+
+        assert_eq!(encode_instruction(MOV, &[SIL.into(), DIL.into()]), [0x40, 0x88, 0xfe]);
 
         // Test: extended register but 16-bit operand size
         //
         // mov cx, r9w  -> 66 44 89 c9
 
-        // todo
+        assert_eq!(encode_instruction(MOV, &[CX.into(), R9W.into()]), [0x66, 0x44, 0x89, 0xc9]);
     }
 
     #[test]
@@ -172,21 +1197,81 @@ mod tests {
         // mov ax, [rbx]  -> 66 8b 03
         // mov al, [rbx]  ->    8a 03
 
-        // todo
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), mem(Qword, RBX).into()]),
+            [0x48, 0x8b, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[EAX.into(), mem(Dword, RBX).into()]),
+            [0x8b, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[AX.into(), mem(Word, RBX).into()]),
+            [0x66, 0x8b, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[AL.into(), mem(Byte, RBX).into()]),
+            [0x8a, 0x03]
+        );
 
         // mov [rbx], rax ->    48 89 03 (REX 48 = 0100 1000, W=1, R=0, X=0, B=0) (ModRM byte 03 = 00 000 011, mod=00, reg=000, r/m=011)
         // mov [ebx], eax -> 67    89 03 (encodes with prefix 67h, forcing 32-bit compatibility addressing, limited to low 4 GiB)
         // mov [bx], ax   -> invalid 64-bit effective address in 64-bit mode
         // mov [bl], al   -> invalid 64-bit effective address in 64-bit mode
 
-        // todo
+        assert_eq!(
+            encode_instruction(MOV, &[mem(Qword, RBX).into(), RAX.into()]),
+            [0x48, 0x89, 0x03]
+        );
 
         // Test: memory with segment override prefix
         //
         // mov rax, gs:[rbx] -> 65 48 8b 03 (segment override prefix 65h for GS)
         // mov rax, fs:[rbx] -> 64 48 8b 03 (segment override prefix 64h for FS)
 
-        // todo
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), mem(Qword, RBX).with_segment(GS).into()]),
+            [0x65, 0x48, 0x8b, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), mem(Qword, RBX).with_segment(FS).into()]),
+            [0x64, 0x48, 0x8b, 0x03]
+        );
+
+        // mov ax, fs:[rbx]                       -> 64 66 8b 03
+        // mov rax, qword fs:[0x28]               -> 64 48 8b 04 25 28000000 (ModRM byte 04 = 00 000 100, r/m=100(SIB)) (SIB byte 25 = 00 100 101, index=100(none), base=101(disp32))
+        // mov r10, qword gs:[r12 + rsi*4 - 0x10] -> 65 4d 8b 54 b4 f0
+
+        assert_eq!(
+            encode_instruction(MOV, &[AX.into(), mem(Word, RBX).with_segment(FS).into()]),
+            [0x64, 0x66, 0x8b, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    MemoryOperand::new_absolute(Qword, 0x28)
+                        .with_segment(FS)
+                        .into()
+                ]
+            ),
+            [0x64, 0x48, 0x8b, 0x04, 0x25, 0x28, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    R10.into(),
+                    mem(Qword, R12)
+                        .with_segment(GS)
+                        .with_index(RSI, 4)
+                        .with_displacement(-0x10)
+                        .into()
+                ]
+            ),
+            [0x65, 0x4d, 0x8b, 0x54, 0xb4, 0xf0]
+        );
     }
 
     #[test]
@@ -256,7 +1341,66 @@ mod tests {
         // mov r8, [rbp + 0x10]         -> 4c 8b 45 10 (displacement 10h = 16)
         // mov r8, [rbp + 0x1234]       -> 4c 8b 85 34120000 (displacement 34120000h)
 
-        // todo
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(0x10).into()]
+            ),
+            [0x48, 0x8b, 0x43, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[mem(Qword, RBX).with_displacement(0x10).into(), RAX.into()]
+            ),
+            [0x48, 0x89, 0x43, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(0x12345678).into()]
+            ),
+            [0x48, 0x8b, 0x83, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(0x1234).into()]
+            ),
+            [0x48, 0x8b, 0x83, 0x34, 0x12, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[R8.into(), mem(Qword, RBP).into()]),
+            [0x4c, 0x8b, 0x45, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[R8.into(), mem(Qword, RBP).with_displacement(0x10).into()]
+            ),
+            [0x4c, 0x8b, 0x45, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[R8.into(), mem(Qword, RBP).with_displacement(0x1234).into()]
+            ),
+            [0x4c, 0x8b, 0x85, 0x34, 0x12, 0x00, 0x00]
+        );
+
+        // R13 has the same low 3 bits as RBP, and R12 has the same low 3 bits as RSP
+        //
+        // mov rax, [r13] -> 49 8b 45 00
+        // mov rax, [r12] -> 49 8b 04 24
+
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), mem(Qword, R13).into()]),
+            [0x49, 0x8b, 0x45, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), mem(Qword, R12).into()]),
+            [0x49, 0x8b, 0x04, 0x24]
+        );
 
         // Test: move from memory to register
         //
@@ -268,8 +1412,9 @@ mod tests {
         // mov eax, [num1]     -> 8b 04 25 00000000 (ModRM byte 04 = 00 000 100, mod=00, reg=000, r/m=100) (SIB byte 25 = 00 100 101, scale=00, index=100(none), base=101(disp32)) (displacement 00000000h = address of num1)
         // mov eax, [num2]     -> 8b 04 25 02000000 (displacement 02000000h = address of num2)
         // mov eax, [num3]     -> 8b 04 25 04000000 (displacement 04000000h = address of num3)
-
-        // todo
+        //
+        // Note: ANASM generates position-independent code only, so the absolute address
+        // of a symbol is not supported, `[num1]` is parsed as `[rel num1]`.
 
         // Test: RIP-relative addressing
         //
@@ -277,7 +1422,50 @@ mod tests {
         // mov eax, [rel num2] -> 8b 05 f6ffffff (displacement f6ffffffh = -10)
         // mov eax, [rel num3] -> 8b 05 f8ffffff (displacement f8ffffffh = -8)
 
-        // todo
+        let labels = [("num1", 0), ("num2", 2), ("num3", 4)];
+
+        assert_eq!(
+            encode_instruction_at(MOV, &[EAX.into(), rel(Unsized, "num1").into()], 6, &labels),
+            [0x8b, 0x05, 0xf4, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_at(MOV, &[EAX.into(), rel(Unsized, "num2").into()], 6, &labels),
+            [0x8b, 0x05, 0xf6, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_at(MOV, &[EAX.into(), rel(Unsized, "num3").into()], 6, &labels),
+            [0x8b, 0x05, 0xf8, 0xff, 0xff, 0xff]
+        );
+
+        // Test: RIP-relative addressing by displacement
+        //
+        // mov eax, dword [rip + 0x10]  -> 8b 05 10000000
+        // mov dword [rip - 0x10], r8d  -> 44 89 05 f0ffffff
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    EAX.into(),
+                    MemoryOperand::new_rip_relative(Dword)
+                        .with_displacement(0x10)
+                        .into()
+                ]
+            ),
+            [0x8b, 0x05, 0x10, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    MemoryOperand::new_rip_relative(Dword)
+                        .with_displacement(-0x10)
+                        .into(),
+                    R8D.into()
+                ]
+            ),
+            [0x44, 0x89, 0x05, 0xf0, 0xff, 0xff, 0xff]
+        );
 
         // Test: RIP-relative addressing, move from register to memory
         //
@@ -285,7 +1473,36 @@ mod tests {
         // mov dword [rel num2], eax -> 89 05 f6ffffff
         // mov dword [rel num3], eax -> 89 05 f8ffffff
 
-        // todo
+        assert_eq!(
+            encode_instruction_at(MOV, &[rel(Dword, "num1").into(), EAX.into()], 6, &labels),
+            [0x89, 0x05, 0xf4, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_at(MOV, &[rel(Dword, "num2").into(), EAX.into()], 6, &labels),
+            [0x89, 0x05, 0xf6, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_at(MOV, &[rel(Dword, "num3").into(), EAX.into()], 6, &labels),
+            [0x89, 0x05, 0xf8, 0xff, 0xff, 0xff]
+        );
+
+        // Test: RIP-relative addressing with immediate, the displacement is relative to
+        // the end of the instruction, i.e. after the immediate.
+        //
+        // mov dword [rel num1 + 4], 0x12 -> c7 05 f4ffffff 12000000 (0 + 4 - (6 + 10) = -12 = f4ffffff)
+
+        assert_eq!(
+            encode_instruction_at(
+                MOV,
+                &[
+                    rel(Dword, "num1").with_displacement(4).into(),
+                    Operand::Immediate8(0x12)
+                ],
+                6,
+                &labels
+            ),
+            [0xc7, 0x05, 0xf4, 0xff, 0xff, 0xff, 0x12, 0x00, 0x00, 0x00]
+        );
     }
 
     #[test]
@@ -343,6 +1560,160 @@ mod tests {
         // mov rax, [rsp + rsi*2]          -> 48 8b 04 74 (SIB byte 74 = 01 110 100, scale=01(*2))
         // mov rax, [rsp + rsi*4 + 0x10]   -> 48 8b 44 b4 10 (SIB byte b4 = 10 110 100, scale=10(*4)) (displacement 10h = 16)
         // mov rax, [rsp + rsi*4 + 0x1234] -> 48 8b 84 b4 34120000 (displacement 34120000h)
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RCX).with_index(RSI, 1).into()]
+            ),
+            [0x48, 0x8b, 0x04, 0x31]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RCX).with_index(RSI, 2).into()]
+            ),
+            [0x48, 0x8b, 0x04, 0x71]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Qword, RCX)
+                        .with_index(RSI, 4)
+                        .with_displacement(0x10)
+                        .into()
+                ]
+            ),
+            [0x48, 0x8b, 0x44, 0xb1, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Qword, RCX)
+                        .with_index(RSI, 4)
+                        .with_displacement(0x1234)
+                        .into()
+                ]
+            ),
+            [0x48, 0x8b, 0x84, 0xb1, 0x34, 0x12, 0x00, 0x00]
+        );
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBP).with_index(RSI, 1).into()]
+            ),
+            [0x48, 0x8b, 0x44, 0x35, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBP).with_index(RSI, 2).into()]
+            ),
+            [0x48, 0x8b, 0x44, 0x75, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Qword, RBP)
+                        .with_index(RSI, 4)
+                        .with_displacement(0x10)
+                        .into()
+                ]
+            ),
+            [0x48, 0x8b, 0x44, 0xb5, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Qword, RBP)
+                        .with_index(RSI, 4)
+                        .with_displacement(0x1234)
+                        .into()
+                ]
+            ),
+            [0x48, 0x8b, 0x84, 0xb5, 0x34, 0x12, 0x00, 0x00]
+        );
+
+        assert_eq!(
+            encode_instruction(MOV, &[R8.into(), mem(Qword, RSP).into()]),
+            [0x4c, 0x8b, 0x04, 0x24]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[R8.into(), mem(Qword, RSP).with_displacement(-0x10).into()]
+            ),
+            [0x4c, 0x8b, 0x44, 0x24, 0xf0]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[R8.into(), mem(Qword, RSP).with_displacement(-0x1234).into()]
+            ),
+            [0x4c, 0x8b, 0x84, 0x24, 0xcc, 0xed, 0xff, 0xff]
+        );
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RSP).with_index(RSI, 1).into()]
+            ),
+            [0x48, 0x8b, 0x04, 0x34]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RSP).with_index(RSI, 2).into()]
+            ),
+            [0x48, 0x8b, 0x04, 0x74]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Qword, RSP)
+                        .with_index(RSI, 4)
+                        .with_displacement(0x10)
+                        .into()
+                ]
+            ),
+            [0x48, 0x8b, 0x44, 0xb4, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Qword, RSP)
+                        .with_index(RSI, 4)
+                        .with_displacement(0x1234)
+                        .into()
+                ]
+            ),
+            [0x48, 0x8b, 0x84, 0xb4, 0x34, 0x12, 0x00, 0x00]
+        );
+
+        // extended index register
+        //
+        // mov rax, [r9 + r10*8] -> 4b 8b 04 d1 (REX 4b = 0100 1011, W=1, R=0, X=1, B=1)
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, R9).with_index(R10, 8).into()]
+            ),
+            [0x4b, 0x8b, 0x04, 0xd1]
+        );
     }
 
     #[test]
@@ -374,6 +1745,52 @@ mod tests {
         // mov ax, 0xcdef              -> 66    b8 efcd
         // mov r10b, 0xef              ->    41 b2 ef (REX 41 = 0100 0001, W=0, R=0, X=0, B=1) (opcode b2 = b0 + 2, rd=010(r10b))
         // mov al, 0xef                ->       b0 ef (Opcode b0 = b0 + 0, rd=000(al))
+
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), Operand::Immediate64(0x1234567890abcdef)]),
+            [0x48, 0xb8, 0xef, 0xcd, 0xab, 0x90, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[EAX.into(), Operand::Immediate32(0x90abcdef)]),
+            [0xb8, 0xef, 0xcd, 0xab, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[ECX.into(), Operand::Immediate32(0x90abcdef)]),
+            [0xb9, 0xef, 0xcd, 0xab, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[R10.into(), Operand::Immediate64(0x1234567890abcdef)]),
+            [0x49, 0xba, 0xef, 0xcd, 0xab, 0x90, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[R10D.into(), Operand::Immediate32(0x90abcdef)]),
+            [0x41, 0xba, 0xef, 0xcd, 0xab, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[R10W.into(), Operand::Immediate16(0xcdef)]),
+            [0x66, 0x41, 0xba, 0xef, 0xcd]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[AX.into(), Operand::Immediate16(0xcdef)]),
+            [0x66, 0xb8, 0xef, 0xcd]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[R10B.into(), Operand::Immediate8(0xef)]),
+            [0x41, 0xb2, 0xef]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[AL.into(), Operand::Immediate8(0xef)]),
+            [0xb0, 0xef]
+        );
+
+        // MOV r/m64, imm32 (sign-extended)
+        //
+        // mov rax, dword 0x90abcdef -> 48 c7 c0 efcdab90 (ModRM byte c0 = 11 000 000, mod=11, reg=000(/0), r/m=000(rax))
+
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), Operand::Immediate32(0x90abcdef)]),
+            [0x48, 0xc7, 0xc0, 0xef, 0xcd, 0xab, 0x90]
+        );
     }
 
     #[test]
@@ -398,6 +1815,159 @@ mod tests {
         // mov byte [rax], 0xef                ->       c6 00 ef
         // mov dword [rcx], 0x90abcdef         ->       c7 01 efcdab90 (ModRM byte 01 = 00 000 001, mod=00, reg=000(/0), r/m=001(rcx))
         // mov dword [r8], 0x90abcdef          ->    41 c7 00 efcdab90 (ModRM byte 00 = 00 000 000, mod=00, reg=000(/0), r/m=000(r8))
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[mem(Dword, RAX).into(), Operand::Immediate32(0x90abcdef)]
+            ),
+            [0xc7, 0x00, 0xef, 0xcd, 0xab, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[mem(Word, RAX).into(), Operand::Immediate16(0xcdef)]),
+            [0x66, 0xc7, 0x00, 0xef, 0xcd]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[mem(Byte, RAX).into(), Operand::Immediate8(0xef)]),
+            [0xc6, 0x00, 0xef]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[mem(Dword, RCX).into(), Operand::Immediate32(0x90abcdef)]
+            ),
+            [0xc7, 0x01, 0xef, 0xcd, 0xab, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[mem(Dword, R8).into(), Operand::Immediate32(0x90abcdef)]
+            ),
+            [0x41, 0xc7, 0x00, 0xef, 0xcd, 0xab, 0x90]
+        );
+
+        // mov qword [rax], dword 0x90abcdef -> 48 c7 00 efcdab90 (sign-extended)
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[mem(Qword, RAX).into(), Operand::Immediate32(0x90abcdef)]
+            ),
+            [0x48, 0xc7, 0x00, 0xef, 0xcd, 0xab, 0x90]
+        );
+    }
+
+    #[test]
+    fn test_encode_shortest_form() {
+        // the immediate is selected by value, not by the width of the operand variant.
+        //
+        // mov rax, 1                   -> b8 01000000 (same as `mov eax, 1`, zero-extended)
+        // mov r10, 0x90abcdef          -> 41 ba efcdab90 (same as `mov r10d, 0x90abcdef`)
+        // mov rax, -1                  -> 48 c7 c0 ffffffff (MOV r/m64, imm32, sign-extended)
+        // mov rax, 0x1234567890abcdef  -> 48 b8 efcdab9078563412
+        // mov al, 255                  -> b0 ff
+        // mov qword [rax], -1          -> 48 c7 00 ffffffff
+
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), Operand::Immediate8(1)]),
+            [0xb8, 0x01, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[R10.into(), Operand::Immediate64(0x90abcdef)]),
+            [0x41, 0xba, 0xef, 0xcd, 0xab, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), Operand::Immediate8(0xff)]),
+            [0x48, 0xc7, 0xc0, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[RAX.into(), Operand::Immediate64(0x1234567890abcdef)]),
+            [0x48, 0xb8, 0xef, 0xcd, 0xab, 0x90, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[AL.into(), Operand::Immediate16(255)]),
+            [0xb0, 0xff]
+        );
+        assert_eq!(
+            encode_instruction(MOV, &[mem(Qword, RAX).into(), Operand::Immediate8(0xff)]),
+            [0x48, 0xc7, 0x00, 0xff, 0xff, 0xff, 0xff]
+        );
+
+        // disp8 when the displacement fits in i8, otherwise disp32
+        //
+        // mov rax, [rbx + 0x7f]  -> 48 8b 43 7f
+        // mov rax, [rbx + 0x80]  -> 48 8b 83 80000000
+        // mov rax, [rbx - 0x80]  -> 48 8b 43 80
+        // mov rax, [rbx - 0x81]  -> 48 8b 83 7fffffff
+
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(0x7f).into()]
+            ),
+            [0x48, 0x8b, 0x43, 0x7f]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(0x80).into()]
+            ),
+            [0x48, 0x8b, 0x83, 0x80, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(-0x80).into()]
+            ),
+            [0x48, 0x8b, 0x43, 0x80]
+        );
+        assert_eq!(
+            encode_instruction(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(-0x81).into()]
+            ),
+            [0x48, 0x8b, 0x83, 0x7f, 0xff, 0xff, 0xff]
+        );
+    }
+
+    #[test]
+    fn test_encode_fixed_size() {
+        // the length of encoding does not depend on the values of displacement and immediate.
+        //
+        // mov rax, 1            -> 48 b8 0100000000000000
+        // mov rax, -1           -> 48 b8 ffffffffffffffff
+        // mov rax, [rbx]        -> 48 8b 83 00000000
+        // mov rax, [rbx + 0x10] -> 48 8b 83 10000000
+        // mov rax, [rsp]        -> 48 8b 84 24 00000000
+        // mov eax, 1            -> b8 01000000
+
+        assert_eq!(
+            encode_instruction_fixed_size(MOV, &[RAX.into(), Operand::Immediate8(1)]),
+            [0x48, 0xb8, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction_fixed_size(MOV, &[RAX.into(), Operand::Immediate8(0xff)]),
+            [0x48, 0xb8, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_fixed_size(MOV, &[RAX.into(), mem(Qword, RBX).into()]),
+            [0x48, 0x8b, 0x83, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction_fixed_size(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(0x10).into()]
+            ),
+            [0x48, 0x8b, 0x83, 0x10, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction_fixed_size(MOV, &[RAX.into(), mem(Qword, RSP).into()]),
+            [0x48, 0x8b, 0x84, 0x24, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction_fixed_size(MOV, &[EAX.into(), Operand::Immediate8(1)]),
+            [0xb8, 0x01, 0x00, 0x00, 0x00]
+        );
     }
 
     #[test]
@@ -428,7 +1998,51 @@ mod tests {
         // lea rax, [rbp+0x10]  -> 48 8d 45 10 (displacement 10h = 16)
         // lea rax, [rsp-0x10]  -> 48 8d 44 24 f0 (ModRM byte 44 = 01 000 100, mod=01, reg=000(rax), r/m=100(SIB)) (SIB byte 24 = 00 100 100, scale=00, index=100(none), base=100(rsp)) (displacement f0h = -16)
 
-        // todo
+        // lea rax, m
+        let lea = |memory: MemoryOperand| encode_instruction(LEA, &[RAX.into(), memory.into()]);
+
+        assert_eq!(lea(mem(Unsized, RCX)), [0x48, 0x8d, 0x01]);
+        assert_eq!(lea(mem(Unsized, R9)), [0x49, 0x8d, 0x01]);
+        assert_eq!(lea(mem(Unsized, RCX).with_displacement(0x10)), [0x48, 0x8d, 0x41, 0x10]);
+        assert_eq!(
+            lea(mem(Unsized, RCX).with_displacement(0x1234)),
+            [0x48, 0x8d, 0x81, 0x34, 0x12, 0x00, 0x00]
+        );
+        assert_eq!(
+            lea(mem(Unsized, RCX).with_index(RSI, 4).with_displacement(0x10)),
+            [0x48, 0x8d, 0x44, 0xb1, 0x10]
+        );
+
+        assert_eq!(lea(mem(Unsized, RBP)), [0x48, 0x8d, 0x45, 0x00]);
+        assert_eq!(lea(mem(Unsized, RSP)), [0x48, 0x8d, 0x04, 0x24]);
+        assert_eq!(lea(mem(Unsized, RBP).with_displacement(0x10)), [0x48, 0x8d, 0x45, 0x10]);
+        assert_eq!(
+            lea(mem(Unsized, RSP).with_displacement(-0x10)),
+            [0x48, 0x8d, 0x44, 0x24, 0xf0]
+        );
+
+        // the size of the memory operand is irrelevant, the operand size is
+        // determined by the destination register
+        //
+        // lea eax, [rdi+rdi*2]  -> 8d 04 7f
+        // lea r8w, [rax+1]      -> 66 44 8d 40 01
+        // lea rax, qword [rcx]  -> 48 8d 01
+
+        assert_eq!(
+            encode_instruction(LEA, &[EAX.into(), mem(Unsized, RDI).with_index(RDI, 2).into()]),
+            [0x8d, 0x04, 0x7f]
+        );
+        assert_eq!(
+            encode_instruction(LEA, &[R8W.into(), mem(Unsized, RAX).with_displacement(1).into()]),
+            [0x66, 0x44, 0x8d, 0x40, 0x01]
+        );
+        assert_eq!(lea(mem(Qword, RCX)), [0x48, 0x8d, 0x01]);
+
+        // lea rax, rcx -> invalid, the source must be a memory operand
+        assert_eq!(
+            encode(&Instruction::new(LEA, &[RAX.into(), RCX.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(LEA)
+        );
 
         // Address by label:
         //
@@ -445,8 +2059,44 @@ mod tests {
         // lea rax, [rel num2]
         // lea rax, [rel num3]
         // lea rax, [rel num1 + 0x10]
+        //
+        // Note: `[num1]` is parsed as `[rel num1]`, see `test_encode_mov_memory_with_base_and_displacement`,
+        // and `[rip + num1]` is the same as `[rel num1]`.
+        //
+        // lea rax, [rel num1]        -> 48 8d 05 f3ffffff (displacement f3ffffffh = -13, address of num1 - next instruction address)
+        // lea rax, [rel num2]        -> 48 8d 05 f5ffffff (displacement f5ffffffh = -11)
+        // lea rax, [rel num3]        -> 48 8d 05 f7ffffff (displacement f7ffffffh = -9)
+        // lea rax, [rel num1 + 0x10] -> 48 8d 05 03000000 (displacement 03000000h = 3)
+
+        let labels = [("num1", 0), ("num2", 2), ("num3", 4)];
 
-        // todo
+        assert_eq!(
+            encode_instruction_at(LEA, &[RAX.into(), rel(Unsized, "num1").into()], 6, &labels),
+            [0x48, 0x8d, 0x05, 0xf3, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_at(LEA, &[RAX.into(), rel(Unsized, "num2").into()], 6, &labels),
+            [0x48, 0x8d, 0x05, 0xf5, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_at(LEA, &[RAX.into(), rel(Unsized, "num3").into()], 6, &labels),
+            [0x48, 0x8d, 0x05, 0xf7, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(
+            encode_instruction_at(
+                LEA,
+                &[RAX.into(), rel(Unsized, "num1").with_displacement(0x10).into()],
+                6,
+                &labels
+            ),
+            [0x48, 0x8d, 0x05, 0x03, 0x00, 0x00, 0x00]
+        );
+
+        // lea rax, [rip + 0x10] -> 48 8d 05 10000000
+        assert_eq!(
+            lea(MemoryOperand::new_rip_relative(Unsized).with_displacement(0x10)),
+            [0x48, 0x8d, 0x05, 0x10, 0x00, 0x00, 0x00]
+        );
     }
 
     #[test]
@@ -496,7 +2146,18 @@ mod tests {
         // movzx eax, cx ->    0f b7 c1
         // movzx rax, cx -> 48 0f b7 c1
 
-        // todo
+        assert_eq!(encode_instruction(MOVZX, &[AX.into(), CL.into()]), [0x66, 0x0f, 0xb6, 0xc1]);
+        assert_eq!(encode_instruction(MOVZX, &[EAX.into(), CL.into()]), [0x0f, 0xb6, 0xc1]);
+        assert_eq!(encode_instruction(MOVZX, &[RAX.into(), CL.into()]), [0x48, 0x0f, 0xb6, 0xc1]);
+        assert_eq!(encode_instruction(MOVZX, &[EAX.into(), CX.into()]), [0x0f, 0xb7, 0xc1]);
+        assert_eq!(encode_instruction(MOVZX, &[RAX.into(), CX.into()]), [0x48, 0x0f, 0xb7, 0xc1]);
+
+        // movzx r9d, byte [r10] -> 45 0f b6 0a
+
+        assert_eq!(
+            encode_instruction(MOVZX, &[R9D.into(), mem(Byte, R10).into()]),
+            [0x45, 0x0f, 0xb6, 0x0a]
+        );
 
         // MOVSX/MOVSXD  -- Move With Sign-Extension
         //
@@ -529,9 +2190,24 @@ mod tests {
         // movsx rax, cx   ->    48 0f bf c1
         // movsxd rax, ecx ->    48    63 c1
 
-        // todo
+        assert_eq!(encode_instruction(MOVSX, &[AX.into(), CL.into()]), [0x66, 0x0f, 0xbe, 0xc1]);
+        assert_eq!(encode_instruction(MOVSX, &[EAX.into(), CL.into()]), [0x0f, 0xbe, 0xc1]);
+        assert_eq!(encode_instruction(MOVSX, &[RAX.into(), CL.into()]), [0x48, 0x0f, 0xbe, 0xc1]);
+        assert_eq!(encode_instruction(MOVSX, &[EAX.into(), CX.into()]), [0x0f, 0xbf, 0xc1]);
+        assert_eq!(encode_instruction(MOVSX, &[RAX.into(), CX.into()]), [0x48, 0x0f, 0xbf, 0xc1]);
+        assert_eq!(encode_instruction(MOVSXD, &[RAX.into(), ECX.into()]), [0x48, 0x63, 0xc1]);
+
+        // movsxd rax, dword [rbx + 8] -> 48 63 43 08
 
-        // Other convertion (ANASOM does not support these instructions)
+        assert_eq!(
+            encode_instruction(
+                MOVSXD,
+                &[RAX.into(), mem(Dword, RBX).with_displacement(8).into()]
+            ),
+            [0x48, 0x63, 0x43, 0x08]
+        );
+
+        // Other convertion
         //
         // - cbw:   al -> ax
         // - cwde:  ax -> eax
@@ -561,5 +2237,2261 @@ mod tests {
         // | Op/En | Operand 1 | Operand 2 | Operand 3 | Operand 4 |
         // | ---   |  ---      |  ---      |  ---      |  ---      |
         // | ZO    | N/A       | N/A       | N/A       | N/A       |
+        //
+        // cbw  -> 66 98
+        // cwde -> 98
+        // cdqe -> 48 98
+        // cwd  -> 66 99
+        // cdq  -> 99
+        // cqo  -> 48 99
+
+        assert_eq!(encode_instruction(CBW, &[]), [0x66, 0x98]);
+        assert_eq!(encode_instruction(CWDE, &[]), [0x98]);
+        assert_eq!(encode_instruction(CDQE, &[]), [0x48, 0x98]);
+        assert_eq!(encode_instruction(CWD, &[]), [0x66, 0x99]);
+        assert_eq!(encode_instruction(CDQ, &[]), [0x99]);
+        assert_eq!(encode_instruction(CQO, &[]), [0x48, 0x99]);
+    }
+
+    #[test]
+    fn test_encode_high_byte_register() {
+        // AH, CH, DH and BH share the register numbers 4-7 with SPL, BPL, SIL and DIL,
+        // they are selected by the absence of the REX prefix.
+        //
+        // mov ah, al          ->    88 c4
+        // mov bh, 0x12        ->    b7 12
+        // movzx eax, ah       -> 0f b6 c4
+        // mov byte [rbx], ch  ->    88 2b
+
+        assert_eq!(encode_instruction(MOV, &[AH.into(), AL.into()]), [0x88, 0xc4]);
+        assert_eq!(
+            encode_instruction(MOV, &[BH.into(), Operand::Immediate8(0x12)]),
+            [0xb7, 0x12]
+        );
+        assert_eq!(encode_instruction(MOVZX, &[EAX.into(), AH.into()]), [0x0f, 0xb6, 0xc4]);
+        assert_eq!(encode_instruction(MOV, &[mem(Byte, RBX).into(), CH.into()]), [0x88, 0x2b]);
+    }
+
+    #[test]
+    fn test_encode_error() {
+        fn encode_error(mnemonic: Mnemonic, operands: &[Operand]) -> EncodeError {
+            encode(&Instruction::new(mnemonic, operands), 0, &[]).unwrap_err()
+        }
+
+        // mov ax, [bx] -> invalid 64-bit effective address in 64-bit mode
+        assert_eq!(
+            encode_error(MOV, &[AX.into(), mem(Word, BX).into()]),
+            EncodeError::InvalidMemoryOperand {
+                operand: 1,
+                error: MemoryOperandError::InvalidBase(BX)
+            }
+        );
+
+        // mov [bl], al -> invalid 64-bit effective address in 64-bit mode
+        assert_eq!(
+            encode_error(MOV, &[mem(Byte, BL).into(), AL.into()]),
+            EncodeError::InvalidMemoryOperand {
+                operand: 0,
+                error: MemoryOperandError::InvalidBase(BL)
+            }
+        );
+
+        // mov rax, [rbx + rsp] -> RSP can not be the index
+        assert_eq!(
+            encode_error(MOV, &[RAX.into(), mem(Qword, RBX).with_index(RSP, 1).into()]),
+            EncodeError::InvalidMemoryOperand {
+                operand: 1,
+                error: MemoryOperandError::InvalidIndex(RSP)
+            }
+        );
+
+        // mov rax, [rbx + 0x8000_0000] -> displacement exceeds i32
+        assert_eq!(
+            encode_error(
+                MOV,
+                &[RAX.into(), mem(Qword, RBX).with_displacement(0x8000_0000).into()]
+            ),
+            EncodeError::InvalidMemoryOperand {
+                operand: 1,
+                error: MemoryOperandError::DisplacementOutOfRange(0x8000_0000)
+            }
+        );
+
+        // mov ah, sil / movzx rax, ah -> AH can not be encoded with REX
+        assert_eq!(
+            encode_error(MOV, &[AH.into(), SIL.into()]),
+            EncodeError::HighByteRegisterWithRex {
+                operand: 0,
+                register: AH
+            }
+        );
+        assert_eq!(
+            encode_error(MOVZX, &[RAX.into(), AH.into()]),
+            EncodeError::HighByteRegisterWithRex {
+                operand: 1,
+                register: AH
+            }
+        );
+        assert_eq!(
+            encode_error(MOV, &[mem(Byte, R8).into(), DH.into()]),
+            EncodeError::HighByteRegisterWithRex {
+                operand: 1,
+                register: DH
+            }
+        );
+
+        // mov [rax], 0x10 -> operand size is not specified
+        assert_eq!(
+            encode_error(MOV, &[mem(Unsized, RAX).into(), Operand::Immediate8(0x10)]),
+            EncodeError::AmbiguousOperandSize { operand: 0 }
+        );
+
+        // mov al, 0x100 and mov qword [rax], 0x1234567890abcdef -> immediate exceeds bounds
+        assert_eq!(
+            encode_error(MOV, &[AL.into(), Operand::Immediate16(0x100)]),
+            EncodeError::ImmediateOutOfRange {
+                operand: 1,
+                value: 0x100
+            }
+        );
+        assert_eq!(
+            encode_error(
+                MOV,
+                &[mem(Qword, RAX).into(), Operand::Immediate64(0x1234567890abcdef)]
+            ),
+            EncodeError::ImmediateOutOfRange {
+                operand: 1,
+                value: 0x1234567890abcdef
+            }
+        );
+
+        // mov eax, cx -> operand sizes mismatch
+        assert_eq!(
+            encode_error(MOV, &[EAX.into(), CX.into()]),
+            EncodeError::InvalidOperands(MOV)
+        );
+    }
+
+    #[test]
+    fn test_encode_label() {
+        // CALL -- Call Procedure
+        //
+        // | Opcode | Instruction | Op/En | 64-bit Mode | Compat/Leg Mode | Description |
+        // | ---    |  ---        |  ---  |  ---        |  ---            |  ---        |
+        // | E8 cd  | CALL rel32  | D     | Valid       | Valid           | Call near, relative, displacement relative to next instruction. |
+        //
+        // JMP -- Jump
+        //
+        // | Opcode | Instruction | Op/En | 64-bit Mode | Compat/Leg Mode | Description |
+        // | ---    |  ---        |  ---  |  ---        |  ---            |  ---        |
+        // | EB cb  | JMP rel8    | D     | Valid       | Valid           | Jump short, RIP = RIP + 8-bit displacement sign extended to 64-bits. |
+        // | E9 cd  | JMP rel32   | D     | Valid       | Valid           | Jump near, relative, RIP = RIP + 32-bit displacement sign extended to 64-bits. |
+
+        // Test: the label is resolved
+        //
+        // 0x1000: call func -> e8 fb0f0000 (0x2000 - 0x1005 = 0x0ffb)
+        // 0x2000: jmp start -> e9 fbefffff (0x1000 - 0x2005 = -0x1005)
+
+        let labels = [("start", 0x1000), ("func", 0x2000)];
+
+        assert_eq!(
+            encode_instruction_at(CALL, &[Operand::Label("func".to_owned())], 0x1000, &labels),
+            [0xe8, 0xfb, 0x0f, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction_at(JMP, &[Operand::Label("start".to_owned())], 0x2000, &labels),
+            [0xe9, 0xfb, 0xef, 0xff, 0xff]
+        );
+
+        // Test: the short form is selected if the label is in the range of rel8,
+        // unless the long branch is required.
+        //
+        // 0x1000: jmp .next  -> eb 10 (0x1012 - 0x1002)
+        // 0x1000: jmp .prev  -> eb 80 (0x0f82 - 0x1002 = -128)
+        // 0x1000: jmp .far   -> e9 7d000000 (0x1082 - 0x1002 = 128 exceeds rel8, 0x1082 - 0x1005 = 0x7d)
+
+        let labels = [(".next", 0x1012), (".prev", 0x0f82), (".far", 0x1082)];
+
+        assert_eq!(
+            encode_instruction_at(JMP, &[Operand::Label(".next".to_owned())], 0x1000, &labels),
+            [0xeb, 0x10]
+        );
+        assert_eq!(
+            encode_instruction_at(JMP, &[Operand::Label(".prev".to_owned())], 0x1000, &labels),
+            [0xeb, 0x80]
+        );
+        assert_eq!(
+            encode_instruction_at(JMP, &[Operand::Label(".far".to_owned())], 0x1000, &labels),
+            [0xe9, 0x7d, 0x00, 0x00, 0x00]
+        );
+
+        let options = EncodeOptions {
+            long_branch: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            encode_with_options(
+                &Instruction::new(JMP, &[Operand::Label(".next".to_owned())]),
+                0x1000,
+                &labels,
+                &options
+            )
+            .unwrap()
+            .bytes,
+            [0xe9, 0x0d, 0x00, 0x00, 0x00]
+        );
+
+        // Test: the label is not resolved, the field is left zero and a fixup is recorded,
+        // the addend is the displacement minus the distance from the field to the next instruction.
+        //
+        // call func                      -> e8 00000000, fixup at 1, addend -4
+        // mov eax, [rel num1 + 8]        -> 8b 05 00000000, fixup at 2, addend 4
+        // mov dword [rel num1], 0x12     -> c7 05 00000000 12000000, fixup at 2, addend -8
+
+        let encoded = encode(
+            &Instruction::new(CALL, &[Operand::Label("func".to_owned())]),
+            0x1000,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(encoded.bytes, [0xe8, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            encoded.fixups,
+            [Fixup {
+                offset: 1,
+                size: Dword,
+                label: "func".to_owned(),
+                addend: -4
+            }]
+        );
+
+        let encoded = encode(
+            &Instruction::new(
+                MOV,
+                &[EAX.into(), rel(Unsized, "num1").with_displacement(8).into()],
+            ),
+            0,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(encoded.bytes, [0x8b, 0x05, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            encoded.fixups,
+            [Fixup {
+                offset: 2,
+                size: Dword,
+                label: "num1".to_owned(),
+                addend: 4
+            }]
+        );
+
+        let encoded = encode(
+            &Instruction::new(
+                MOV,
+                &[rel(Dword, "num1").into(), Operand::Immediate8(0x12)],
+            ),
+            0,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            encoded.bytes,
+            [0xc7, 0x05, 0x00, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encoded.fixups,
+            [Fixup {
+                offset: 2,
+                size: Dword,
+                label: "num1".to_owned(),
+                addend: -8
+            }]
+        );
+
+        // Test: the label is out of range of rel32
+        assert_eq!(
+            encode(
+                &Instruction::new(CALL, &[Operand::Label("func".to_owned())]),
+                0,
+                &[("func", 0x1_0000_0000)],
+            )
+            .unwrap_err(),
+            EncodeError::RelativeOutOfRange {
+                label: "func".to_owned(),
+                distance: 0x1_0000_0000 - 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_encode_jcc() {
+        // Jcc -- Jump if Condition Is Met
+        //
+        // | Opcode   | Instruction | Op/En | 64-Bit Mode | Compat/Leg Mode | Description |
+        // | ---      |  ---        |  ---  |  ---        |  ---            |  ---        |
+        // | 70 cb    | JO rel8     | D     | Valid       | Valid           | Jump short if overflow (OF=1). |
+        // | 72 cb    | JB rel8     | D     | Valid       | Valid           | Jump short if below (CF=1). |
+        // | 74 cb    | JE rel8     | D     | Valid       | Valid           | Jump short if equal (ZF=1). |
+        // | ...      |             |       |             |                 |             |
+        // | 0F 80 cd | JO rel32    | D     | Valid       | Valid           | Jump near if overflow (OF=1). |
+        // | 0F 84 cd | JE rel32    | D     | Valid       | Valid           | Jump near if equal (ZF=1). |
+        // | ...      |             |       |             |                 |             |
+        //
+        // Test
+        //
+        // 0x1000: je .near  -> 74 0e             (0x1010 - 0x1002)
+        // 0x1000: je .far   -> 0f 84 fa0f0000    (0x2000 - 0x1006)
+
+        let labels = [(".near", 0x1010), (".far", 0x2000)];
+        for (cc, condition) in CONDITIONS.into_iter().enumerate() {
+            let mnemonic = Jcc(condition);
+            assert_eq!(
+                encode_instruction_at(mnemonic, &[Operand::Label(".near".to_owned())], 0x1000, &labels),
+                [0x70 + cc as u8, 0x0e]
+            );
+            assert_eq!(
+                encode_instruction_at(mnemonic, &[Operand::Label(".far".to_owned())], 0x1000, &labels),
+                [0x0f, 0x80 + cc as u8, 0xfa, 0x0f, 0x00, 0x00]
+            );
+        }
+
+        // aliases are the same mnemonic, e.g. JZ is JE, JC and JNAE are JB.
+        assert_eq!(Mnemonic::from_name("jz"), Some(Jcc(Condition::E)));
+        assert_eq!(Mnemonic::from_name("JC"), Some(Jcc(Condition::B)));
+        assert_eq!(Mnemonic::from_name("jnae"), Some(Jcc(Condition::B)));
+        assert_eq!(Mnemonic::from_name("jnle"), Some(Jcc(Condition::G)));
+        assert_eq!(Mnemonic::from_name("jpo"), Some(Jcc(Condition::NP)));
+        assert_eq!(Jcc(Condition::B).name(), "jb");
+
+        // unresolved label uses the near form
+        //
+        // jne .next -> 0f 85 00000000, fixup at 2, addend -4
+        let encoded = encode(
+            &Instruction::new(Jcc(Condition::NE), &[Operand::Label(".next".to_owned())]),
+            0,
+            &[],
+        )
+        .unwrap();
+        assert_eq!(encoded.bytes, [0x0f, 0x85, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(
+            encoded.fixups,
+            [Fixup {
+                offset: 2,
+                size: Dword,
+                label: ".next".to_owned(),
+                addend: -4
+            }]
+        );
+    }
+
+    #[test]
+    fn test_encode_call_jmp_ret() {
+        // CALL -- Call Procedure
+        //
+        // | Opcode | Instruction | Op/En | 64-bit Mode | Compat/Leg Mode | Description |
+        // | ---    |  ---        |  ---  |  ---        |  ---            |  ---        |
+        // | E8 cd  | CALL rel32  | D     | Valid       | Valid           | Call near, relative, displacement relative to next instruction. |
+        // | FF /2  | CALL r/m64  | M     | Valid       | N.E.            | Call near, absolute indirect, address given in r/m64. |
+        //
+        // JMP -- Jump
+        //
+        // | Opcode | Instruction | Op/En | 64-bit Mode | Compat/Leg Mode | Description |
+        // | ---    |  ---        |  ---  |  ---        |  ---            |  ---        |
+        // | FF /4  | JMP r/m64   | M     | Valid       | N.E.            | Jump near, absolute indirect, RIP = 64-Bit offset from register or memory. |
+        //
+        // The operand size of near branches is 64-bit by default, REX.W is not required.
+        //
+        // call rax              ->    ff d0
+        // call r11              -> 41 ff d3
+        // call qword [rbx]      ->    ff 13
+        // call qword [r12 + 8]  -> 41 ff 54 24 08
+        // jmp rax               ->    ff e0
+        // jmp r9                -> 41 ff e1
+        // jmp qword [rip + 0x10] ->   ff 25 10000000
+
+        assert_eq!(encode_instruction(CALL, &[RAX.into()]), [0xff, 0xd0]);
+        assert_eq!(encode_instruction(CALL, &[R11.into()]), [0x41, 0xff, 0xd3]);
+        assert_eq!(encode_instruction(CALL, &[mem(Qword, RBX).into()]), [0xff, 0x13]);
+        assert_eq!(
+            encode_instruction(CALL, &[mem(Unsized, R12).with_displacement(8).into()]),
+            [0x41, 0xff, 0x54, 0x24, 0x08]
+        );
+        assert_eq!(encode_instruction(JMP, &[RAX.into()]), [0xff, 0xe0]);
+        assert_eq!(encode_instruction(JMP, &[R9.into()]), [0x41, 0xff, 0xe1]);
+        assert_eq!(
+            encode_instruction(
+                JMP,
+                &[MemoryOperand::new_rip_relative(Qword).with_displacement(0x10).into()]
+            ),
+            [0xff, 0x25, 0x10, 0x00, 0x00, 0x00]
+        );
+
+        // RET -- Return From Procedure
+        //
+        // | Opcode | Instruction | Op/En | 64-Bit Mode | Compat/Leg Mode | Description |
+        // | ---    |  ---        |  ---  |  ---        |  ---            |  ---        |
+        // | C3     | RET         | ZO    | Valid       | Valid           | Near return to calling procedure. |
+        // | C2 iw  | RET imm16   | I     | Valid       | Valid           | Near return to calling procedure and pop imm16 bytes from stack. |
+        //
+        // ret        -> c3
+        // ret 8      -> c2 0800
+        // ret 0xffff -> c2 ffff
+
+        assert_eq!(encode_instruction(RET, &[]), [0xc3]);
+        assert_eq!(encode_instruction(RET, &[Operand::Immediate8(8)]), [0xc2, 0x08, 0x00]);
+        assert_eq!(
+            encode_instruction(RET, &[Operand::Immediate32(0xffff)]),
+            [0xc2, 0xff, 0xff]
+        );
+
+        // call eax -> invalid, the operand size of near branch is 64-bit
+        assert_eq!(
+            encode(&Instruction::new(CALL, &[EAX.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(CALL)
+        );
+    }
+
+    #[test]
+    fn test_encode_alu() {
+        // ADD/OR/ADC/SBB/AND/SUB/XOR/CMP
+        //
+        // Each instruction of the group has the same 9 forms, `n` is the index in the group
+        // (ADD=0, OR=1, ADC=2, SBB=3, AND=4, SUB=5, XOR=6, CMP=7):
+        //
+        // | Opcode           | Instruction        | Op/En |
+        // | ---              |  ---               |  ---  |
+        // | n*8+0 /r         | ADD r/m8, r8       | MR    |
+        // | n*8+1 /r         | ADD r/m32, r32     | MR    |
+        // | n*8+2 /r         | ADD r8, r/m8       | RM    |
+        // | n*8+3 /r         | ADD r32, r/m32     | RM    |
+        // | n*8+4 ib         | ADD AL, imm8       | I     |
+        // | n*8+5 id         | ADD EAX, imm32     | I     |
+        // | 80 /n ib         | ADD r/m8, imm8     | MI    |
+        // | 81 /n id         | ADD r/m32, imm32   | MI    |
+        // | 83 /n ib         | ADD r/m32, imm8    | MI    | (imm8 is sign-extended)
+        //
+        // add rax, rcx                   -> 48 01 c8
+        // add r9d, ecx                   -> 41 01 c9
+        // sub rdx, qword [rbx + 8]       -> 48 2b 53 08
+        // xor r8d, r8d                   -> 45 31 c0
+        // xor sil, dil                   -> 40 30 fe
+        // cmp eax, ebx                   -> 39 d8
+
+        assert_eq!(encode_instruction(ADD, &[RAX.into(), RCX.into()]), [0x48, 0x01, 0xc8]);
+        assert_eq!(encode_instruction(ADD, &[R9D.into(), ECX.into()]), [0x41, 0x01, 0xc9]);
+        assert_eq!(
+            encode_instruction(SUB, &[RDX.into(), mem(Qword, RBX).with_displacement(8).into()]),
+            [0x48, 0x2b, 0x53, 0x08]
+        );
+        assert_eq!(encode_instruction(XOR, &[R8D.into(), R8D.into()]), [0x45, 0x31, 0xc0]);
+        assert_eq!(encode_instruction(XOR, &[SIL.into(), DIL.into()]), [0x40, 0x30, 0xfe]);
+        assert_eq!(encode_instruction(CMP, &[EAX.into(), EBX.into()]), [0x39, 0xd8]);
+
+        // the accumulator forms are selected if they are shorter
+        //
+        // adc al, 1                      -> 14 01
+        // sbb ax, 0x1234                 -> 66 1d 3412
+        // and eax, 0x12345678            -> 25 78563412
+        // add rax, 0x7fffffff            -> 48 05 ffffff7f
+        // cmp rax, -0x80000000           -> 48 3d 00000080
+
+        assert_eq!(encode_instruction(ADC, &[AL.into(), Operand::Immediate8(1)]), [0x14, 0x01]);
+        assert_eq!(
+            encode_instruction(SBB, &[AX.into(), Operand::Immediate16(0x1234)]),
+            [0x66, 0x1d, 0x34, 0x12]
+        );
+        assert_eq!(
+            encode_instruction(AND, &[EAX.into(), Operand::Immediate32(0x12345678)]),
+            [0x25, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(
+            encode_instruction(ADD, &[RAX.into(), Operand::Immediate32(0x7fffffff)]),
+            [0x48, 0x05, 0xff, 0xff, 0xff, 0x7f]
+        );
+        assert_eq!(
+            encode_instruction(CMP, &[RAX.into(), Operand::Immediate32(0x80000000)]),
+            [0x48, 0x3d, 0x00, 0x00, 0x00, 0x80]
+        );
+
+        // the sign-extended imm8 form is selected if the immediate fits in it
+        //
+        // or rax, -1                     -> 48 83 c8 ff
+        // and eax, 0xfffffff0            -> 83 e0 f0
+        // cmp qword [rsp + 8], 0x10      -> 48 83 7c 24 08 10
+        // sub word [rbx], 0x10           -> 66 83 2b 10
+
+        assert_eq!(
+            encode_instruction(OR, &[RAX.into(), Operand::Immediate8(0xff)]),
+            [0x48, 0x83, 0xc8, 0xff]
+        );
+        assert_eq!(
+            encode_instruction(AND, &[EAX.into(), Operand::Immediate64(0xfffffff0)]),
+            [0x83, 0xe0, 0xf0]
+        );
+        assert_eq!(
+            encode_instruction(
+                CMP,
+                &[mem(Qword, RSP).with_displacement(8).into(), Operand::Immediate8(0x10)]
+            ),
+            [0x48, 0x83, 0x7c, 0x24, 0x08, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(SUB, &[mem(Word, RBX).into(), Operand::Immediate8(0x10)]),
+            [0x66, 0x83, 0x2b, 0x10]
+        );
+
+        // otherwise the imm8/imm16/imm32 form
+        //
+        // add cl, 1                      -> 80 c1 01
+        // cmp byte [rax], 0x80           -> 80 38 80
+        // sub r12, 0x80                  -> 49 81 ec 80000000
+        // adc ebx, 0x1000                -> 81 d3 00100000
+        // add sp, 0x1234                 -> 66 81 c4 3412
+
+        assert_eq!(encode_instruction(ADD, &[CL.into(), Operand::Immediate8(1)]), [0x80, 0xc1, 0x01]);
+        assert_eq!(
+            encode_instruction(CMP, &[mem(Byte, RAX).into(), Operand::Immediate16(0x80)]),
+            [0x80, 0x38, 0x80]
+        );
+        assert_eq!(
+            encode_instruction(SUB, &[R12.into(), Operand::Immediate16(0x80)]),
+            [0x49, 0x81, 0xec, 0x80, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(ADC, &[EBX.into(), Operand::Immediate16(0x1000)]),
+            [0x81, 0xd3, 0x00, 0x10, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(ADD, &[SP.into(), Operand::Immediate16(0x1234)]),
+            [0x66, 0x81, 0xc4, 0x34, 0x12]
+        );
+
+        // TEST -- Logical Compare
+        //
+        // | Opcode         | Instruction         | Op/En |
+        // | ---            |  ---                |  ---  |
+        // | A8 ib          | TEST AL, imm8       | I     |
+        // | A9 id          | TEST EAX, imm32     | I     |
+        // | REX.W + A9 id  | TEST RAX, imm32     | I     |
+        // | F6 /0 ib       | TEST r/m8, imm8     | MI    |
+        // | F7 /0 id       | TEST r/m32, imm32   | MI    |
+        // | 84 /r          | TEST r/m8, r8       | MR    |
+        // | 85 /r          | TEST r/m32, r32     | MR    |
+        //
+        // test al, 1                     -> a8 01
+        // test rax, 0x10                 -> 48 a9 10000000
+        // test eax, ecx                  -> 85 c8
+        // test qword [rdi], 0x100        -> 48 f7 07 00010000
+        // test r9b, 7                    -> 41 f6 c1 07
+
+        assert_eq!(encode_instruction(TEST, &[AL.into(), Operand::Immediate8(1)]), [0xa8, 0x01]);
+        assert_eq!(
+            encode_instruction(TEST, &[RAX.into(), Operand::Immediate8(0x10)]),
+            [0x48, 0xa9, 0x10, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(encode_instruction(TEST, &[EAX.into(), ECX.into()]), [0x85, 0xc8]);
+        assert_eq!(
+            encode_instruction(TEST, &[mem(Qword, RDI).into(), Operand::Immediate16(0x100)]),
+            [0x48, 0xf7, 0x07, 0x00, 0x01, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(TEST, &[R9B.into(), Operand::Immediate8(7)]),
+            [0x41, 0xf6, 0xc1, 0x07]
+        );
+
+        // test eax, [rbx] -> invalid, TEST has no r, r/m form
+        assert_eq!(
+            encode(&Instruction::new(TEST, &[EAX.into(), mem(Dword, RBX).into()]), 0, &[])
+                .unwrap_err(),
+            EncodeError::InvalidOperands(TEST)
+        );
+    }
+
+    #[test]
+    fn test_encode_shift_rotate() {
+        // ROL/ROR/RCL/RCR/SHL/SHR/SAR
+        //
+        // `n` is the opcode extension (ROL=0, ROR=1, RCL=2, RCR=3, SHL=4, SHR=5, SAR=7):
+        //
+        // | Opcode   | Instruction      | Op/En |
+        // | ---      |  ---             |  ---  |
+        // | D0 /n    | SHL r/m8, 1      | M1    |
+        // | D1 /n    | SHL r/m32, 1     | M1    |
+        // | D2 /n    | SHL r/m8, CL     | MC    |
+        // | D3 /n    | SHL r/m32, CL    | MC    |
+        // | C0 /n ib | SHL r/m8, imm8   | MI    |
+        // | C1 /n ib | SHL r/m32, imm8  | MI    |
+        //
+        // shl eax, 1                     -> d1 e0
+        // shl rax, 1                     -> 48 d1 e0
+        // shr r9b, 1                     -> 41 d0 e9
+        // sar dword [rbx], cl            -> d3 3b
+        // ror cl, cl                     -> d2 c9
+        // rol rdx, 5                     -> 48 c1 c2 05
+        // rcr word [rax + 8], 3          -> 66 c1 58 08 03
+        // sal ecx, 4                     -> c1 e1 04
+        // shl eax, 0                     -> c1 e0 00
+
+        assert_eq!(
+            encode_instruction(SHL, &[EAX.into(), Operand::Immediate8(1)]),
+            [0xd1, 0xe0]
+        );
+        assert_eq!(
+            encode_instruction(SHL, &[RAX.into(), Operand::Immediate8(1)]),
+            [0x48, 0xd1, 0xe0]
+        );
+        assert_eq!(
+            encode_instruction(SHR, &[R9B.into(), Operand::Immediate8(1)]),
+            [0x41, 0xd0, 0xe9]
+        );
+        assert_eq!(
+            encode_instruction(SAR, &[mem(Dword, RBX).into(), CL.into()]),
+            [0xd3, 0x3b]
+        );
+        assert_eq!(
+            encode_instruction(ROR, &[CL.into(), CL.into()]),
+            [0xd2, 0xc9]
+        );
+        assert_eq!(
+            encode_instruction(ROL, &[RDX.into(), Operand::Immediate8(5)]),
+            [0x48, 0xc1, 0xc2, 0x05]
+        );
+        assert_eq!(
+            encode_instruction(
+                RCR,
+                &[
+                    mem(Word, RAX).with_displacement(8).into(),
+                    Operand::Immediate8(3)
+                ]
+            ),
+            [0x66, 0xc1, 0x58, 0x08, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(
+                Mnemonic::from_name("sal").unwrap(),
+                &[ECX.into(), Operand::Immediate8(4)]
+            ),
+            [0xc1, 0xe1, 0x04]
+        );
+        assert_eq!(
+            encode_instruction(SHL, &[EAX.into(), Operand::Immediate8(0)]),
+            [0xc1, 0xe0, 0x00]
+        );
+
+        // shl eax, dl -> invalid, the count register must be CL
+        assert_eq!(
+            encode(&Instruction::new(SHL, &[EAX.into(), DL.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(SHL)
+        );
+    }
+
+    #[test]
+    fn test_encode_mul_div() {
+        // MUL/IMUL/DIV/IDIV with one operand, the other operands are implicit:
+        //
+        // | Opcode | Instruction | Description                                 |
+        // | ---    |  ---        |  ---                                        |
+        // | F6 /4  | MUL r/m8    | AX := AL * r/m8                             |
+        // | F7 /4  | MUL r/m32   | EDX:EAX := EAX * r/m32                      |
+        // | F6 /6  | DIV r/m8    | AL := AX / r/m8, AH := remainder            |
+        // | F7 /6  | DIV r/m32   | EAX := EDX:EAX / r/m32, EDX := remainder    |
+        //
+        // mul rcx                        -> 48 f7 e1
+        // imul r8b                       -> 41 f6 e8
+        // div dword [rsi]                -> f7 36
+        // idiv r11                       -> 49 f7 fb
+
+        assert_eq!(encode_instruction(MUL, &[RCX.into()]), [0x48, 0xf7, 0xe1]);
+        assert_eq!(encode_instruction(IMUL, &[R8B.into()]), [0x41, 0xf6, 0xe8]);
+        assert_eq!(
+            encode_instruction(DIV, &[mem(Dword, RSI).into()]),
+            [0xf7, 0x36]
+        );
+        assert_eq!(encode_instruction(IDIV, &[R11.into()]), [0x49, 0xf7, 0xfb]);
+
+        // IMUL with two and three operands
+        //
+        // imul eax, ecx                  -> 0f af c1
+        // imul rax, qword [rbx], 10      -> 48 6b 03 0a
+        // imul rax, rcx, -2              -> 48 6b c1 fe
+        // imul ecx, edx, 1000            -> 69 ca e8030000
+        // imul r9w, ax, 300              -> 66 44 69 c8 2c01
+
+        assert_eq!(
+            encode_instruction(IMUL, &[EAX.into(), ECX.into()]),
+            [0x0f, 0xaf, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(
+                IMUL,
+                &[RAX.into(), mem(Qword, RBX).into(), Operand::Immediate8(10)]
+            ),
+            [0x48, 0x6b, 0x03, 0x0a]
+        );
+        assert_eq!(
+            encode_instruction(IMUL, &[RAX.into(), RCX.into(), Operand::Immediate8(0xfe)]),
+            [0x48, 0x6b, 0xc1, 0xfe]
+        );
+        assert_eq!(
+            encode_instruction(IMUL, &[ECX.into(), EDX.into(), Operand::Immediate16(1000)]),
+            [0x69, 0xca, 0xe8, 0x03, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(IMUL, &[R9W.into(), AX.into(), Operand::Immediate16(300)]),
+            [0x66, 0x44, 0x69, 0xc8, 0x2c, 0x01]
+        );
+
+        // NEG/NOT/INC/DEC
+        //
+        // neg rax                        -> 48 f7 d8
+        // not byte [rdi]                 -> f6 17
+        // inc r12d                       -> 41 ff c4
+        // dec word [rax]                 -> 66 ff 08
+
+        assert_eq!(encode_instruction(NEG, &[RAX.into()]), [0x48, 0xf7, 0xd8]);
+        assert_eq!(
+            encode_instruction(NOT, &[mem(Byte, RDI).into()]),
+            [0xf6, 0x17]
+        );
+        assert_eq!(encode_instruction(INC, &[R12D.into()]), [0x41, 0xff, 0xc4]);
+        assert_eq!(
+            encode_instruction(DEC, &[mem(Word, RAX).into()]),
+            [0x66, 0xff, 0x08]
+        );
+
+        // inc [rax] -> ambiguous
+        assert_eq!(
+            encode(&Instruction::new(INC, &[mem(Unsized, RAX).into()]), 0, &[]).unwrap_err(),
+            EncodeError::AmbiguousOperandSize { operand: 0 }
+        );
+    }
+
+    #[test]
+    fn test_encode_push_pop() {
+        // PUSH/POP default to 64-bit operand size, REX.W is not needed
+        //
+        // | Opcode | Instruction  | Op/En |
+        // | ---    |  ---         |  ---  |
+        // | 50+rd  | PUSH r64     | O     |
+        // | FF /6  | PUSH r/m64   | M     |
+        // | 6A ib  | PUSH imm8    | I     | (sign-extended to 64 bits)
+        // | 68 id  | PUSH imm32   | I     | (sign-extended to 64 bits)
+        // | 58+rd  | POP r64      | O     |
+        // | 8F /0  | POP r/m64    | M     |
+        //
+        // the 16-bit forms (r16, r/m16, imm8 and imm16) require the 66 prefix,
+        // the 16-bit immediate form is only selected by the `word` keyword.
+        //
+        // push rbp                -> 55
+        // push r12                -> 41 54
+        // push ax                 -> 66 50
+        // push qword [rbx + 8]    -> ff 73 08
+        // push 0x10               -> 6a 10
+        // push -1                 -> 6a ff
+        // push 0x12345678         -> 68 78563412
+        // pop r15                 -> 41 5f
+        // pop cx                  -> 66 59
+        // pop qword [rsp]         -> 8f 04 24
+        // push word [rax]         -> 66 ff 30
+        // pop word [rax]          -> 66 8f 00
+        // push word 1             -> 66 6a 01
+        // push word 0x1234        -> 66 68 34 12
+        // push 0x1234             -> 68 34120000
+
+        assert_eq!(encode_instruction(PUSH, &[RBP.into()]), [0x55]);
+        assert_eq!(encode_instruction(PUSH, &[R12.into()]), [0x41, 0x54]);
+        assert_eq!(encode_instruction(PUSH, &[AX.into()]), [0x66, 0x50]);
+        assert_eq!(
+            encode_instruction(PUSH, &[mem(Qword, RBX).with_displacement(8).into()]),
+            [0xff, 0x73, 0x08]
+        );
+        assert_eq!(encode_instruction(PUSH, &[Operand::Immediate8(0x10)]), [0x6a, 0x10]);
+        assert_eq!(encode_instruction(PUSH, &[Operand::Immediate32(0xffff_ffff)]), [0x6a, 0xff]);
+        assert_eq!(
+            encode_instruction(PUSH, &[Operand::Immediate32(0x12345678)]),
+            [0x68, 0x78, 0x56, 0x34, 0x12]
+        );
+        assert_eq!(encode_instruction(POP, &[R15.into()]), [0x41, 0x5f]);
+        assert_eq!(encode_instruction(POP, &[CX.into()]), [0x66, 0x59]);
+        assert_eq!(encode_instruction(POP, &[mem(Unsized, RSP).into()]), [0x8f, 0x04, 0x24]);
+        assert_eq!(encode_instruction(PUSH, &[mem(Word, RAX).into()]), [0x66, 0xff, 0x30]);
+        assert_eq!(encode_instruction(POP, &[mem(Word, RAX).into()]), [0x66, 0x8f, 0x00]);
+        assert_eq!(encode_instruction(PUSH, &[Operand::Immediate16(1)]), [0x66, 0x6a, 0x01]);
+        assert_eq!(
+            encode_instruction(PUSH, &[Operand::Immediate16(0x1234)]),
+            [0x66, 0x68, 0x34, 0x12]
+        );
+        assert_eq!(
+            encode_instruction(PUSH, &[Operand::Immediate32(0x1234)]),
+            [0x68, 0x34, 0x12, 0x00, 0x00]
+        );
+
+        // push eax -> invalid, 32-bit operand size is not encodable in 64-bit mode
+        assert_eq!(
+            encode(&Instruction::new(PUSH, &[EAX.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(PUSH)
+        );
+
+        // PUSHFQ/POPFQ/ENTER/LEAVE
+        //
+        // pushfq                  -> 9c
+        // popfq                   -> 9d
+        // enter 0x20, 0           -> c8 2000 00
+        // enter 0x1000, 1         -> c8 0010 01
+        // leave                   -> c9
+
+        assert_eq!(encode_instruction(PUSHFQ, &[]), [0x9c]);
+        assert_eq!(encode_instruction(POPFQ, &[]), [0x9d]);
+        assert_eq!(
+            encode_instruction(ENTER, &[Operand::Immediate8(0x20), Operand::Immediate8(0)]),
+            [0xc8, 0x20, 0x00, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(ENTER, &[Operand::Immediate16(0x1000), Operand::Immediate8(1)]),
+            [0xc8, 0x00, 0x10, 0x01]
+        );
+        assert_eq!(encode_instruction(LEAVE, &[]), [0xc9]);
+
+        // a function prologue and epilogue
+        //
+        // push rbp                -> 55
+        // mov rbp, rsp            -> 48 89 e5
+        // sub rsp, 0x20           -> 48 83 ec 20
+        // ...
+        // leave                   -> c9
+        // ret                     -> c3
+
+        assert_eq!(encode_instruction(MOV, &[RBP.into(), RSP.into()]), [0x48, 0x89, 0xe5]);
+        assert_eq!(
+            encode_instruction(SUB, &[RSP.into(), Operand::Immediate8(0x20)]),
+            [0x48, 0x83, 0xec, 0x20]
+        );
+    }
+
+    #[test]
+    fn test_encode_setcc_cmovcc() {
+        // SETcc -- Set Byte on Condition
+        //
+        // | Opcode   | Instruction  | Op/En | Description                          |
+        // | ---      |  ---         |  ---  |  ---                                 |
+        // | 0F 94    | SETE r/m8    | M     | Set byte if equal (ZF=1).            |
+        // | 0F 92    | SETB r/m8    | M     | Set byte if below (CF=1).            |
+        // | ...      |              |       |                                      |
+        //
+        // CMOVcc -- Conditional Move
+        //
+        // | Opcode   | Instruction          | Op/En | Description                   |
+        // | ---      |  ---                 |  ---  |  ---                          |
+        // | 0F 44 /r | CMOVE r32, r/m32     | RM    | Move if equal (ZF=1).         |
+        // | 0F 42 /r | CMOVB r32, r/m32     | RM    | Move if below (CF=1).         |
+        // | ...      |                      |       |                               |
+
+        for (cc, condition) in CONDITIONS.into_iter().enumerate() {
+            assert_eq!(
+                encode_instruction(SETcc(condition), &[AL.into()]),
+                [0x0f, 0x90 + cc as u8, 0xc0]
+            );
+            assert_eq!(
+                encode_instruction(CMOVcc(condition), &[EAX.into(), ECX.into()]),
+                [0x0f, 0x40 + cc as u8, 0xc1]
+            );
+        }
+
+        // setnae sil              -> 40 0f 92 c6
+        // setb r10b               -> 41 0f 92 c2
+        // setg byte [rbx]         -> 0f 9f 03
+        // cmovne eax, ecx         -> 0f 45 c1
+        // cmovl rax, qword [rdi+8] -> 48 0f 4c 47 08
+        // cmova r9w, dx           -> 66 44 0f 47 ca
+        // cmovo r8, r9            -> 4d 0f 40 c1
+
+        let setnae = Mnemonic::from_name("setnae").unwrap();
+        assert_eq!(setnae, SETcc(Condition::B));
+        assert_eq!(Mnemonic::from_name("setc"), Some(setnae));
+        assert_eq!(Mnemonic::from_name("SETB"), Some(setnae));
+        assert_eq!(encode_instruction(setnae, &[SIL.into()]), [0x40, 0x0f, 0x92, 0xc6]);
+        assert_eq!(encode_instruction(setnae, &[R10B.into()]), [0x41, 0x0f, 0x92, 0xc2]);
+        assert_eq!(
+            encode_instruction(SETcc(Condition::G), &[mem(Unsized, RBX).into()]),
+            [0x0f, 0x9f, 0x03]
+        );
+
+        let cmovne = Mnemonic::from_name("cmovnz").unwrap();
+        assert_eq!(cmovne.name(), "cmovne");
+        assert_eq!(encode_instruction(cmovne, &[EAX.into(), ECX.into()]), [0x0f, 0x45, 0xc1]);
+        assert_eq!(
+            encode_instruction(
+                CMOVcc(Condition::L),
+                &[RAX.into(), mem(Qword, RDI).with_displacement(8).into()]
+            ),
+            [0x48, 0x0f, 0x4c, 0x47, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(CMOVcc(Condition::A), &[R9W.into(), DX.into()]),
+            [0x66, 0x44, 0x0f, 0x47, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(CMOVcc(Condition::O), &[R8.into(), R9.into()]),
+            [0x4d, 0x0f, 0x40, 0xc1]
+        );
+
+        // cmove al, cl -> invalid, CMOVcc has no 8-bit form
+        assert_eq!(
+            encode(&Instruction::new(CMOVcc(Condition::E), &[AL.into(), CL.into()]), 0, &[])
+                .unwrap_err(),
+            EncodeError::InvalidOperands(CMOVcc(Condition::E))
+        );
+
+        // sete eax -> invalid, SETcc only sets a byte
+        assert_eq!(
+            encode(&Instruction::new(SETcc(Condition::E), &[EAX.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(SETcc(Condition::E))
+        );
+    }
+
+    #[test]
+    fn test_encode_flag() {
+        // clc  -> f8
+        // stc  -> f9
+        // cmc  -> f5
+        // cld  -> fc
+        // std  -> fd
+        // lahf -> 9f
+        // sahf -> 9e
+
+        assert_eq!(encode_instruction(CLC, &[]), [0xf8]);
+        assert_eq!(encode_instruction(STC, &[]), [0xf9]);
+        assert_eq!(encode_instruction(CMC, &[]), [0xf5]);
+        assert_eq!(encode_instruction(CLD, &[]), [0xfc]);
+        assert_eq!(encode_instruction(STD, &[]), [0xfd]);
+        assert_eq!(encode_instruction(LAHF, &[]), [0x9f]);
+        assert_eq!(encode_instruction(SAHF, &[]), [0x9e]);
+    }
+
+    #[test]
+    fn test_encode_string() {
+        let encode_prefix = |prefix: Prefix, mnemonic: Mnemonic| {
+            encode(&Instruction::new(mnemonic, &[]).with_prefix(prefix), 0, &[])
+        };
+
+        // movsb        -> a4
+        // movsw        -> 66 a5
+        // movsd        -> a5
+        // movsq        -> 48 a5
+        // cmpsb        -> a6
+        // cmpsq        -> 48 a7
+        // stosw        -> 66 ab
+        // lodsd        -> ad
+        // scasq        -> 48 af
+
+        assert_eq!(encode_instruction(MOVSB, &[]), [0xa4]);
+        assert_eq!(encode_instruction(MOVSW, &[]), [0x66, 0xa5]);
+        assert_eq!(encode_instruction(MOVSD, &[]), [0xa5]);
+        assert_eq!(encode_instruction(MOVSQ, &[]), [0x48, 0xa5]);
+        assert_eq!(encode_instruction(CMPSB, &[]), [0xa6]);
+        assert_eq!(encode_instruction(CMPSQ, &[]), [0x48, 0xa7]);
+        assert_eq!(encode_instruction(STOSW, &[]), [0x66, 0xab]);
+        assert_eq!(encode_instruction(LODSD, &[]), [0xad]);
+        assert_eq!(encode_instruction(SCASQ, &[]), [0x48, 0xaf]);
+
+        // rep movsb    -> f3 a4
+        // rep movsw    -> 66 f3 a5 (the same order as GAS)
+        // rep stosq    -> f3 48 ab
+        // rep lodsb    -> f3 ac
+        // repe cmpsb   -> f3 a6
+        // repne scasb  -> f2 ae
+        // repne cmpsd  -> f2 a7
+
+        assert_eq!(encode_prefix(Prefix::REP, MOVSB).unwrap().bytes, [0xf3, 0xa4]);
+        assert_eq!(encode_prefix(Prefix::REP, MOVSW).unwrap().bytes, [0x66, 0xf3, 0xa5]);
+        assert_eq!(encode_prefix(Prefix::REP, STOSQ).unwrap().bytes, [0xf3, 0x48, 0xab]);
+        assert_eq!(encode_prefix(Prefix::REP, LODSB).unwrap().bytes, [0xf3, 0xac]);
+        assert_eq!(encode_prefix(Prefix::REPE, CMPSB).unwrap().bytes, [0xf3, 0xa6]);
+        assert_eq!(encode_prefix(Prefix::REPNE, SCASB).unwrap().bytes, [0xf2, 0xae]);
+        assert_eq!(encode_prefix(Prefix::REPNE, CMPSD).unwrap().bytes, [0xf2, 0xa7]);
+
+        // repe movsb -> invalid, MOVS does not compare
+        assert_eq!(
+            encode_prefix(Prefix::REPE, MOVSB).unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::REPE,
+                mnemonic: MOVSB
+            }
+        );
+
+        // rep scasb -> invalid, REPE or REPNE is required
+        assert_eq!(
+            encode_prefix(Prefix::REP, SCASB).unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::REP,
+                mnemonic: SCASB
+            }
+        );
+
+        // lock movsb -> invalid
+        assert_eq!(
+            encode_prefix(Prefix::LOCK, MOVSB).unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::LOCK,
+                mnemonic: MOVSB
+            }
+        );
+
+        // rep add [rax], ecx -> invalid
+        assert_eq!(
+            encode(
+                &Instruction::new(ADD, &[mem(Dword, RAX).into(), ECX.into()])
+                    .with_prefix(Prefix::REP),
+                0,
+                &[]
+            )
+            .unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::REP,
+                mnemonic: ADD
+            }
+        );
+    }
+
+    #[test]
+    fn test_encode_lock() {
+        let encode_lock = |mnemonic: Mnemonic, operands: &[Operand]| {
+            encode(&Instruction::new(mnemonic, operands).with_prefix(Prefix::LOCK), 0, &[])
+        };
+
+        // lock add qword [rbx], 1        -> f0 48 83 03 01
+        // lock or dword [rax + 8], ecx   -> f0 09 48 08
+        // lock sub byte [rdi], al        -> f0 28 07
+        // lock dec word [rax]            -> 66 f0 ff 08
+        // lock neg qword [rsp]           -> f0 48 f7 1c 24
+        // lock add dword fs:[rax], 1     -> 64 f0 83 00 01
+
+        assert_eq!(
+            encode_lock(ADD, &[mem(Qword, RBX).into(), Operand::Immediate8(1)]).unwrap().bytes,
+            [0xf0, 0x48, 0x83, 0x03, 0x01]
+        );
+        assert_eq!(
+            encode_lock(OR, &[mem(Dword, RAX).with_displacement(8).into(), ECX.into()])
+                .unwrap()
+                .bytes,
+            [0xf0, 0x09, 0x48, 0x08]
+        );
+        assert_eq!(
+            encode_lock(SUB, &[mem(Unsized, RDI).into(), AL.into()]).unwrap().bytes,
+            [0xf0, 0x28, 0x07]
+        );
+        assert_eq!(
+            encode_lock(DEC, &[mem(Word, RAX).into()]).unwrap().bytes,
+            [0x66, 0xf0, 0xff, 0x08]
+        );
+        assert_eq!(
+            encode_lock(NEG, &[mem(Qword, RSP).into()]).unwrap().bytes,
+            [0xf0, 0x48, 0xf7, 0x1c, 0x24]
+        );
+        assert_eq!(
+            encode_lock(
+                ADD,
+                &[mem(Dword, RAX).with_segment(FS).into(), Operand::Immediate8(1)]
+            )
+            .unwrap()
+            .bytes,
+            [0x64, 0xf0, 0x83, 0x00, 0x01]
+        );
+
+        // lock add eax, 1 -> invalid, the destination is a register
+        assert_eq!(
+            encode_lock(ADD, &[EAX.into(), Operand::Immediate8(1)]).unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::LOCK,
+                mnemonic: ADD
+            }
+        );
+
+        // lock add ecx, [rax] -> invalid, the memory operand is the source
+        assert_eq!(
+            encode_lock(ADD, &[ECX.into(), mem(Dword, RAX).into()]).unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::LOCK,
+                mnemonic: ADD
+            }
+        );
+
+        // lock mov [rax], ecx -> invalid, MOV can not be locked
+        assert_eq!(
+            encode_lock(MOV, &[mem(Dword, RAX).into(), ECX.into()]).unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::LOCK,
+                mnemonic: MOV
+            }
+        );
+
+        // lock cmp [rax], ecx -> invalid, CMP does not write the destination
+        assert_eq!(
+            encode_lock(CMP, &[mem(Dword, RAX).into(), ECX.into()]).unwrap_err(),
+            EncodeError::InvalidPrefix {
+                prefix: Prefix::LOCK,
+                mnemonic: CMP
+            }
+        );
+    }
+
+    #[test]
+    fn test_encode_atomic() {
+        // XCHG
+        //
+        // xchg qword [rax], rcx          -> 48 87 08
+        // xchg rcx, qword [rax]          -> 48 87 08
+        // lock xchg qword [rax], rcx     -> f0 48 87 08
+        // xchg byte [rax], cl            -> 86 08
+        // xchg eax, ecx                  -> 91
+        // xchg ecx, eax                  -> 91
+        // xchg rax, r9                   -> 49 91
+        // xchg ax, bx                    -> 66 93
+        // xchg eax, eax                  -> 87 c0 (90 is NOP)
+
+        let lock = |mnemonic: Mnemonic, operands: &[Operand]| {
+            encode(&Instruction::new(mnemonic, operands).with_prefix(Prefix::LOCK), 0, &[])
+                .unwrap()
+                .bytes
+        };
+
+        assert_eq!(
+            encode_instruction(XCHG, &[mem(Qword, RAX).into(), RCX.into()]),
+            [0x48, 0x87, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(XCHG, &[RCX.into(), mem(Qword, RAX).into()]),
+            [0x48, 0x87, 0x08]
+        );
+        assert_eq!(lock(XCHG, &[mem(Qword, RAX).into(), RCX.into()]), [0xf0, 0x48, 0x87, 0x08]);
+        assert_eq!(encode_instruction(XCHG, &[mem(Unsized, RAX).into(), CL.into()]), [0x86, 0x08]);
+        assert_eq!(encode_instruction(XCHG, &[EAX.into(), ECX.into()]), [0x91]);
+        assert_eq!(encode_instruction(XCHG, &[ECX.into(), EAX.into()]), [0x91]);
+        assert_eq!(encode_instruction(XCHG, &[RAX.into(), R9.into()]), [0x49, 0x91]);
+        assert_eq!(encode_instruction(XCHG, &[AX.into(), BX.into()]), [0x66, 0x93]);
+        assert_eq!(encode_instruction(XCHG, &[EAX.into(), EAX.into()]), [0x87, 0xc0]);
+
+        // CMPXCHG/CMPXCHG16B/XADD
+        //
+        // lock cmpxchg qword [rdi], rsi  -> f0 48 0f b1 37
+        // lock cmpxchg byte [rbx], cl    -> f0 0f b0 0b
+        // lock cmpxchg16b [r8]           -> f0 49 0f c7 08
+        // lock xadd qword [rbx], rax     -> f0 48 0f c1 03
+        // xadd dword [rax], ecx          -> 0f c1 08
+
+        assert_eq!(
+            lock(CMPXCHG, &[mem(Qword, RDI).into(), RSI.into()]),
+            [0xf0, 0x48, 0x0f, 0xb1, 0x37]
+        );
+        assert_eq!(lock(CMPXCHG, &[mem(Byte, RBX).into(), CL.into()]), [0xf0, 0x0f, 0xb0, 0x0b]);
+        assert_eq!(lock(CMPXCHG16B, &[mem(Unsized, R8).into()]), [0xf0, 0x49, 0x0f, 0xc7, 0x08]);
+        assert_eq!(
+            encode_instruction(CMPXCHG16B, &[mem(XMMWord, RDI).into()]),
+            [0x48, 0x0f, 0xc7, 0x0f]
+        );
+        assert_eq!(
+            lock(XADD, &[mem(Qword, RBX).into(), RAX.into()]),
+            [0xf0, 0x48, 0x0f, 0xc1, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(XADD, &[mem(Dword, RAX).into(), ECX.into()]),
+            [0x0f, 0xc1, 0x08]
+        );
+
+        // BTS/BTR/BTC
+        //
+        // lock bts dword [rax], 3        -> f0 0f ba 28 03
+        // lock btr qword [rax], rcx      -> f0 48 0f b3 08
+        // lock btc word [rax], cx        -> 66 f0 0f bb 08
+        // btc word [rax], 15             -> 66 0f ba 38 0f
+
+        assert_eq!(
+            lock(BTS, &[mem(Dword, RAX).into(), Operand::Immediate8(3)]),
+            [0xf0, 0x0f, 0xba, 0x28, 0x03]
+        );
+        assert_eq!(
+            lock(BTR, &[mem(Qword, RAX).into(), RCX.into()]),
+            [0xf0, 0x48, 0x0f, 0xb3, 0x08]
+        );
+        assert_eq!(lock(BTC, &[mem(Word, RAX).into(), CX.into()]), [0x66, 0xf0, 0x0f, 0xbb, 0x08]);
+        assert_eq!(
+            encode_instruction(BTC, &[mem(Word, RAX).into(), Operand::Immediate8(15)]),
+            [0x66, 0x0f, 0xba, 0x38, 0x0f]
+        );
+
+        // MFENCE/LFENCE/SFENCE/PAUSE
+        //
+        // mfence -> 0f ae f0
+        // lfence -> 0f ae e8
+        // sfence -> 0f ae f8
+        // pause  -> f3 90
+
+        assert_eq!(encode_instruction(MFENCE, &[]), [0x0f, 0xae, 0xf0]);
+        assert_eq!(encode_instruction(LFENCE, &[]), [0x0f, 0xae, 0xe8]);
+        assert_eq!(encode_instruction(SFENCE, &[]), [0x0f, 0xae, 0xf8]);
+        assert_eq!(encode_instruction(PAUSE, &[]), [0xf3, 0x90]);
+    }
+
+    #[test]
+    fn test_encode_bit() {
+        // BT
+        //
+        // bt eax, ecx             -> 0f a3 c8
+        // bt word [rax], 3        -> 66 0f ba 20 03
+        // bt r9, 63               -> 49 0f ba e1 3f
+        // bt qword [rsi], rdx     -> 48 0f a3 16
+
+        assert_eq!(encode_instruction(BT, &[EAX.into(), ECX.into()]), [0x0f, 0xa3, 0xc8]);
+        assert_eq!(
+            encode_instruction(BT, &[mem(Word, RAX).into(), Operand::Immediate8(3)]),
+            [0x66, 0x0f, 0xba, 0x20, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(BT, &[R9.into(), Operand::Immediate8(63)]),
+            [0x49, 0x0f, 0xba, 0xe1, 0x3f]
+        );
+        assert_eq!(
+            encode_instruction(BT, &[mem(Qword, RSI).into(), RDX.into()]),
+            [0x48, 0x0f, 0xa3, 0x16]
+        );
+
+        // BSF/BSR/POPCNT/LZCNT/TZCNT
+        //
+        // bsf eax, ecx              -> 0f bc c1
+        // bsf ax, bx                -> 66 0f bc c3
+        // bsr r8, qword [rax]       -> 4c 0f bd 00
+        // popcnt eax, ecx           -> f3 0f b8 c1
+        // popcnt ax, bx             -> 66 f3 0f b8 c3
+        // popcnt r10, qword [r11]   -> f3 4d 0f b8 13
+        // lzcnt rax, rcx            -> f3 48 0f bd c1
+        // tzcnt ecx, dword [rbx]    -> f3 0f bc 0b
+
+        assert_eq!(encode_instruction(BSF, &[EAX.into(), ECX.into()]), [0x0f, 0xbc, 0xc1]);
+        assert_eq!(encode_instruction(BSF, &[AX.into(), BX.into()]), [0x66, 0x0f, 0xbc, 0xc3]);
+        assert_eq!(
+            encode_instruction(BSR, &[R8.into(), mem(Qword, RAX).into()]),
+            [0x4c, 0x0f, 0xbd, 0x00]
+        );
+        assert_eq!(encode_instruction(POPCNT, &[EAX.into(), ECX.into()]), [0xf3, 0x0f, 0xb8, 0xc1]);
+        assert_eq!(
+            encode_instruction(POPCNT, &[AX.into(), BX.into()]),
+            [0x66, 0xf3, 0x0f, 0xb8, 0xc3]
+        );
+        assert_eq!(
+            encode_instruction(POPCNT, &[R10.into(), mem(Unsized, R11).into()]),
+            [0xf3, 0x4d, 0x0f, 0xb8, 0x13]
+        );
+        assert_eq!(
+            encode_instruction(LZCNT, &[RAX.into(), RCX.into()]),
+            [0xf3, 0x48, 0x0f, 0xbd, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(TZCNT, &[ECX.into(), mem(Dword, RBX).into()]),
+            [0xf3, 0x0f, 0xbc, 0x0b]
+        );
+
+        // BSWAP/MOVBE
+        //
+        // bswap eax                 -> 0f c8
+        // bswap rcx                 -> 48 0f c9
+        // bswap r12                 -> 49 0f cc
+        // movbe eax, dword [rcx]    -> 0f 38 f0 01
+        // movbe qword [r8], r9      -> 4d 0f 38 f1 08
+        // movbe word [rax], cx      -> 66 0f 38 f1 08
+        // movbe eax, ecx            -> invalid, one of the operands must be memory
+
+        assert_eq!(encode_instruction(BSWAP, &[EAX.into()]), [0x0f, 0xc8]);
+        assert_eq!(encode_instruction(BSWAP, &[RCX.into()]), [0x48, 0x0f, 0xc9]);
+        assert_eq!(encode_instruction(BSWAP, &[R12.into()]), [0x49, 0x0f, 0xcc]);
+        assert_eq!(
+            encode_instruction(MOVBE, &[EAX.into(), mem(Unsized, RCX).into()]),
+            [0x0f, 0x38, 0xf0, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(MOVBE, &[mem(Qword, R8).into(), R9.into()]),
+            [0x4d, 0x0f, 0x38, 0xf1, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(MOVBE, &[mem(Word, RAX).into(), CX.into()]),
+            [0x66, 0x0f, 0x38, 0xf1, 0x08]
+        );
+        assert_eq!(
+            encode(&Instruction::new(MOVBE, &[EAX.into(), ECX.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(MOVBE)
+        );
+    }
+
+    #[test]
+    fn test_encode_bmi() {
+        // andn eax, ebx, ecx              -> c4 e2 60 f2 c1
+        // andn rax, rbx, qword [r9]       -> c4 c2 e0 f2 01
+        // andn r8d, r15d, r10d            -> c4 42 00 f2 c2
+        // bextr eax, ecx, edx             -> c4 e2 68 f7 c1
+        // blsi eax, ecx                   -> c4 e2 78 f3 d9
+        // blsmsk rax, rcx                 -> c4 e2 f8 f3 d1
+        // blsr r12, qword [rax]           -> c4 e2 98 f3 08
+        // bzhi eax, ecx, edx              -> c4 e2 68 f5 c1
+
+        assert_eq!(
+            encode_instruction(ANDN, &[EAX.into(), EBX.into(), ECX.into()]),
+            [0xc4, 0xe2, 0x60, 0xf2, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(ANDN, &[RAX.into(), RBX.into(), mem(Qword, R9).into()]),
+            [0xc4, 0xc2, 0xe0, 0xf2, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(ANDN, &[R8D.into(), R15D.into(), R10D.into()]),
+            [0xc4, 0x42, 0x00, 0xf2, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(BEXTR, &[EAX.into(), ECX.into(), EDX.into()]),
+            [0xc4, 0xe2, 0x68, 0xf7, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(BLSI, &[EAX.into(), ECX.into()]),
+            [0xc4, 0xe2, 0x78, 0xf3, 0xd9]
+        );
+        assert_eq!(
+            encode_instruction(BLSMSK, &[RAX.into(), RCX.into()]),
+            [0xc4, 0xe2, 0xf8, 0xf3, 0xd1]
+        );
+        assert_eq!(
+            encode_instruction(BLSR, &[R12.into(), mem(Qword, RAX).into()]),
+            [0xc4, 0xe2, 0x98, 0xf3, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(BZHI, &[EAX.into(), ECX.into(), EDX.into()]),
+            [0xc4, 0xe2, 0x68, 0xf5, 0xc1]
+        );
+
+        // mulx rax, rbx, rcx              -> c4 e2 e3 f6 c1
+        // pdep eax, ebx, ecx              -> c4 e2 63 f5 c1
+        // pext rax, rbx, rcx              -> c4 e2 e2 f5 c1
+        // rorx eax, ecx, 3                -> c4 e3 7b f0 c1 03
+        // rorx r9, qword [rsi], 63        -> c4 63 fb f0 0e 3f
+        // sarx eax, ecx, edx              -> c4 e2 6a f7 c1
+        // shlx rax, rcx, r11              -> c4 e2 a1 f7 c1
+        // shrx eax, dword [rcx], edx      -> c4 e2 6b f7 01
+
+        assert_eq!(
+            encode_instruction(MULX, &[RAX.into(), RBX.into(), RCX.into()]),
+            [0xc4, 0xe2, 0xe3, 0xf6, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(PDEP, &[EAX.into(), EBX.into(), ECX.into()]),
+            [0xc4, 0xe2, 0x63, 0xf5, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(PEXT, &[RAX.into(), RBX.into(), RCX.into()]),
+            [0xc4, 0xe2, 0xe2, 0xf5, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(RORX, &[EAX.into(), ECX.into(), Operand::Immediate8(3)]),
+            [0xc4, 0xe3, 0x7b, 0xf0, 0xc1, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(RORX, &[R9.into(), mem(Qword, RSI).into(), Operand::Immediate8(63)]),
+            [0xc4, 0x63, 0xfb, 0xf0, 0x0e, 0x3f]
+        );
+        assert_eq!(
+            encode_instruction(SARX, &[EAX.into(), ECX.into(), EDX.into()]),
+            [0xc4, 0xe2, 0x6a, 0xf7, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(SHLX, &[RAX.into(), RCX.into(), R11.into()]),
+            [0xc4, 0xe2, 0xa1, 0xf7, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(SHRX, &[EAX.into(), mem(Dword, RCX).into(), EDX.into()]),
+            [0xc4, 0xe2, 0x6b, 0xf7, 0x01]
+        );
+    }
+
+    #[test]
+    fn test_encode_system() {
+        // nop       -> 90
+        // syscall   -> 0f 05
+        // cpuid     -> 0f a2
+        // rdtsc     -> 0f 31
+        // rdtscp    -> 0f 01 f9
+        // rdpid rax -> f3 0f c7 f8
+        // rdpid r9  -> f3 41 0f c7 f9
+        // xgetbv    -> 0f 01 d0
+        // int3      -> cc
+        // int 0x80  -> cd 80
+        // int 3     -> cd 03 (not INT3)
+        // ud2       -> 0f 0b
+        // hlt       -> f4
+
+        assert_eq!(encode_instruction(NOP, &[]), [0x90]);
+        assert_eq!(encode_instruction(SYSCALL, &[]), [0x0f, 0x05]);
+        assert_eq!(encode_instruction(CPUID, &[]), [0x0f, 0xa2]);
+        assert_eq!(encode_instruction(RDTSC, &[]), [0x0f, 0x31]);
+        assert_eq!(encode_instruction(RDTSCP, &[]), [0x0f, 0x01, 0xf9]);
+        assert_eq!(encode_instruction(RDPID, &[RAX.into()]), [0xf3, 0x0f, 0xc7, 0xf8]);
+        assert_eq!(encode_instruction(RDPID, &[R9.into()]), [0xf3, 0x41, 0x0f, 0xc7, 0xf9]);
+        assert_eq!(encode_instruction(XGETBV, &[]), [0x0f, 0x01, 0xd0]);
+        assert_eq!(encode_instruction(INT3, &[]), [0xcc]);
+        assert_eq!(encode_instruction(INT, &[Operand::Immediate8(0x80)]), [0xcd, 0x80]);
+        assert_eq!(encode_instruction(INT, &[Operand::Immediate8(3)]), [0xcd, 0x03]);
+        assert_eq!(encode_instruction(UD2, &[]), [0x0f, 0x0b]);
+        assert_eq!(encode_instruction(HLT, &[]), [0xf4]);
+
+        // rdpid eax -> invalid, the operand is always 64-bit in 64-bit mode
+        assert_eq!(
+            encode(&Instruction::new(RDPID, &[EAX.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(RDPID)
+        );
+    }
+
+    #[test]
+    fn test_encode_debug_register() {
+        // mov rax, dr7  -> 0f 21 f8
+        // mov r15, dr3  -> 41 0f 21 df
+        // mov dr0, r9   -> 41 0f 23 c1
+        // mov dr6, rsp  -> 0f 23 f4
+
+        assert_eq!(encode_instruction(MOV, &[RAX.into(), DR7.into()]), [0x0f, 0x21, 0xf8]);
+        assert_eq!(encode_instruction(MOV, &[R15.into(), DR3.into()]), [0x41, 0x0f, 0x21, 0xdf]);
+        assert_eq!(encode_instruction(MOV, &[DR0.into(), R9.into()]), [0x41, 0x0f, 0x23, 0xc1]);
+        assert_eq!(encode_instruction(MOV, &[DR6.into(), RSP.into()]), [0x0f, 0x23, 0xf4]);
+
+        fn encode_error(mnemonic: Mnemonic, operands: &[Operand]) -> EncodeError {
+            encode(&Instruction::new(mnemonic, operands), 0, &[]).unwrap_err()
+        }
+
+        // mov eax, dr0   -> invalid, the general-purpose register is always 64-bit
+        // mov [rax], dr0 -> invalid, there is no memory form
+        // mov dr0, dr1   -> invalid
+        // mov dr0, 1     -> invalid
+        // add rax, dr1   -> invalid, only MOV accepts debug registers
+        // push dr7       -> invalid
+        assert_eq!(
+            encode_error(MOV, &[EAX.into(), DR0.into()]),
+            EncodeError::InvalidOperands(MOV)
+        );
+        assert_eq!(
+            encode_error(MOV, &[mem(Qword, RAX).into(), DR0.into()]),
+            EncodeError::InvalidOperands(MOV)
+        );
+        assert_eq!(
+            encode_error(MOV, &[DR0.into(), DR1.into()]),
+            EncodeError::InvalidOperands(MOV)
+        );
+        assert_eq!(
+            encode_error(MOV, &[DR0.into(), Operand::Immediate8(1)]),
+            EncodeError::InvalidOperands(MOV)
+        );
+        assert_eq!(
+            encode_error(ADD, &[RAX.into(), DR1.into()]),
+            EncodeError::InvalidOperands(ADD)
+        );
+        assert_eq!(encode_error(PUSH, &[DR7.into()]), EncodeError::InvalidOperands(PUSH));
+
+        // mov rax, [dr0] -> invalid base
+        assert_eq!(
+            encode_error(MOV, &[RAX.into(), mem(Qword, DR0).into()]),
+            EncodeError::InvalidMemoryOperand {
+                operand: 1,
+                error: MemoryOperandError::InvalidBase(DR0)
+            }
+        );
+    }
+
+    #[test]
+    fn test_encode_sse() {
+        // MOVSS/MOVSD/MOVAPS/MOVUPS/MOVDQA/MOVDQU
+        //
+        // movss xmm0, xmm1            -> f3 0f 10 c1
+        // movss xmm8, dword [rax]     -> f3 44 0f 10 00
+        // movss dword [rax], xmm9     -> f3 44 0f 11 08
+        // movsd xmm0, qword [rsp + 8] -> f2 0f 10 44 24 08
+        // movaps xmm0, xmm15          -> 41 0f 28 c7
+        // movups xmmword [rdi], xmm2  -> 0f 11 17
+        // movdqu [rax], xmm3          -> f3 0f 7f 18
+
+        assert_eq!(
+            encode_instruction(MOVSS, &[XMM0.into(), XMM1.into()]),
+            [0xf3, 0x0f, 0x10, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(MOVSS, &[XMM8.into(), mem(Dword, RAX).into()]),
+            [0xf3, 0x44, 0x0f, 0x10, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(MOVSS, &[mem(Dword, RAX).into(), XMM9.into()]),
+            [0xf3, 0x44, 0x0f, 0x11, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(MOVSD, &[XMM0.into(), mem(Qword, RSP).with_displacement(8).into()]),
+            [0xf2, 0x0f, 0x10, 0x44, 0x24, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(MOVAPS, &[XMM0.into(), XMM15.into()]),
+            [0x41, 0x0f, 0x28, 0xc7]
+        );
+        assert_eq!(
+            encode_instruction(MOVUPS, &[mem(XMMWord, RDI).into(), XMM2.into()]),
+            [0x0f, 0x11, 0x17]
+        );
+        assert_eq!(
+            encode_instruction(MOVDQU, &[mem(Unsized, RAX).into(), XMM3.into()]),
+            [0xf3, 0x0f, 0x7f, 0x18]
+        );
+
+        // arithmetic and comparison
+        //
+        // addps xmm0, xmm1         -> 0f 58 c1
+        // addpd xmm0, xmm1         -> 66 0f 58 c1
+        // addss xmm0, xmm1         -> f3 0f 58 c1
+        // addsd xmm10, qword [r11] -> f2 45 0f 58 13
+        // sqrtsd xmm0, xmm1        -> f2 0f 51 c1
+        // maxps xmm2, xmm3         -> 0f 5f d3
+        // ucomiss xmm0, xmm1       -> 0f 2e c1
+        // comisd xmm0, qword [rax] -> 66 0f 2f 00
+
+        assert_eq!(encode_instruction(ADDPS, &[XMM0.into(), XMM1.into()]), [0x0f, 0x58, 0xc1]);
+        assert_eq!(
+            encode_instruction(ADDPD, &[XMM0.into(), XMM1.into()]),
+            [0x66, 0x0f, 0x58, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(ADDSS, &[XMM0.into(), XMM1.into()]),
+            [0xf3, 0x0f, 0x58, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(ADDSD, &[XMM10.into(), mem(Qword, R11).into()]),
+            [0xf2, 0x45, 0x0f, 0x58, 0x13]
+        );
+        assert_eq!(
+            encode_instruction(SQRTSD, &[XMM0.into(), XMM1.into()]),
+            [0xf2, 0x0f, 0x51, 0xc1]
+        );
+        assert_eq!(encode_instruction(MAXPS, &[XMM2.into(), XMM3.into()]), [0x0f, 0x5f, 0xd3]);
+        assert_eq!(encode_instruction(UCOMISS, &[XMM0.into(), XMM1.into()]), [0x0f, 0x2e, 0xc1]);
+        assert_eq!(
+            encode_instruction(COMISD, &[XMM0.into(), mem(Qword, RAX).into()]),
+            [0x66, 0x0f, 0x2f, 0x00]
+        );
+
+        // conversion
+        //
+        // cvtsi2sd xmm0, rax         -> f2 48 0f 2a c0
+        // cvtsi2ss xmm1, dword [rax] -> f3 0f 2a 08
+        // cvttsd2si eax, xmm0        -> f2 0f 2c c0
+        // cvtsd2si r8, qword [rax]   -> f2 4c 0f 2d 00
+        // cvtss2sd xmm0, xmm1        -> f3 0f 5a c1
+
+        assert_eq!(
+            encode_instruction(CVTSI2SD, &[XMM0.into(), RAX.into()]),
+            [0xf2, 0x48, 0x0f, 0x2a, 0xc0]
+        );
+        assert_eq!(
+            encode_instruction(CVTSI2SS, &[XMM1.into(), mem(Dword, RAX).into()]),
+            [0xf3, 0x0f, 0x2a, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(CVTTSD2SI, &[EAX.into(), XMM0.into()]),
+            [0xf2, 0x0f, 0x2c, 0xc0]
+        );
+        assert_eq!(
+            encode_instruction(CVTSD2SI, &[R8.into(), mem(Qword, RAX).into()]),
+            [0xf2, 0x4c, 0x0f, 0x2d, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(CVTSS2SD, &[XMM0.into(), XMM1.into()]),
+            [0xf3, 0x0f, 0x5a, 0xc1]
+        );
+
+        // packed integer and SSE3
+        //
+        // paddq xmm0, xmm1          -> 66 0f d4 c1
+        // pxor xmm9, xmm9           -> 66 45 0f ef c9
+        // pcmpeqb xmm0, [rax]       -> 66 0f 74 00
+        // pshufd xmm0, xmm1, 0x1b   -> 66 0f 70 c1 1b
+        // punpcklbw xmm0, xmm1      -> 66 0f 60 c1
+        // punpckhqdq xmm2, xmm3     -> 66 0f 6d d3
+        // haddps xmm0, xmm1         -> f2 0f 7c c1
+        // addsubpd xmm0, xmm1       -> 66 0f d0 c1
+        // movddup xmm0, qword [rax] -> f2 0f 12 00
+        // lddqu xmm0, [rax]         -> f2 0f f0 00
+
+        assert_eq!(
+            encode_instruction(PADDQ, &[XMM0.into(), XMM1.into()]),
+            [0x66, 0x0f, 0xd4, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(PXOR, &[XMM9.into(), XMM9.into()]),
+            [0x66, 0x45, 0x0f, 0xef, 0xc9]
+        );
+        assert_eq!(
+            encode_instruction(PCMPEQB, &[XMM0.into(), mem(Unsized, RAX).into()]),
+            [0x66, 0x0f, 0x74, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(PSHUFD, &[XMM0.into(), XMM1.into(), Operand::Immediate8(0x1b)]),
+            [0x66, 0x0f, 0x70, 0xc1, 0x1b]
+        );
+        assert_eq!(
+            encode_instruction(PUNPCKLBW, &[XMM0.into(), XMM1.into()]),
+            [0x66, 0x0f, 0x60, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(PUNPCKHQDQ, &[XMM2.into(), XMM3.into()]),
+            [0x66, 0x0f, 0x6d, 0xd3]
+        );
+        assert_eq!(
+            encode_instruction(HADDPS, &[XMM0.into(), XMM1.into()]),
+            [0xf2, 0x0f, 0x7c, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(ADDSUBPD, &[XMM0.into(), XMM1.into()]),
+            [0x66, 0x0f, 0xd0, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(MOVDDUP, &[XMM0.into(), mem(Qword, RAX).into()]),
+            [0xf2, 0x0f, 0x12, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(LDDQU, &[XMM0.into(), mem(Unsized, RAX).into()]),
+            [0xf2, 0x0f, 0xf0, 0x00]
+        );
+
+        // cvtsi2sd xmm0, [rax] -> ambiguous, the source can be m32 or m64
+        assert_eq!(
+            encode(&Instruction::new(CVTSI2SD, &[XMM0.into(), mem(Unsized, RAX).into()]), 0, &[])
+                .unwrap_err(),
+            EncodeError::AmbiguousOperandSize { operand: 1 }
+        );
+
+        // addps xmm0, ymm1 -> invalid, SSE only accepts XMM registers
+        assert_eq!(
+            encode(&Instruction::new(ADDPS, &[XMM0.into(), YMM1.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(ADDPS)
+        );
+    }
+
+    #[test]
+    fn test_encode_sse4() {
+        // SSSE3/SSE4.1, the 0F 38 and 0F 3A opcode maps
+        //
+        // pshufb xmm1, xmm2            -> 66 0f 38 00 ca
+        // pshufb xmm9, xmmword [rax]   -> 66 44 0f 38 00 08
+        // palignr xmm1, xmm10, 5       -> 66 41 0f 3a 0f ca 05
+        // pblendvb xmm1, xmm2, xmm0    -> 66 0f 38 10 ca
+        // pminub xmm1, xmm2            -> 66 0f da ca
+        // pmaxud xmm8, xmm2            -> 66 44 0f 38 3f c2
+        // roundsd xmm1, qword [rax], 4 -> 66 0f 3a 0b 08 04
+        // ptest xmm1, xmm2             -> 66 0f 38 17 ca
+
+        assert_eq!(
+            encode_instruction(PSHUFB, &[XMM1.into(), XMM2.into()]),
+            [0x66, 0x0f, 0x38, 0x00, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(PSHUFB, &[XMM9.into(), mem(XMMWord, RAX).into()]),
+            [0x66, 0x44, 0x0f, 0x38, 0x00, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(PALIGNR, &[XMM1.into(), XMM10.into(), Operand::Immediate8(5)]),
+            [0x66, 0x41, 0x0f, 0x3a, 0x0f, 0xca, 0x05]
+        );
+        assert_eq!(
+            encode_instruction(PBLENDVB, &[XMM1.into(), XMM2.into(), XMM0.into()]),
+            [0x66, 0x0f, 0x38, 0x10, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(PMINUB, &[XMM1.into(), XMM2.into()]),
+            [0x66, 0x0f, 0xda, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(PMAXUD, &[XMM8.into(), XMM2.into()]),
+            [0x66, 0x44, 0x0f, 0x38, 0x3f, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(
+                ROUNDSD,
+                &[XMM1.into(), mem(Qword, RAX).into(), Operand::Immediate8(4)]
+            ),
+            [0x66, 0x0f, 0x3a, 0x0b, 0x08, 0x04]
+        );
+        assert_eq!(
+            encode_instruction(PTEST, &[XMM1.into(), XMM2.into()]),
+            [0x66, 0x0f, 0x38, 0x17, 0xca]
+        );
+
+        // PEXTR*/PINSR*, the 66 of the 16-bit memory forms is not repeated
+        //
+        // pextrb eax, xmm1, 1        -> 66 0f 3a 14 c8 01
+        // pextrb byte [rax], xmm1, 1 -> 66 0f 3a 14 08 01
+        // pextrw eax, xmm1, 1        -> 66 0f c5 c1 01
+        // pextrw word [rax], xmm1, 1 -> 66 0f 3a 15 08 01
+        // pextrq rax, xmm1, 1        -> 66 48 0f 3a 16 c8 01
+        // pinsrw xmm1, word [rax], 1 -> 66 0f c4 08 01
+        // pinsrd xmm1, eax, 1        -> 66 0f 3a 22 c8 01
+        // pinsrq xmm9, qword [r8], 1 -> 66 4d 0f 3a 22 08 01
+
+        assert_eq!(
+            encode_instruction(PEXTRB, &[EAX.into(), XMM1.into(), Operand::Immediate8(1)]),
+            [0x66, 0x0f, 0x3a, 0x14, 0xc8, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(
+                PEXTRB,
+                &[mem(Byte, RAX).into(), XMM1.into(), Operand::Immediate8(1)]
+            ),
+            [0x66, 0x0f, 0x3a, 0x14, 0x08, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(PEXTRW, &[EAX.into(), XMM1.into(), Operand::Immediate8(1)]),
+            [0x66, 0x0f, 0xc5, 0xc1, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(
+                PEXTRW,
+                &[mem(Word, RAX).into(), XMM1.into(), Operand::Immediate8(1)]
+            ),
+            [0x66, 0x0f, 0x3a, 0x15, 0x08, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(PEXTRQ, &[RAX.into(), XMM1.into(), Operand::Immediate8(1)]),
+            [0x66, 0x48, 0x0f, 0x3a, 0x16, 0xc8, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(
+                PINSRW,
+                &[XMM1.into(), mem(Word, RAX).into(), Operand::Immediate8(1)]
+            ),
+            [0x66, 0x0f, 0xc4, 0x08, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(PINSRD, &[XMM1.into(), EAX.into(), Operand::Immediate8(1)]),
+            [0x66, 0x0f, 0x3a, 0x22, 0xc8, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(
+                PINSRQ,
+                &[XMM9.into(), mem(Qword, R8).into(), Operand::Immediate8(1)]
+            ),
+            [0x66, 0x4d, 0x0f, 0x3a, 0x22, 0x08, 0x01]
+        );
+
+        // SSE4.2
+        //
+        // pcmpestri xmm1, xmm2, 0x18          -> 66 0f 3a 61 ca 18
+        // pcmpistrm xmm1, xmmword [rax], 0x18 -> 66 0f 3a 62 08 18
+        // crc32 eax, cl                       -> f2 0f 38 f0 c1
+        // crc32 eax, sil                      -> f2 40 0f 38 f0 c6
+        // crc32 eax, word [rax]               -> 66 f2 0f 38 f1 00
+        // crc32 r9d, dword [r8]               -> f2 45 0f 38 f1 08
+        // crc32 rax, cl                       -> f2 48 0f 38 f0 c1
+        // crc32 rax, rcx                      -> f2 48 0f 38 f1 c1
+
+        assert_eq!(
+            encode_instruction(PCMPESTRI, &[XMM1.into(), XMM2.into(), Operand::Immediate8(0x18)]),
+            [0x66, 0x0f, 0x3a, 0x61, 0xca, 0x18]
+        );
+        assert_eq!(
+            encode_instruction(
+                PCMPISTRM,
+                &[XMM1.into(), mem(XMMWord, RAX).into(), Operand::Immediate8(0x18)]
+            ),
+            [0x66, 0x0f, 0x3a, 0x62, 0x08, 0x18]
+        );
+        assert_eq!(
+            encode_instruction(CRC32, &[EAX.into(), CL.into()]),
+            [0xf2, 0x0f, 0x38, 0xf0, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(CRC32, &[EAX.into(), SIL.into()]),
+            [0xf2, 0x40, 0x0f, 0x38, 0xf0, 0xc6]
+        );
+        assert_eq!(
+            encode_instruction(CRC32, &[EAX.into(), mem(Word, RAX).into()]),
+            [0x66, 0xf2, 0x0f, 0x38, 0xf1, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(CRC32, &[R9D.into(), mem(Dword, R8).into()]),
+            [0xf2, 0x45, 0x0f, 0x38, 0xf1, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(CRC32, &[RAX.into(), CL.into()]),
+            [0xf2, 0x48, 0x0f, 0x38, 0xf0, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(CRC32, &[RAX.into(), RCX.into()]),
+            [0xf2, 0x48, 0x0f, 0x38, 0xf1, 0xc1]
+        );
+
+        // pblendvb xmm1, xmm2, xmm3 -> invalid, the mask is always XMM0
+        assert_eq!(
+            encode(&Instruction::new(PBLENDVB, &[XMM1.into(), XMM2.into(), XMM3.into()]), 0, &[])
+                .unwrap_err(),
+            EncodeError::InvalidOperands(PBLENDVB)
+        );
+
+        // crc32 ax, cx -> invalid, the destination is 32-bit or 64-bit
+        assert_eq!(
+            encode(&Instruction::new(CRC32, &[AX.into(), CX.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(CRC32)
+        );
+    }
+
+    #[test]
+    fn test_encode_crypto() {
+        // AES-NI and PCLMULQDQ
+        //
+        // aesenc xmm1, xmm2                 -> 66 0f 38 dc ca
+        // aesenc xmm9, xmmword [rax]        -> 66 44 0f 38 dc 08
+        // aesdeclast xmm1, xmm10            -> 66 41 0f 38 df ca
+        // aesimc xmm1, xmm2                 -> 66 0f 38 db ca
+        // aeskeygenassist xmm1, xmm2, 1     -> 66 0f 3a df ca 01
+        // pclmulqdq xmm1, xmm2, 0x11        -> 66 0f 3a 44 ca 11
+        // vpclmulqdq xmm1, xmm2, xmm3, 0x10 -> c4 e3 69 44 cb 10
+        // vpclmulqdq ymm9, ymm2, ymm3, 1    -> c4 63 6d 44 cb 01
+
+        assert_eq!(
+            encode_instruction(AESENC, &[XMM1.into(), XMM2.into()]),
+            [0x66, 0x0f, 0x38, 0xdc, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(AESENC, &[XMM9.into(), mem(XMMWord, RAX).into()]),
+            [0x66, 0x44, 0x0f, 0x38, 0xdc, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(AESDECLAST, &[XMM1.into(), XMM10.into()]),
+            [0x66, 0x41, 0x0f, 0x38, 0xdf, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(AESIMC, &[XMM1.into(), XMM2.into()]),
+            [0x66, 0x0f, 0x38, 0xdb, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(
+                AESKEYGENASSIST,
+                &[XMM1.into(), XMM2.into(), Operand::Immediate8(1)]
+            ),
+            [0x66, 0x0f, 0x3a, 0xdf, 0xca, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(PCLMULQDQ, &[XMM1.into(), XMM2.into(), Operand::Immediate8(0x11)]),
+            [0x66, 0x0f, 0x3a, 0x44, 0xca, 0x11]
+        );
+        assert_eq!(
+            encode_instruction(
+                VPCLMULQDQ,
+                &[XMM1.into(), XMM2.into(), XMM3.into(), Operand::Immediate8(0x10)]
+            ),
+            [0xc4, 0xe3, 0x69, 0x44, 0xcb, 0x10]
+        );
+        assert_eq!(
+            encode_instruction(
+                VPCLMULQDQ,
+                &[YMM9.into(), YMM2.into(), YMM3.into(), Operand::Immediate8(1)]
+            ),
+            [0xc4, 0x63, 0x6d, 0x44, 0xcb, 0x01]
+        );
+
+        // SHA
+        //
+        // sha1rnds4 xmm1, xmm2, 3        -> 0f 3a cc ca 03
+        // sha1nexte xmm1, xmm2           -> 0f 38 c8 ca
+        // sha1msg2 xmm9, xmm2            -> 44 0f 38 ca ca
+        // sha256rnds2 xmm1, xmm2, xmm0   -> 0f 38 cb ca
+        // sha256msg1 xmm1, xmmword [rax] -> 0f 38 cc 08
+
+        assert_eq!(
+            encode_instruction(SHA1RNDS4, &[XMM1.into(), XMM2.into(), Operand::Immediate8(3)]),
+            [0x0f, 0x3a, 0xcc, 0xca, 0x03]
+        );
+        assert_eq!(
+            encode_instruction(SHA1NEXTE, &[XMM1.into(), XMM2.into()]),
+            [0x0f, 0x38, 0xc8, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(SHA1MSG2, &[XMM9.into(), XMM2.into()]),
+            [0x44, 0x0f, 0x38, 0xca, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(SHA256RNDS2, &[XMM1.into(), XMM2.into(), XMM0.into()]),
+            [0x0f, 0x38, 0xcb, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(SHA256MSG1, &[XMM1.into(), mem(XMMWord, RAX).into()]),
+            [0x0f, 0x38, 0xcc, 0x08]
+        );
+
+        // RDRAND/RDSEED
+        //
+        // rdrand ax  -> 66 0f c7 f0
+        // rdrand eax -> 0f c7 f0
+        // rdrand r9  -> 49 0f c7 f1
+        // rdseed rcx -> 48 0f c7 f9
+
+        assert_eq!(encode_instruction(RDRAND, &[AX.into()]), [0x66, 0x0f, 0xc7, 0xf0]);
+        assert_eq!(encode_instruction(RDRAND, &[EAX.into()]), [0x0f, 0xc7, 0xf0]);
+        assert_eq!(encode_instruction(RDRAND, &[R9.into()]), [0x49, 0x0f, 0xc7, 0xf1]);
+        assert_eq!(encode_instruction(RDSEED, &[RCX.into()]), [0x48, 0x0f, 0xc7, 0xf9]);
+
+        // rdrand dword [rax] -> invalid, the destination is a register
+        assert_eq!(
+            encode(&Instruction::new(RDRAND, &[mem(Dword, RAX).into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(RDRAND)
+        );
+    }
+
+    #[test]
+    fn test_encode_avx() {
+        // VEX.128 and VEX.256, the 2-byte VEX (C5) is used when possible
+        //
+        // vmovaps xmm0, xmm1             -> c5 f8 28 c1
+        // vmovaps ymm8, ymmword [rax]    -> c5 7c 28 00
+        // vmovdqu xmm9, xmm10            -> c4 41 7a 6f ca
+        // vmovdqu ymmword [rax], ymm3    -> c5 fe 7f 18
+        // vaddps ymm0, ymm1, ymm2        -> c5 f4 58 c2
+        // vaddps xmm0, xmm1, [rax]       -> c5 f0 58 00
+        // vaddpd ymm8, ymm9, ymm10       -> c4 41 35 58 c2
+        // vaddsd xmm0, xmm1, qword [rax] -> c5 f3 58 00
+        // vmulpd xmm0, xmm14, xmm2       -> c5 89 59 c2
+        // vsqrtps ymm0, ymm1             -> c5 fc 51 c1
+        // vxorps ymm0, ymm0, ymm0        -> c5 fc 57 c0
+
+        assert_eq!(
+            encode_instruction(VMOVAPS, &[XMM0.into(), XMM1.into()]),
+            [0xc5, 0xf8, 0x28, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(VMOVAPS, &[YMM8.into(), mem(YMMWord, RAX).into()]),
+            [0xc5, 0x7c, 0x28, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(VMOVDQU, &[XMM9.into(), XMM10.into()]),
+            [0xc4, 0x41, 0x7a, 0x6f, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(VMOVDQU, &[mem(YMMWord, RAX).into(), YMM3.into()]),
+            [0xc5, 0xfe, 0x7f, 0x18]
+        );
+        assert_eq!(
+            encode_instruction(VADDPS, &[YMM0.into(), YMM1.into(), YMM2.into()]),
+            [0xc5, 0xf4, 0x58, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VADDPS, &[XMM0.into(), XMM1.into(), mem(Unsized, RAX).into()]),
+            [0xc5, 0xf0, 0x58, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(VADDPD, &[YMM8.into(), YMM9.into(), YMM10.into()]),
+            [0xc4, 0x41, 0x35, 0x58, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VADDSD, &[XMM0.into(), XMM1.into(), mem(Qword, RAX).into()]),
+            [0xc5, 0xf3, 0x58, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(VMULPD, &[XMM0.into(), XMM14.into(), XMM2.into()]),
+            [0xc5, 0x89, 0x59, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VSQRTPS, &[YMM0.into(), YMM1.into()]),
+            [0xc5, 0xfc, 0x51, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(VXORPS, &[YMM0.into(), YMM0.into(), YMM0.into()]),
+            [0xc5, 0xfc, 0x57, 0xc0]
+        );
+
+        // broadcast, insert/extract and permute
+        //
+        // vbroadcastss ymm0, dword [rax]      -> c4 e2 7d 18 00
+        // vpbroadcastq ymm0, xmm9             -> c4 c2 7d 59 c1
+        // vinsertf128 ymm0, ymm1, xmm2, 1     -> c4 e3 75 18 c2 01
+        // vextracti128 xmmword [rax], ymm1, 1 -> c4 e3 7d 39 08 01
+        // vpermilps xmm0, xmm1, 0x1b          -> c4 e3 79 04 c1 1b
+        // vpermilps ymm0, ymm1, ymm2          -> c4 e2 75 0c c2
+        // vpermilps xmm0, xmm1, xmmword [rax] -> c4 e2 71 0c 00
+        // vpermilpd xmm9, xmm10, xmm11        -> c4 42 29 0d cb
+        // vpermilpd ymm0, ymm1, [rax + 0x20]  -> c4 e2 75 0d 40 20
+        // vperm2i128 ymm0, ymm1, ymm2, 0x31   -> c4 e3 75 46 c2 31
+        // vpermd ymm0, ymm1, ymm2             -> c4 e2 75 36 c2
+        // vpermq ymm0, ymm1, 0x4e             -> c4 e3 fd 00 c1 4e
+
+        assert_eq!(
+            encode_instruction(VBROADCASTSS, &[YMM0.into(), mem(Dword, RAX).into()]),
+            [0xc4, 0xe2, 0x7d, 0x18, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(VPBROADCASTQ, &[YMM0.into(), XMM9.into()]),
+            [0xc4, 0xc2, 0x7d, 0x59, 0xc1]
+        );
+        assert_eq!(
+            encode_instruction(
+                VINSERTF128,
+                &[YMM0.into(), YMM1.into(), XMM2.into(), Operand::Immediate8(1)]
+            ),
+            [0xc4, 0xe3, 0x75, 0x18, 0xc2, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(
+                VEXTRACTI128,
+                &[mem(XMMWord, RAX).into(), YMM1.into(), Operand::Immediate8(1)]
+            ),
+            [0xc4, 0xe3, 0x7d, 0x39, 0x08, 0x01]
+        );
+        assert_eq!(
+            encode_instruction(VPERMILPS, &[XMM0.into(), XMM1.into(), Operand::Immediate8(0x1b)]),
+            [0xc4, 0xe3, 0x79, 0x04, 0xc1, 0x1b]
+        );
+        assert_eq!(
+            encode_instruction(VPERMILPS, &[YMM0.into(), YMM1.into(), YMM2.into()]),
+            [0xc4, 0xe2, 0x75, 0x0c, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VPERMILPS, &[XMM0.into(), XMM1.into(), mem(XMMWord, RAX).into()]),
+            [0xc4, 0xe2, 0x71, 0x0c, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(VPERMILPD, &[XMM9.into(), XMM10.into(), XMM11.into()]),
+            [0xc4, 0x42, 0x29, 0x0d, 0xcb]
+        );
+        assert_eq!(
+            encode_instruction(
+                VPERMILPD,
+                &[YMM0.into(), YMM1.into(), mem(YMMWord, RAX).with_displacement(0x20).into()]
+            ),
+            [0xc4, 0xe2, 0x75, 0x0d, 0x40, 0x20]
+        );
+        assert_eq!(
+            encode_instruction(
+                VPERM2I128,
+                &[YMM0.into(), YMM1.into(), YMM2.into(), Operand::Immediate8(0x31)]
+            ),
+            [0xc4, 0xe3, 0x75, 0x46, 0xc2, 0x31]
+        );
+        assert_eq!(
+            encode_instruction(VPERMD, &[YMM0.into(), YMM1.into(), YMM2.into()]),
+            [0xc4, 0xe2, 0x75, 0x36, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VPERMQ, &[YMM0.into(), YMM1.into(), Operand::Immediate8(0x4e)]),
+            [0xc4, 0xe3, 0xfd, 0x00, 0xc1, 0x4e]
+        );
+
+        // packed integer
+        //
+        // vpaddd ymm0, ymm1, ymmword [rax] -> c5 f5 fe 00
+        // vpaddq xmm8, xmm9, xmm10         -> c4 41 31 d4 c2
+        // vpxor ymm12, ymm12, ymm12        -> c4 41 1d ef e4
+        // vpcmpeqq ymm0, ymm1, ymm2        -> c4 e2 75 29 c2
+        // vpshufb ymm0, ymm1, ymm2         -> c4 e2 75 00 c2
+        // vpshufd ymm0, ymm1, 0x1b         -> c5 fd 70 c1 1b
+
+        assert_eq!(
+            encode_instruction(VPADDD, &[YMM0.into(), YMM1.into(), mem(YMMWord, RAX).into()]),
+            [0xc5, 0xf5, 0xfe, 0x00]
+        );
+        assert_eq!(
+            encode_instruction(VPADDQ, &[XMM8.into(), XMM9.into(), XMM10.into()]),
+            [0xc4, 0x41, 0x31, 0xd4, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VPXOR, &[YMM12.into(), YMM12.into(), YMM12.into()]),
+            [0xc4, 0x41, 0x1d, 0xef, 0xe4]
+        );
+        assert_eq!(
+            encode_instruction(VPCMPEQQ, &[YMM0.into(), YMM1.into(), YMM2.into()]),
+            [0xc4, 0xe2, 0x75, 0x29, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VPSHUFB, &[YMM0.into(), YMM1.into(), YMM2.into()]),
+            [0xc4, 0xe2, 0x75, 0x00, 0xc2]
+        );
+        assert_eq!(
+            encode_instruction(VPSHUFD, &[YMM0.into(), YMM1.into(), Operand::Immediate8(0x1b)]),
+            [0xc5, 0xfd, 0x70, 0xc1, 0x1b]
+        );
+
+        // gather (VSIB)
+        //
+        // vgatherdps ymm1, [rax + ymm2*4], ymm3      -> c4 e2 65 92 0c 90
+        // vgatherqps xmm1, [rax + ymm2*4], xmm3      -> c4 e2 65 93 0c 90
+        // vgatherdpd ymm1, [rax + xmm2*8], ymm3      -> c4 e2 e5 92 0c d0
+        // vpgatherdd xmm1, [rax + xmm4*4], xmm3      -> c4 e2 61 90 0c a0
+        // vpgatherqq ymm1, [r8 + ymm12*8 + 16], ymm3 -> c4 82 e5 91 4c e0 10
+
+        assert_eq!(
+            encode_instruction(
+                VGATHERDPS,
+                &[YMM1.into(), mem(Unsized, RAX).with_index(YMM2, 4).into(), YMM3.into()]
+            ),
+            [0xc4, 0xe2, 0x65, 0x92, 0x0c, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(
+                VGATHERQPS,
+                &[XMM1.into(), mem(Unsized, RAX).with_index(YMM2, 4).into(), XMM3.into()]
+            ),
+            [0xc4, 0xe2, 0x65, 0x93, 0x0c, 0x90]
+        );
+        assert_eq!(
+            encode_instruction(
+                VGATHERDPD,
+                &[YMM1.into(), mem(Unsized, RAX).with_index(XMM2, 8).into(), YMM3.into()]
+            ),
+            [0xc4, 0xe2, 0xe5, 0x92, 0x0c, 0xd0]
+        );
+        assert_eq!(
+            encode_instruction(
+                VPGATHERDD,
+                &[XMM1.into(), mem(Unsized, RAX).with_index(XMM4, 4).into(), XMM3.into()]
+            ),
+            [0xc4, 0xe2, 0x61, 0x90, 0x0c, 0xa0]
+        );
+        assert_eq!(
+            encode_instruction(
+                VPGATHERQQ,
+                &[
+                    YMM1.into(),
+                    mem(Unsized, R8).with_index(YMM12, 8).with_displacement(16).into(),
+                    YMM3.into()
+                ]
+            ),
+            [0xc4, 0x82, 0xe5, 0x91, 0x4c, 0xe0, 0x10]
+        );
+
+        // vzeroupper -> c5 f8 77
+        // vzeroall   -> c5 fc 77
+
+        assert_eq!(encode_instruction(VZEROUPPER, &[]), [0xc5, 0xf8, 0x77]);
+        assert_eq!(encode_instruction(VZEROALL, &[]), [0xc5, 0xfc, 0x77]);
+
+        // vaddps ymm0, xmm1, ymm2 -> invalid, the vector lengths are mixed
+        assert_eq!(
+            encode(&Instruction::new(VADDPS, &[YMM0.into(), XMM1.into(), YMM2.into()]), 0, &[])
+                .unwrap_err(),
+            EncodeError::InvalidOperands(VADDPS)
+        );
+
+        // vaddss xmm0, xmm1, ymm2 -> invalid, the scalar form only accepts XMM
+        assert_eq!(
+            encode(&Instruction::new(VADDSS, &[XMM0.into(), XMM1.into(), YMM2.into()]), 0, &[])
+                .unwrap_err(),
+            EncodeError::InvalidOperands(VADDSS)
+        );
+
+        // vgatherdps xmm1, [rax + rbx*4], xmm3 -> invalid, the index must be a vector register
+        assert_eq!(
+            encode(
+                &Instruction::new(
+                    VGATHERDPS,
+                    &[XMM1.into(), mem(Unsized, RAX).with_index(RBX, 4).into(), XMM3.into()]
+                ),
+                0,
+                &[]
+            )
+            .unwrap_err(),
+            EncodeError::InvalidOperands(VGATHERDPS)
+        );
+
+        // mov eax, [rax + xmm1*4] -> invalid, VSIB is only used by the gather instructions
+        assert_eq!(
+            encode(
+                &Instruction::new(MOV, &[EAX.into(), mem(Dword, RAX).with_index(XMM1, 4).into()]),
+                0,
+                &[]
+            )
+            .unwrap_err(),
+            EncodeError::InvalidOperands(MOV)
+        );
+    }
+
+    #[test]
+    fn test_encode_fma() {
+        // FMA3, the 132/213/231 forms have the same operands but different opcodes
+        //
+        // vfmadd132ps xmm1, xmm2, xmm3           -> c4 e2 69 98 cb
+        // vfmadd213ps xmm1, xmm2, xmm3           -> c4 e2 69 a8 cb
+        // vfmadd231ps xmm1, xmm2, xmm3           -> c4 e2 69 b8 cb
+        // vfmadd231pd ymm9, ymm12, ymmword [rax] -> c4 62 9d b8 08
+        // vfmsub213ss xmm1, xmm2, dword [rax]    -> c4 e2 69 ab 08
+        // vfnmadd132sd xmm11, xmm2, xmm3         -> c4 62 e9 9d db
+        // vfnmsub231ps ymm1, ymm2, ymm13         -> c4 c2 6d be cd
+
+        assert_eq!(
+            encode_instruction(VFMADD132PS, &[XMM1.into(), XMM2.into(), XMM3.into()]),
+            [0xc4, 0xe2, 0x69, 0x98, 0xcb]
+        );
+        assert_eq!(
+            encode_instruction(VFMADD213PS, &[XMM1.into(), XMM2.into(), XMM3.into()]),
+            [0xc4, 0xe2, 0x69, 0xa8, 0xcb]
+        );
+        assert_eq!(
+            encode_instruction(VFMADD231PS, &[XMM1.into(), XMM2.into(), XMM3.into()]),
+            [0xc4, 0xe2, 0x69, 0xb8, 0xcb]
+        );
+        assert_eq!(
+            encode_instruction(VFMADD231PD, &[YMM9.into(), YMM12.into(), mem(YMMWord, RAX).into()]),
+            [0xc4, 0x62, 0x9d, 0xb8, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(VFMSUB213SS, &[XMM1.into(), XMM2.into(), mem(Dword, RAX).into()]),
+            [0xc4, 0xe2, 0x69, 0xab, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(VFNMADD132SD, &[XMM11.into(), XMM2.into(), XMM3.into()]),
+            [0xc4, 0x62, 0xe9, 0x9d, 0xdb]
+        );
+        assert_eq!(
+            encode_instruction(VFNMSUB231PS, &[YMM1.into(), YMM2.into(), YMM13.into()]),
+            [0xc4, 0xc2, 0x6d, 0xbe, 0xcd]
+        );
+
+        // F16C
+        //
+        // vcvtph2ps xmm1, xmm2           -> c4 e2 79 13 ca
+        // vcvtph2ps ymm1, xmmword [rax]  -> c4 e2 7d 13 08
+        // vcvtps2ph xmm1, ymm2, 4        -> c4 e3 7d 1d d1 04
+        // vcvtps2ph qword [rax], xmm2, 0 -> c4 e3 79 1d 10 00
+
+        assert_eq!(
+            encode_instruction(VCVTPH2PS, &[XMM1.into(), XMM2.into()]),
+            [0xc4, 0xe2, 0x79, 0x13, 0xca]
+        );
+        assert_eq!(
+            encode_instruction(VCVTPH2PS, &[YMM1.into(), mem(XMMWord, RAX).into()]),
+            [0xc4, 0xe2, 0x7d, 0x13, 0x08]
+        );
+        assert_eq!(
+            encode_instruction(VCVTPS2PH, &[XMM1.into(), YMM2.into(), Operand::Immediate8(4)]),
+            [0xc4, 0xe3, 0x7d, 0x1d, 0xd1, 0x04]
+        );
+        assert_eq!(
+            encode_instruction(
+                VCVTPS2PH,
+                &[mem(Qword, RAX).into(), XMM2.into(), Operand::Immediate8(0)]
+            ),
+            [0xc4, 0xe3, 0x79, 0x1d, 0x10, 0x00]
+        );
+
+        // AVX-VNNI
+        //
+        // vpdpbusd xmm1, xmm2, xmm3            -> c4 e2 69 50 cb
+        // vpdpwssds ymm1, ymm12, ymmword [rax] -> c4 e2 1d 53 08
+
+        assert_eq!(
+            encode_instruction(VPDPBUSD, &[XMM1.into(), XMM2.into(), XMM3.into()]),
+            [0xc4, 0xe2, 0x69, 0x50, 0xcb]
+        );
+        assert_eq!(
+            encode_instruction(VPDPWSSDS, &[YMM1.into(), YMM12.into(), mem(YMMWord, RAX).into()]),
+            [0xc4, 0xe2, 0x1d, 0x53, 0x08]
+        );
+
+        // vcvtph2ps ymm1, ymm2 -> invalid, the source is always XMM
+        assert_eq!(
+            encode(&Instruction::new(VCVTPH2PS, &[YMM1.into(), YMM2.into()]), 0, &[]).unwrap_err(),
+            EncodeError::InvalidOperands(VCVTPH2PS)
+        );
     }
 }
@@ -10,18 +10,270 @@ use crate::mnemonic::Mnemonic;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Instruction {
+    pub prefix: Option<Prefix>,
     pub mnemonic: Mnemonic,
     pub operands: [Option<Operand>; 4],
 }
 
+impl Instruction {
+    pub fn new(mnemonic: Mnemonic, operands: &[Operand]) -> Self {
+        let mut items: [Option<Operand>; 4] = [None, None, None, None];
+        for (item, operand) in items.iter_mut().zip(operands) {
+            *item = Some(operand.clone());
+        }
+
+        Self {
+            prefix: None,
+            mnemonic,
+            operands: items,
+        }
+    }
+
+    pub fn with_prefix(mut self, prefix: Prefix) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+}
+
+/// The instruction prefix that is written in the assembly, e.g. `lock add [rax], 1`.
+///
+/// The other prefixes (i.e. segment override, operand-size and REX) are
+/// determined by the operands.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Prefix {
+    LOCK,  // F0, only allowed on the forms that read-modify-write a memory destination
+    REP,   // F3, only allowed on MOVS, STOS and LODS
+    REPE,  // F3, only allowed on CMPS and SCAS, alias REPZ
+    REPNE, // F2, only allowed on CMPS and SCAS, alias REPNZ
+}
+
+impl Prefix {
+    pub fn from_name(name: &str) -> Option<Prefix> {
+        match name.to_ascii_lowercase().as_str() {
+            "lock" => Some(Prefix::LOCK),
+            "rep" => Some(Prefix::REP),
+            "repe" | "repz" => Some(Prefix::REPE),
+            "repne" | "repnz" => Some(Prefix::REPNE),
+            _ => None,
+        }
+    }
+
+    /// The name in lowercase, e.g. "lock".
+    pub fn name(&self) -> &'static str {
+        match self {
+            Prefix::LOCK => "lock",
+            Prefix::REP => "rep",
+            Prefix::REPE => "repe",
+            Prefix::REPNE => "repne",
+        }
+    }
+
+    pub fn byte(&self) -> u8 {
+        match self {
+            Prefix::LOCK => 0xf0,
+            Prefix::REP | Prefix::REPE => 0xf3,
+            Prefix::REPNE => 0xf2,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum Operand {
-    Register(u8),           // Register operand, e.g., RAX, RBX
+    Register(Register),     // Register operand, e.g., RAX, RBX
     Immediate8(u8),         // Immediate value operand
     Immediate16(u16),       // Immediate value operand
     Immediate32(u32),       // Immediate value operand
     Immediate64(u64),       // Immediate value operand
-    Memory(u64 /* size */), // Memory address operand
+    Memory(MemoryOperand),  // Memory address operand
+    Label(String),          // The target of relative branch, e.g. `call label`
+}
+
+impl Operand {
+    /// The value of an immediate operand, it is sign-extended from the width of the variant,
+    /// e.g. `Immediate8(0xff)` is -1, and `Immediate16(0x00ff)` is 255.
+    pub fn immediate_value(&self) -> Option<i64> {
+        match self {
+            Operand::Immediate8(value) => Some(*value as i8 as i64),
+            Operand::Immediate16(value) => Some(*value as i16 as i64),
+            Operand::Immediate32(value) => Some(*value as i32 as i64),
+            Operand::Immediate64(value) => Some(*value as i64),
+            _ => None,
+        }
+    }
+}
+
+impl From<Register> for Operand {
+    fn from(register: Register) -> Self {
+        Operand::Register(register)
+    }
+}
+
+impl From<MemoryOperand> for Operand {
+    fn from(memory: MemoryOperand) -> Self {
+        Operand::Memory(memory)
+    }
+}
+
+/// Memory operand, i.e. `size segment:[base + index*scale + displacement]`.
+///
+/// Forms:
+///
+/// - `qword [rbx]`, `qword [rbx + 0x10]`: base + displacement
+/// - `qword [r12 + rsi*4 - 0x10]`: base + index*scale + displacement
+/// - `qword fs:[0x28]`, `qword gs:[rax]`: with segment override
+/// - `[rax + xmm2*4]`, `[rax + ymm2*8]`: VSIB, the index is a vector register,
+///   it is only used by the gather instructions, e.g. `VGATHERDPS`.
+/// - `dword [rel num1]`, `dword [rel num1 + 4]`: RIP-relative, the address of the
+///   symbol (if present) plus the displacement.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MemoryOperand {
+    pub size: OperandSize,
+    pub segment: Option<Register>, // FS or GS
+    pub base: Option<Register>,
+    pub index: Option<Register>,
+    pub scale: u8, // 1, 2, 4 or 8
+    pub displacement: i64,
+    pub symbol: Option<String>,
+    pub rip_relative: bool,
+}
+
+impl MemoryOperand {
+    pub fn new(size: OperandSize, base: Register) -> Self {
+        Self {
+            size,
+            segment: None,
+            base: Some(base),
+            index: None,
+            scale: 1,
+            displacement: 0,
+            symbol: None,
+            rip_relative: false,
+        }
+    }
+
+    /// RIP-relative memory operand, e.g. `[rel num1]`.
+    pub fn new_rip_relative(size: OperandSize) -> Self {
+        Self {
+            size,
+            segment: None,
+            base: None,
+            index: None,
+            scale: 1,
+            displacement: 0,
+            symbol: None,
+            rip_relative: true,
+        }
+    }
+
+    /// Absolute address, it is only allowed with FS/GS segment override, e.g. `fs:[0x28]`.
+    pub fn new_absolute(size: OperandSize, displacement: i64) -> Self {
+        Self {
+            size,
+            segment: None,
+            base: None,
+            index: None,
+            scale: 1,
+            displacement,
+            symbol: None,
+            rip_relative: false,
+        }
+    }
+
+    pub fn with_segment(mut self, segment: Register) -> Self {
+        self.segment = Some(segment);
+        self
+    }
+
+    pub fn with_index(mut self, index: Register, scale: u8) -> Self {
+        self.index = Some(index);
+        self.scale = scale;
+        self
+    }
+
+    pub fn with_displacement(mut self, displacement: i64) -> Self {
+        self.displacement = displacement;
+        self
+    }
+
+    /// Whether the index is a vector register (XMM or YMM), i.e. VSIB.
+    pub fn is_vsib(&self) -> bool {
+        self.index
+            .is_some_and(|index| matches!(index.class(), RegisterClass::XMM | RegisterClass::YMM))
+    }
+
+    pub fn with_symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    /// Checks the memory operand against the limitations of ANASM,
+    /// see the header of `lib.rs`.
+    pub fn validate(&self) -> Result<(), MemoryOperandError> {
+        if let Some(segment) = &self.segment
+            && !matches!(segment, Register::FS | Register::GS)
+        {
+            return Err(MemoryOperandError::InvalidSegment(*segment));
+        }
+
+        if self.rip_relative {
+            if self.base.is_some() || self.index.is_some() {
+                return Err(MemoryOperandError::RipRelativeWithRegister);
+            }
+        } else {
+            match &self.base {
+                Some(base) if !is_general_purpose_64(base) => {
+                    return Err(MemoryOperandError::InvalidBase(*base));
+                }
+                Some(_) => {}
+                // absolute address is only allowed with FS/GS segment override,
+                // e.g. `mov rax, qword fs:[0x28]`
+                None if self.segment.is_none() => return Err(MemoryOperandError::MissingBase),
+                None => {}
+            }
+        }
+
+        if let Some(index) = &self.index {
+            // SIB.index=100 indicates no index, so RSP can not be used as the index,
+            // but XMM4/YMM4 can, since VSIB always has an index.
+            if !(is_general_purpose_64(index) || self.is_vsib()) || *index == Register::RSP {
+                return Err(MemoryOperandError::InvalidIndex(*index));
+            }
+        }
+
+        if !matches!(self.scale, 1 | 2 | 4 | 8) {
+            return Err(MemoryOperandError::InvalidScale(self.scale));
+        }
+
+        // the address of symbol is only available in RIP-relative addressing
+        // since ANASM generates position-independent code only.
+        if self.symbol.is_some() && !self.rip_relative {
+            return Err(MemoryOperandError::SymbolWithoutRipRelative);
+        }
+
+        if i32::try_from(self.displacement).is_err() {
+            return Err(MemoryOperandError::DisplacementOutOfRange(
+                self.displacement,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum MemoryOperandError {
+    InvalidSegment(Register),      // only FS and GS are allowed
+    InvalidBase(Register),         // only 64-bit general-purpose registers are allowed
+    InvalidIndex(Register),        // only 64-bit general-purpose registers except RSP, or XMM/YMM
+    InvalidScale(u8),              // only 1, 2, 4 and 8 are allowed
+    DisplacementOutOfRange(i64),   // the displacement is a signed 32-bit integer
+    MissingBase,                   // the base register is required except RIP-relative and FS/GS addressing
+    RipRelativeWithRegister,       // RIP-relative addressing can not have base or index register
+    SymbolWithoutRipRelative,      // symbol can only be used in RIP-relative addressing
+}
+
+fn is_general_purpose_64(register: &Register) -> bool {
+    register.class() == RegisterClass::GeneralPurpose && register.width() == 64
 }
 
 /* *
@@ -38,7 +290,7 @@ pub enum Operand {
  *
  * Supported:
  *
- * - Debug Registers (DR0–DR7, 32-bit)
+ * - Debug Registers (DR0–DR7, 64-bit in 64-bit mode), only `MOV` to and from them
  * - RDX:RAX register pair representing a 128-bit operand.
  * - SSE, SSE2, SSE3 (XMM0-XMM15, 128-bit)
  * - AVX, AVX2 (XMM0-XMM15, YMM0-YMM15, 256-bit), recommended
@@ -61,11 +313,12 @@ pub enum Operand {
  *   https://www.syncfusion.com/succinctly-free-ebooks/assemblylanguage/simd-instruction-sets
  *
  */
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Register {
     /* *
-     * AH, BH, CH and DH are conflicted with REX prefix in long mode, they are
-     * not allowed since ANASM is intented for developing modern x86-64 applications.
+     * AH, BH, CH and DH are conflicted with REX prefix in long mode, they can only
+     * be used in instructions that do not require the REX prefix, e.g. `mov ah, al`
+     * is valid but `mov ah, sil` and `movzx rax, ah` are not.
      *
      * ## the REX prefix
      *
@@ -144,6 +397,7 @@ pub enum Register {
     RBP, EBP, BP, BPL, /* alias R5, (REX.R/B, ModRM.reg/rm) = 0,5 */
     RSI, ESI, SI, SIL, /* alias R6, (REX.R/B, ModRM.reg/rm) = 0,6 */
     RDI, EDI, DI, DIL, /* alias R7, (REX.R/B, ModRM.reg/rm) = 0,7 */
+    AH, CH, DH, BH, /* high-byte registers, ModRM.reg/rm = 4,5,6,7 without REX */
     R8, R8D, R8W, R8B, /* (REX.R/B, ModRM.reg/rm) = 1.0 */
     R9, R9D, R9W, R9B, /* (REX.R/B, ModRM.reg/rm) = 1.1 */
     R10, R10D, R10W, R10B, /* (REX.R/B, ModRM.reg/rm) = 1.2 */
@@ -185,10 +439,304 @@ pub enum Register {
     // once a thread is created.
     // GS is more used in kernel space to access per-CPU data.
     FS,
+
+    // debug registers, accessed only by `MOV r64, DRn` and `MOV DRn, r64` (privileged),
+    // DR4 and DR5 are aliases of DR6 and DR7 when CR4.DE is clear.
+    DR0, DR1, DR2, DR3, DR4, DR5, DR6, DR7, /* ModRM.reg = 0-7 */
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RegisterClass {
+    GeneralPurpose,     // RAX-R15, EAX-R15D, AX-R15W, AL-R15B, AH-BH
+    XMM,                // XMM0-XMM15, 128-bit
+    YMM,                // YMM0-YMM15, 256-bit
+    ZMM,                // ZMM0-ZMM15, 512-bit
+    Segment,            // FS, GS
+    InstructionPointer, // RIP
+    Debug,              // DR0-DR7
+}
+
+impl Register {
+    pub const fn class(&self) -> RegisterClass {
+        use Register::*;
+        match self {
+            XMM0 | XMM1 | XMM2 | XMM3 | XMM4 | XMM5 | XMM6 | XMM7 | XMM8 | XMM9 | XMM10
+            | XMM11 | XMM12 | XMM13 | XMM14 | XMM15 => RegisterClass::XMM,
+            YMM0 | YMM1 | YMM2 | YMM3 | YMM4 | YMM5 | YMM6 | YMM7 | YMM8 | YMM9 | YMM10
+            | YMM11 | YMM12 | YMM13 | YMM14 | YMM15 => RegisterClass::YMM,
+            ZMM0 | ZMM1 | ZMM2 | ZMM3 | ZMM4 | ZMM5 | ZMM6 | ZMM7 | ZMM8 | ZMM9 | ZMM10
+            | ZMM11 | ZMM12 | ZMM13 | ZMM14 | ZMM15 => RegisterClass::ZMM,
+            FS | GS => RegisterClass::Segment,
+            RIP => RegisterClass::InstructionPointer,
+            DR0 | DR1 | DR2 | DR3 | DR4 | DR5 | DR6 | DR7 => RegisterClass::Debug,
+            _ => RegisterClass::GeneralPurpose,
+        }
+    }
+
+    pub const fn size(&self) -> OperandSize {
+        use Register::*;
+        match self {
+            RAX | RCX | RDX | RBX | RSP | RBP | RSI | RDI | R8 | R9 | R10 | R11 | R12 | R13
+            | R14 | R15 | RIP | DR0 | DR1 | DR2 | DR3 | DR4 | DR5 | DR6 | DR7 => {
+                OperandSize::Qword
+            }
+            EAX | ECX | EDX | EBX | ESP | EBP | ESI | EDI | R8D | R9D | R10D | R11D | R12D
+            | R13D | R14D | R15D => OperandSize::Dword,
+            AX | CX | DX | BX | SP | BP | SI | DI | R8W | R9W | R10W | R11W | R12W | R13W
+            | R14W | R15W | GS | FS => OperandSize::Word,
+            AL | CL | DL | BL | AH | CH | DH | BH | SPL | BPL | SIL | DIL | R8B | R9B | R10B | R11B | R12B | R13B
+            | R14B | R15B => OperandSize::Byte,
+            _ => match self.class() {
+                RegisterClass::XMM => OperandSize::XMMWord,
+                RegisterClass::YMM => OperandSize::YMMWord,
+                _ => OperandSize::ZMMWord,
+            },
+        }
+    }
+
+    /// The width of register in bits, e.g. 64 for RAX, 8 for AL, 256 for YMM0.
+    pub fn width(&self) -> u16 {
+        self.size().bits()
+    }
+
+    /// The 4-bit hardware register number, the high bit goes to REX.R, REX.X or REX.B,
+    /// and the low 3 bits go to ModRM.reg, ModRM.r/m, SIB.index, SIB.base or the opcode.
+    ///
+    /// The number of segment registers are ES=0, CS=1, SS=2, DS=3, FS=4 and GS=5.
+    pub fn number(&self) -> u8 {
+        use Register::*;
+        match self {
+            RAX | EAX | AX | AL | XMM0 | YMM0 | ZMM0 | DR0 => 0,
+            RCX | ECX | CX | CL | XMM1 | YMM1 | ZMM1 | DR1 => 1,
+            RDX | EDX | DX | DL | XMM2 | YMM2 | ZMM2 | DR2 => 2,
+            RBX | EBX | BX | BL | XMM3 | YMM3 | ZMM3 | DR3 => 3,
+            RSP | ESP | SP | SPL | AH | XMM4 | YMM4 | ZMM4 | DR4 => 4,
+            RBP | EBP | BP | BPL | CH | XMM5 | YMM5 | ZMM5 | RIP | DR5 => 5,
+            RSI | ESI | SI | SIL | DH | XMM6 | YMM6 | ZMM6 | DR6 => 6,
+            RDI | EDI | DI | DIL | BH | XMM7 | YMM7 | ZMM7 | DR7 => 7,
+            R8 | R8D | R8W | R8B | XMM8 | YMM8 | ZMM8 => 8,
+            R9 | R9D | R9W | R9B | XMM9 | YMM9 | ZMM9 => 9,
+            R10 | R10D | R10W | R10B | XMM10 | YMM10 | ZMM10 => 10,
+            R11 | R11D | R11W | R11B | XMM11 | YMM11 | ZMM11 => 11,
+            R12 | R12D | R12W | R12B | XMM12 | YMM12 | ZMM12 => 12,
+            R13 | R13D | R13W | R13B | XMM13 | YMM13 | ZMM13 => 13,
+            R14 | R14D | R14W | R14B | XMM14 | YMM14 | ZMM14 => 14,
+            R15 | R15D | R15W | R15B | XMM15 | YMM15 | ZMM15 => 15,
+            FS => 4,
+            GS => 5,
+        }
+    }
+
+    /// Gets the register by name, case-insensitive, e.g. "rax" and "RAX".
+    pub fn from_name(name: &str) -> Option<Register> {
+        use Register::*;
+        let register = match name.to_ascii_lowercase().as_str() {
+            "rax" => RAX,
+            "eax" => EAX,
+            "ax" => AX,
+            "al" => AL,
+            "ah" => AH,
+            "rcx" => RCX,
+            "ecx" => ECX,
+            "cx" => CX,
+            "cl" => CL,
+            "ch" => CH,
+            "rdx" => RDX,
+            "edx" => EDX,
+            "dx" => DX,
+            "dl" => DL,
+            "dh" => DH,
+            "rbx" => RBX,
+            "ebx" => EBX,
+            "bx" => BX,
+            "bl" => BL,
+            "bh" => BH,
+            "rsp" => RSP,
+            "esp" => ESP,
+            "sp" => SP,
+            "spl" => SPL,
+            "rbp" => RBP,
+            "ebp" => EBP,
+            "bp" => BP,
+            "bpl" => BPL,
+            "rsi" => RSI,
+            "esi" => ESI,
+            "si" => SI,
+            "sil" => SIL,
+            "rdi" => RDI,
+            "edi" => EDI,
+            "di" => DI,
+            "dil" => DIL,
+            "r8" => R8,
+            "r8d" => R8D,
+            "r8w" => R8W,
+            "r8b" => R8B,
+            "r9" => R9,
+            "r9d" => R9D,
+            "r9w" => R9W,
+            "r9b" => R9B,
+            "r10" => R10,
+            "r10d" => R10D,
+            "r10w" => R10W,
+            "r10b" => R10B,
+            "r11" => R11,
+            "r11d" => R11D,
+            "r11w" => R11W,
+            "r11b" => R11B,
+            "r12" => R12,
+            "r12d" => R12D,
+            "r12w" => R12W,
+            "r12b" => R12B,
+            "r13" => R13,
+            "r13d" => R13D,
+            "r13w" => R13W,
+            "r13b" => R13B,
+            "r14" => R14,
+            "r14d" => R14D,
+            "r14w" => R14W,
+            "r14b" => R14B,
+            "r15" => R15,
+            "r15d" => R15D,
+            "r15w" => R15W,
+            "r15b" => R15B,
+            "xmm0" => XMM0,
+            "ymm0" => YMM0,
+            "zmm0" => ZMM0,
+            "xmm1" => XMM1,
+            "ymm1" => YMM1,
+            "zmm1" => ZMM1,
+            "xmm2" => XMM2,
+            "ymm2" => YMM2,
+            "zmm2" => ZMM2,
+            "xmm3" => XMM3,
+            "ymm3" => YMM3,
+            "zmm3" => ZMM3,
+            "xmm4" => XMM4,
+            "ymm4" => YMM4,
+            "zmm4" => ZMM4,
+            "xmm5" => XMM5,
+            "ymm5" => YMM5,
+            "zmm5" => ZMM5,
+            "xmm6" => XMM6,
+            "ymm6" => YMM6,
+            "zmm6" => ZMM6,
+            "xmm7" => XMM7,
+            "ymm7" => YMM7,
+            "zmm7" => ZMM7,
+            "xmm8" => XMM8,
+            "ymm8" => YMM8,
+            "zmm8" => ZMM8,
+            "xmm9" => XMM9,
+            "ymm9" => YMM9,
+            "zmm9" => ZMM9,
+            "xmm10" => XMM10,
+            "ymm10" => YMM10,
+            "zmm10" => ZMM10,
+            "xmm11" => XMM11,
+            "ymm11" => YMM11,
+            "zmm11" => ZMM11,
+            "xmm12" => XMM12,
+            "ymm12" => YMM12,
+            "zmm12" => ZMM12,
+            "xmm13" => XMM13,
+            "ymm13" => YMM13,
+            "zmm13" => ZMM13,
+            "xmm14" => XMM14,
+            "ymm14" => YMM14,
+            "zmm14" => ZMM14,
+            "xmm15" => XMM15,
+            "ymm15" => YMM15,
+            "zmm15" => ZMM15,
+            "rip" => RIP,
+            "fs" => FS,
+            "gs" => GS,
+            "dr0" => DR0,
+            "dr1" => DR1,
+            "dr2" => DR2,
+            "dr3" => DR3,
+            "dr4" => DR4,
+            "dr5" => DR5,
+            "dr6" => DR6,
+            "dr7" => DR7,
+            _ => return None,
+        };
+        Some(register)
+    }
+
+    /// Gets the general-purpose register by the hardware number and size,
+    /// e.g. `(0, Dword)` is EAX, and `(9, Byte)` is R9B.
+    pub fn general_purpose(number: u8, size: OperandSize) -> Option<Register> {
+        use Register::*;
+        const REGISTERS: [[Register; 4]; 16] = [
+            [RAX, EAX, AX, AL],
+            [RCX, ECX, CX, CL],
+            [RDX, EDX, DX, DL],
+            [RBX, EBX, BX, BL],
+            [RSP, ESP, SP, SPL],
+            [RBP, EBP, BP, BPL],
+            [RSI, ESI, SI, SIL],
+            [RDI, EDI, DI, DIL],
+            [R8, R8D, R8W, R8B],
+            [R9, R9D, R9W, R9B],
+            [R10, R10D, R10W, R10B],
+            [R11, R11D, R11W, R11B],
+            [R12, R12D, R12W, R12B],
+            [R13, R13D, R13W, R13B],
+            [R14, R14D, R14W, R14B],
+            [R15, R15D, R15W, R15B],
+        ];
+
+        let column = match size {
+            OperandSize::Qword => 0,
+            OperandSize::Dword => 1,
+            OperandSize::Word => 2,
+            OperandSize::Byte => 3,
+            _ => return None,
+        };
+
+        REGISTERS.get(number as usize).map(|row| row[column])
+    }
+
+    /// Whether the register number is 8-15, i.e. it needs REX.R, REX.X or REX.B
+    /// (depending on the field it is encoded in) to extend the 3-bit field.
+    pub fn is_extended(&self) -> bool {
+        self.number() & 0b1000 != 0
+    }
+
+    /// SPL, BPL, SIL and DIL can only be accessed with the REX prefix,
+    /// otherwise they are encoded as AH, CH, DH and BH.
+    pub fn requires_rex(&self) -> bool {
+        matches!(self, Register::SPL | Register::BPL | Register::SIL | Register::DIL)
+    }
+
+    /// AH, CH, DH and BH can only be accessed without the REX prefix,
+    /// otherwise they are encoded as SPL, BPL, SIL and DIL.
+    pub fn conflicts_with_rex(&self) -> bool {
+        matches!(self, Register::AH | Register::CH | Register::DH | Register::BH)
+    }
 }
 
 /// Definition of an instruction, including its mnemonic and encoding details.
 ///
+/// Each definition describes one form of the instruction (i.e. one row of the
+/// opcode table in the Intel manual), e.g. `REX.W + 89 /r, MOV r/m64, r64`.
+///
+/// The opcode column is described by the following fields:
+///
+/// - the encoding scheme (legacy, VEX or EVEX) and the opcode map (one-byte, 0F, 0F 38
+///   or 0F 3A), e.g. `VEX.256.66.0F38.W0 18 /r` is VEX and 0F 38.
+/// - the opcode byte.
+/// - the mandatory prefix (66/F2/F3), it goes to VEX.pp/EVEX.pp in the VEX/EVEX encoding.
+/// - the ModRM extension, i.e. the "/digit" in `C7 /0`, or the whole ModRM byte, e.g. the
+///   F0 in `NP 0F AE F0` (MFENCE).
+/// - the register-in-opcode flag, i.e. `+rb/+rw/+rd/+ro` in `B8+ rd id`.
+/// - the W bit and the L bit, i.e. REX.W/VEX.W/EVEX.W and VEX.L/EVEX.L'L.
+///
+/// The operand-size prefix (66) and REX.W are not part of the definition, they are
+/// determined by the size of the first general-purpose operand:
+/// 16-bit requires 66, and 64-bit requires REX.W. Instructions without explicit
+/// operands (e.g. `CBW` and `CDQE`) specify the operand size by `with_operand_size`.
+///
 /// Instruction list:
 /// - http://ref.x86asm.net/index.html
 /// - http://ref.x86asm.net/coder64.html
@@ -197,13 +745,216 @@ pub enum Register {
 #[derive(Debug, PartialEq, Clone)]
 pub struct InstructionDefinition {
     pub mnemonic: Mnemonic,
-    pub two_bytes: bool, // true if the instruction uses the 0F prefix
-    pub primary_opcode: u8,
-    pub secondary_opcode: Option<u8>,
+    pub scheme: EncodingScheme,
+    pub map: OpcodeMap,
+    pub opcode: u8, // the opcode byte that follows the escape bytes or the VEX/EVEX prefix
+    pub mandatory_prefix: Option<u8>, // e.g. the F3 of `PAUSE` (F3 90)
+    pub opcode_extension: Option<u8>, // the "/digit" in the opcode column, it goes to ModRM.reg
+    pub modrm: Option<u8>, // the fixed ModRM byte, e.g. the F0 of `NP 0F AE F0` (MFENCE)
+    pub register_in_opcode: bool, // an operand is added to the opcode, e.g. `B8+ rd id`
+    pub w: bool, // REX.W/VEX.W/EVEX.W, it is also set by the 64-bit operand size
+    pub l: u8, // VEX.L or EVEX.L'L, i.e. 0 = 128-bit, 1 = 256-bit and 2 = 512-bit
+    pub default_64: bool, // the operand size defaults to 64-bit in long mode, REX.W is not needed
+    pub explicit_operand_size: Option<OperandSize>,
     pub operands: [Option<OperandDefinition>; 4],
+    pub implicit_operands: &'static [ImplicitOperand], // e.g. RDX:RAX of `MUL r/m64`
+    pub allowed_prefixes: &'static [Prefix],
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl InstructionDefinition {
+    pub const fn new(
+        mnemonic: Mnemonic,
+        map: OpcodeMap,
+        opcode: u8,
+        operands: &[OperandDefinition],
+    ) -> Self {
+        let mut items: [Option<OperandDefinition>; 4] = [None, None, None, None];
+        let mut register_in_opcode = false;
+        let mut idx = 0;
+        while idx < operands.len() {
+            items[idx] = Some(operands[idx]);
+            register_in_opcode |= matches!(operands[idx].encoding, OperandEncoding::Opcode);
+            idx += 1;
+        }
+
+        Self {
+            mnemonic,
+            scheme: EncodingScheme::Legacy,
+            map,
+            opcode,
+            mandatory_prefix: None,
+            opcode_extension: None,
+            modrm: None,
+            register_in_opcode,
+            w: false,
+            l: 0,
+            default_64: false,
+            explicit_operand_size: None,
+            operands: items,
+            implicit_operands: &[],
+            allowed_prefixes: &[],
+        }
+    }
+
+    pub const fn with_opcode_extension(mut self, opcode_extension: u8) -> Self {
+        self.opcode_extension = Some(opcode_extension);
+        self
+    }
+
+    /// The ModRM byte is a part of the opcode, e.g. `NP 0F AE F0` (MFENCE)
+    /// and `0F 01 F9` (RDTSCP), the instruction has no ModRM operands.
+    pub const fn with_modrm(mut self, modrm: u8) -> Self {
+        self.modrm = Some(modrm);
+        self
+    }
+
+    /// Near branches (e.g. `CALL r/m64` and `JMP r/m64`) and stack operations
+    /// default to 64-bit operand size, i.e. `call rax` is `ff d0` rather than `48 ff d0`.
+    pub const fn with_default_64(mut self) -> Self {
+        self.default_64 = true;
+        self
+    }
+
+    /// Specifies the operand-size attribute, e.g. `CWD` (16-bit), `CDQ` (32-bit)
+    /// and `CQO` (64-bit) have the same opcode and no explicit operands.
+    pub const fn with_operand_size(mut self, operand_size: OperandSize) -> Self {
+        self.explicit_operand_size = Some(operand_size);
+        self
+    }
+
+    /// The prefix that is a part of the opcode, it is emitted after the
+    /// operand-size prefix and before REX.
+    pub const fn with_mandatory_prefix(mut self, mandatory_prefix: u8) -> Self {
+        self.mandatory_prefix = Some(mandatory_prefix);
+        self
+    }
+
+    /// The prefixes that can be written before the instruction, e.g. LOCK is
+    /// allowed on `ADD r/m32, r32` but not on `ADD r32, r/m32`.
+    pub const fn with_allowed_prefixes(mut self, allowed_prefixes: &'static [Prefix]) -> Self {
+        self.allowed_prefixes = allowed_prefixes;
+        self
+    }
+
+    /// Encodes with the VEX prefix (C4/C5) instead of the legacy prefixes and REX,
+    /// the mandatory prefix goes to VEX.pp and the opcode map goes to VEX.mmmmm,
+    /// e.g. `VEX.LZ.F2.0F38.W0 F6 /r` (MULX) is defined as `F2 0F 38 F6 /r` with VEX.
+    pub const fn with_vex(mut self) -> Self {
+        self.scheme = EncodingScheme::Vex;
+        self
+    }
+
+    /// Encodes with the EVEX prefix (62), the fields are the same as VEX,
+    /// e.g. `EVEX.512.0F.W0 58 /r` (VADDPS zmm1, zmm2, zmm3/m512).
+    pub const fn with_evex(mut self) -> Self {
+        self.scheme = EncodingScheme::Evex;
+        self
+    }
+
+    /// W = 1, e.g. `VEX.256.66.0F3A.W1 00 /r ib` (VPERMQ). The general-purpose
+    /// forms (e.g. `ANDN r64a, r64b, r/m64`) do not need it, W is set by the operand size.
+    pub const fn with_w(mut self) -> Self {
+        self.w = true;
+        self
+    }
+
+    /// The vector length, i.e. 1 for `VEX.256` and 2 for `EVEX.512`.
+    pub const fn with_l(mut self, l: u8) -> Self {
+        self.l = l;
+        self
+    }
+
+    pub const fn with_implicit_operands(mut self, implicit_operands: &'static [ImplicitOperand]) -> Self {
+        self.implicit_operands = implicit_operands;
+        self
+    }
+
+    /// The operand-size attribute, it is determined by the size of the first general-purpose
+    /// register or memory operand, 16-bit requires the 66 prefix and 64-bit requires REX.W.
+    pub fn operand_size(&self) -> OperandSize {
+        if let Some(operand_size) = self.explicit_operand_size {
+            return operand_size;
+        }
+
+        self.operands
+            .iter()
+            .flatten()
+            .find(|operand_definition| {
+                match operand_definition.operand_type {
+                    OperandType::Register(RegisterType::General)
+                    | OperandType::RegisterOrMem(RegisterType::General)
+                    | OperandType::Mem => true,
+                    OperandType::FixedRegister(register) => {
+                        register.class() == RegisterClass::GeneralPurpose
+                    }
+                    _ => false,
+                }
+            })
+            .map_or(OperandSize::Unsized, |operand_definition| operand_definition.size)
+    }
+}
+
+/// The encoding scheme of the prefixes, it determines how the mandatory prefix,
+/// the opcode map, REX.W/R/X/B and the vector length are encoded.
+///
+/// See `Volume 2, Section 2.3 INTEL ADVANCED VECTOR EXTENSIONS (INTEL AVX)`
+/// and `Section 2.7 INTEL AVX-512 ENCODING`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EncodingScheme {
+    Legacy, // legacy prefixes, REX and the escape bytes (0F, 0F 38 and 0F 3A)
+    Vex,    // C4/C5, 2 or 3 bytes
+    Evex,   // 62, 4 bytes
+}
+
+/// The opcode map, i.e. the escape bytes before the opcode byte in the legacy encoding,
+/// or VEX.mmmmm/EVEX.mmm in the VEX/EVEX encoding.
+///
+/// See `Volume 2, Appendix A OPCODE MAP`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OpcodeMap {
+    Legacy,  // the one-byte opcode map, e.g. `89 /r` (MOV), it is not available in VEX/EVEX
+    Map0F,   // 0F xx, e.g. `0F AF /r` (IMUL)
+    Map0F38, // 0F 38 xx, e.g. `0F 38 F0 /r` (MOVBE)
+    Map0F3A, // 0F 3A xx, e.g. `66 0F 3A 0F /r ib` (PALIGNR)
+}
+
+impl OpcodeMap {
+    /// The escape bytes in the legacy encoding.
+    pub fn escape(&self) -> &'static [u8] {
+        match self {
+            OpcodeMap::Legacy => &[],
+            OpcodeMap::Map0F => &[0x0f],
+            OpcodeMap::Map0F38 => &[0x0f, 0x38],
+            OpcodeMap::Map0F3A => &[0x0f, 0x3a],
+        }
+    }
+
+    /// The value of VEX.mmmmm and EVEX.mmm.
+    pub fn number(&self) -> u8 {
+        match self {
+            OpcodeMap::Legacy => 0,
+            OpcodeMap::Map0F => 1,
+            OpcodeMap::Map0F38 => 2,
+            OpcodeMap::Map0F3A => 3,
+        }
+    }
+}
+
+/// The operand that is not written in the instruction but accessed by it,
+/// e.g. `MUL r/m64` reads RAX and writes RDX:RAX.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ImplicitOperand {
+    pub register: Register,
+    pub access: OperandAccess,
+}
+
+impl ImplicitOperand {
+    pub const fn new(register: Register, access: OperandAccess) -> Self {
+        Self { register, access }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct OperandDefinition {
     pub encoding: OperandEncoding,
     pub access: OperandAccess,
@@ -211,35 +962,76 @@ pub struct OperandDefinition {
     pub operand_type: OperandType
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl OperandDefinition {
+    pub const fn new(
+        encoding: OperandEncoding,
+        access: OperandAccess,
+        size: OperandSize,
+        operand_type: OperandType,
+    ) -> Self {
+        Self {
+            encoding,
+            access,
+            size,
+            operand_type,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum OperandEncoding {
     ModRmReg,  // ModRM:reg(...)
     ModRmRm,   // ModRM:r/m(...)
     SIB,       // SIB byte
     Immediate, // 8/16/32/64-bit Immediate
+    Opcode,    // opcode + rb/rw/rd/ro, the low 3 bits of the opcode
+    Implicit,  // not encoded, e.g. the AL/AX/EAX/RAX in `ADD AL, imm8`
+    VexVvvv,   // VEX.vvvv/EVEX.vvvv, the extra register operand of the VEX/EVEX-encoded instruction
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum OperandAccess {
     Read,
     Write,
     ReadWrite
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum OperandType {
-    Register(RegisterType),
-    Mem(Option<OperandSize>),
-    Immediate,
+    Register(RegisterType),      // r8/r16/r32/r64
+    RegisterOrMem(RegisterType), // r/m8, r/m16, r/m32, r/m64
+    Mem,                         // m8/m16/m32/m64, or `m` if the size is `Unsized`
+    Immediate,                   // imm8/imm16/imm32/imm64
+    SignExtendedImmediate,       // imm8/imm32 that is sign-extended to the operand size
+    Relative,                    // rel8/rel32, the offset of label relative to the next instruction
+    FixedRegister(Register),     // the specified register, e.g. AL/AX/EAX/RAX in `ADD AL, imm8`
+    FixedImmediate(u8),          // the specified value, e.g. the 1 in `SHL r/m32, 1`
+    VectorMem(RegisterClass),    // vm32x/vm64x (XMM index) and vm32y/vm64y (YMM index), i.e. VSIB
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum RegisterType {
     General,
+    SSE, // XMM0-XMM15
     AVX,
+    Debug,  // DR0-DR7, only in `MOV r64, DRn` and `MOV DRn, r64`
 }
 
-#[derive(Debug, PartialEq, Clone)]
+impl RegisterType {
+    pub fn accepts(&self, register: &Register) -> bool {
+        match self {
+            RegisterType::General => register.class() == RegisterClass::GeneralPurpose,
+            RegisterType::SSE => register.class() == RegisterClass::XMM,
+            RegisterType::AVX => matches!(
+                register.class(),
+                RegisterClass::XMM | RegisterClass::YMM
+            ),
+            RegisterType::Debug => register.class() == RegisterClass::Debug,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum OperandSize {
     Unsized,
     Byte,       // 8-bit
@@ -256,6 +1048,22 @@ pub enum OperandSize {
     ZMMWord,    // 512-bit
 }
 
+impl OperandSize {
+    /// The size in bits, `Unsized` is 0.
+    pub fn bits(&self) -> u16 {
+        match self {
+            OperandSize::Unsized => 0,
+            OperandSize::Byte => 8,
+            OperandSize::Word => 16,
+            OperandSize::Dword => 32,
+            OperandSize::Qword => 64,
+            OperandSize::XMMWord => 128,
+            OperandSize::YMMWord => 256,
+            OperandSize::ZMMWord => 512,
+        }
+    }
+}
+
 // bitflags! {
 #[repr(u8)]
 #[derive(Debug, PartialEq, Clone)]
@@ -291,14 +1099,186 @@ pub enum REX {
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ModRM {
-    pub Mode: u8, // 2 bits
-    pub Register: u8, // 3 bits
-    pub RegOrMemory: u8,  // 3 bits
+    pub mode: u8, // 2 bits
+    pub register: u8, // 3 bits
+    pub reg_or_memory: u8,  // 3 bits
+}
+
+impl ModRM {
+    pub fn to_byte(&self) -> u8 {
+        (self.mode << 6) | ((self.register & 0b111) << 3) | (self.reg_or_memory & 0b111)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct SIB {
-    pub Scale: u8, // 2 bits
-    pub Index: u8, // 3 bits
-    pub Base: u8,  // 3 bits
-}
\ No newline at end of file
+    pub scale: u8, // 2 bits
+    pub index: u8, // 3 bits
+    pub base: u8,  // 3 bits
+}
+
+impl SIB {
+    pub fn to_byte(&self) -> u8 {
+        (self.scale << 6) | ((self.index & 0b111) << 3) | (self.base & 0b111)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MemoryOperand, MemoryOperandError, OperandSize, Register, RegisterClass};
+
+    #[test]
+    fn test_register_properties() {
+        assert_eq!(Register::RAX.class(), RegisterClass::GeneralPurpose);
+        assert_eq!(Register::R15B.class(), RegisterClass::GeneralPurpose);
+        assert_eq!(Register::XMM3.class(), RegisterClass::XMM);
+        assert_eq!(Register::YMM12.class(), RegisterClass::YMM);
+        assert_eq!(Register::ZMM0.class(), RegisterClass::ZMM);
+        assert_eq!(Register::FS.class(), RegisterClass::Segment);
+        assert_eq!(Register::RIP.class(), RegisterClass::InstructionPointer);
+        assert_eq!(Register::DR7.class(), RegisterClass::Debug);
+
+        assert_eq!(Register::RAX.width(), 64);
+        assert_eq!(Register::R9D.width(), 32);
+        assert_eq!(Register::SP.width(), 16);
+        assert_eq!(Register::DIL.width(), 8);
+        assert_eq!(Register::XMM15.width(), 128);
+        assert_eq!(Register::YMM1.width(), 256);
+        assert_eq!(Register::ZMM2.width(), 512);
+        assert_eq!(Register::GS.width(), 16);
+        assert_eq!(Register::DR0.width(), 64);
+
+        assert_eq!(Register::RAX.number(), 0);
+        assert_eq!(Register::SPL.number(), 4);
+        assert_eq!(Register::R9W.number(), 9);
+        assert_eq!(Register::R15.number(), 15);
+        assert_eq!(Register::XMM10.number(), 10);
+        assert_eq!(Register::YMM7.number(), 7);
+        assert_eq!(Register::DR6.number(), 6);
+
+        assert!(!Register::RDI.is_extended());
+        assert!(Register::R8B.is_extended());
+        assert!(Register::XMM8.is_extended());
+        assert!(!Register::YMM7.is_extended());
+
+        assert!(Register::SIL.requires_rex());
+        assert!(!Register::AL.requires_rex());
+        assert!(!Register::R8B.requires_rex());
+
+        assert_eq!(Register::AH.number(), 4);
+        assert_eq!(Register::BH.width(), 8);
+        assert!(Register::CH.conflicts_with_rex());
+        assert!(!Register::SPL.conflicts_with_rex());
+    }
+
+    #[test]
+    fn test_validate_memory_operand() {
+        // valid forms
+        assert!(MemoryOperand::new(OperandSize::Qword, Register::RBX).validate().is_ok());
+        assert!(
+            MemoryOperand::new(OperandSize::Qword, Register::R12)
+                .with_segment(Register::FS)
+                .with_index(Register::RSI, 4)
+                .with_displacement(-0x10)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            MemoryOperand::new_rip_relative(OperandSize::Dword)
+                .with_symbol("num1")
+                .with_displacement(4)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            MemoryOperand::new_absolute(OperandSize::Qword, 0x28)
+                .with_segment(Register::FS)
+                .validate()
+                .is_ok()
+        );
+
+        // 32-bit or 16-bit effective address
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Dword, Register::EBX).validate(),
+            Err(MemoryOperandError::InvalidBase(Register::EBX))
+        );
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Word, Register::BX).validate(),
+            Err(MemoryOperandError::InvalidBase(Register::BX))
+        );
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Qword, Register::RBX)
+                .with_index(Register::ESI, 1)
+                .validate(),
+            Err(MemoryOperandError::InvalidIndex(Register::ESI))
+        );
+
+        // VSIB, the vector register is the index
+        assert!(
+            MemoryOperand::new(OperandSize::Unsized, Register::RAX)
+                .with_index(Register::XMM4, 4)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            MemoryOperand::new(OperandSize::Unsized, Register::R8)
+                .with_index(Register::YMM12, 8)
+                .validate()
+                .is_ok()
+        );
+
+        // RSP as index
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Qword, Register::RBX)
+                .with_index(Register::RSP, 1)
+                .validate(),
+            Err(MemoryOperandError::InvalidIndex(Register::RSP))
+        );
+
+        // invalid scale
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Qword, Register::RBX)
+                .with_index(Register::RSI, 3)
+                .validate(),
+            Err(MemoryOperandError::InvalidScale(3))
+        );
+
+        // displacement overflow
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Qword, Register::RBX)
+                .with_displacement(0x8000_0000)
+                .validate(),
+            Err(MemoryOperandError::DisplacementOutOfRange(0x8000_0000))
+        );
+
+        // segment other than FS/GS
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Qword, Register::RBX)
+                .with_segment(Register::RAX)
+                .validate(),
+            Err(MemoryOperandError::InvalidSegment(Register::RAX))
+        );
+
+        // absolute address without segment override
+        assert_eq!(
+            MemoryOperand::new_absolute(OperandSize::Qword, 0x1000).validate(),
+            Err(MemoryOperandError::MissingBase)
+        );
+
+        // RIP-relative with base register
+        let mut memory = MemoryOperand::new_rip_relative(OperandSize::Qword);
+        memory.base = Some(Register::RBX);
+        assert_eq!(
+            memory.validate(),
+            Err(MemoryOperandError::RipRelativeWithRegister)
+        );
+
+        // symbol with base register
+        assert_eq!(
+            MemoryOperand::new(OperandSize::Qword, Register::RBX)
+                .with_symbol("num1")
+                .validate(),
+            Err(MemoryOperandError::SymbolWithoutRipRelative)
+        );
+    }
+}
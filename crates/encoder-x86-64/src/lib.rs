@@ -18,7 +18,11 @@
  *   e.g. "mov eax, dword [ebx]" is invalid.
  */
 
-mod encode;
-mod instruction;
-mod mnemonic;
-mod parser;
+// the mnemonics and register names follow the Intel manual, e.g. `MOV` and `RAX`.
+#![allow(clippy::upper_case_acronyms)]
+
+pub mod definition;
+pub mod encode;
+pub mod instruction;
+pub mod mnemonic;
+pub mod parser;
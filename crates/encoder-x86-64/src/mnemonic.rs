@@ -4,8 +4,498 @@
 // the Mozilla Public License version 2.0 and additional exceptions.
 // For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
 
-#[derive(Debug, PartialEq, Clone)]
-pub enum Mnemonic {
+/// Generates the `Mnemonic` enum and the name lookup, so that adding a mnemonic
+/// only needs to add its name to the list.
+///
+/// A mnemonic can have aliases, e.g. `SHL | SAL`, the aliases are accepted by
+/// `from_name` and they are the same variant as the first name.
+///
+/// The conditional mnemonics (e.g. `Jcc`) are listed after the semicolon with their
+/// prefix, the name is the prefix followed by the condition, e.g. `je` and `setnae`.
+macro_rules! mnemonics {
+    (
+        $($name:ident $(| $alias:ident)*),* $(,)?;
+        $($conditional:ident = $prefix:literal),* $(,)?
+    ) => {
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        pub enum Mnemonic {
+            $($name,)*
+            $($conditional(Condition),)*
+        }
+
+        impl Mnemonic {
+            /// Gets the mnemonic by name or alias, case-insensitive, e.g. "mov" and "MOV".
+            pub fn from_name(name: &str) -> Option<Mnemonic> {
+                let name = name.to_ascii_uppercase();
+                match name.as_str() {
+                    $(stringify!($name) $(| stringify!($alias))* => return Some(Mnemonic::$name),)*
+                    _ => {}
+                }
+
+                $(
+                    if let Some(condition) =
+                        name.strip_prefix($prefix).and_then(Condition::from_name)
+                    {
+                        return Some(Mnemonic::$conditional(condition));
+                    }
+                )*
+
+                None
+            }
+
+            /// The name in lowercase, e.g. "mov".
+            pub fn name(&self) -> String {
+                let name = match self {
+                    $(Mnemonic::$name => stringify!($name).to_owned(),)*
+                    $(Mnemonic::$conditional(condition) => {
+                        format!("{}{}", $prefix, condition.name())
+                    })*
+                };
+                name.to_ascii_lowercase()
+            }
+        }
+    };
+}
+
+/// Generates the `Condition` enum, the conditions are listed in the order of
+/// their encoding (0-15), and the aliases follow the first name, e.g. `B | C | NAE`.
+macro_rules! conditions {
+    ($($name:ident $(| $alias:ident)*),* $(,)?) => {
+        /// The condition code of `Jcc`, `SETcc` and `CMOVcc`, it is the low 4 bits
+        /// of the opcode, e.g. `74` (JE rel8), `0F 94` (SETE) and `0F 44` (CMOVE).
+        ///
+        /// See `Volume 1, Appendix B EFLAGS CONDITION CODES`.
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        pub enum Condition {
+            $($name,)*
+        }
+
+        impl Condition {
+            /// Gets the condition by name or alias, e.g. "B", "C" and "NAE".
+            pub fn from_name(name: &str) -> Option<Condition> {
+                match name.to_ascii_uppercase().as_str() {
+                    $(stringify!($name) $(| stringify!($alias))* => Some(Condition::$name),)*
+                    _ => None,
+                }
+            }
+
+            /// The name in uppercase, e.g. "NE".
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Condition::$name => stringify!($name),)*
+                }
+            }
+
+            /// The condition code (0-15).
+            pub fn code(&self) -> u8 {
+                *self as u8
+            }
+        }
+    };
+}
+
+conditions! {
+    O,
+    NO,
+    B | C | NAE,
+    AE | NB | NC,
+    E | Z,
+    NE | NZ,
+    BE | NA,
+    A | NBE,
+    S,
+    NS,
+    P | PE,
+    NP | PO,
+    L | NGE,
+    GE | NL,
+    LE | NG,
+    G | NLE,
+}
+
+mnemonics! {
     MOV,
+    MOVZX,
+    MOVSX,
+    MOVSXD,
+    LEA,
+    PUSH,
+    POP,
+    PUSHFQ | PUSHF,
+    POPFQ | POPF,
+    ENTER,
+    LEAVE,
     CALL,
+    JMP,
+    RET,
+    ADD,
+    OR,
+    ADC,
+    SBB,
+    AND,
+    SUB,
+    XOR,
+    CMP,
+    TEST,
+    ROL,
+    ROR,
+    RCL,
+    RCR,
+    SHL | SAL,
+    SHR,
+    SAR,
+    NOT,
+    NEG,
+    MUL,
+    IMUL,
+    DIV,
+    IDIV,
+    INC,
+    DEC,
+    CBW,
+    CWDE,
+    CDQE,
+    CWD,
+    CDQ,
+    CQO,
+    CLC,
+    STC,
+    CMC,
+    CLD,
+    STD,
+    LAHF,
+    SAHF,
+    MOVSB,
+    MOVSW,
+    MOVSD,
+    MOVSQ,
+    CMPSB,
+    CMPSW,
+    CMPSD,
+    CMPSQ,
+    SCASB,
+    SCASW,
+    SCASD,
+    SCASQ,
+    LODSB,
+    LODSW,
+    LODSD,
+    LODSQ,
+    STOSB,
+    STOSW,
+    STOSD,
+    STOSQ,
+    XCHG,
+    CMPXCHG,
+    CMPXCHG16B,
+    XADD,
+    BT,
+    BTS,
+    BTR,
+    BTC,
+    BSF,
+    BSR,
+    POPCNT,
+    LZCNT,
+    TZCNT,
+    BSWAP,
+    MOVBE,
+    ANDN,
+    BEXTR,
+    BLSI,
+    BLSMSK,
+    BLSR,
+    BZHI,
+    MULX,
+    PDEP,
+    PEXT,
+    RORX,
+    SARX,
+    SHLX,
+    SHRX,
+    RDRAND,
+    RDSEED,
+    MFENCE,
+    LFENCE,
+    SFENCE,
+    PAUSE,
+    NOP,
+    SYSCALL,
+    CPUID,
+    RDTSC,
+    RDTSCP,
+    RDPID,
+    XGETBV,
+    INT3,
+    INT,
+    UD2,
+    HLT,
+    MOVSS,
+    MOVAPS,
+    MOVUPS,
+    MOVDQA,
+    MOVDQU,
+    ADDPS,
+    ADDPD,
+    ADDSS,
+    ADDSD,
+    SUBPS,
+    SUBPD,
+    SUBSS,
+    SUBSD,
+    MULPS,
+    MULPD,
+    MULSS,
+    MULSD,
+    DIVPS,
+    DIVPD,
+    DIVSS,
+    DIVSD,
+    SQRTPS,
+    SQRTPD,
+    SQRTSS,
+    SQRTSD,
+    MINPS,
+    MINPD,
+    MINSS,
+    MINSD,
+    MAXPS,
+    MAXPD,
+    MAXSS,
+    MAXSD,
+    UCOMISS,
+    UCOMISD,
+    COMISS,
+    COMISD,
+    CVTSI2SS,
+    CVTSI2SD,
+    CVTSS2SI,
+    CVTSD2SI,
+    CVTTSS2SI,
+    CVTTSD2SI,
+    CVTSS2SD,
+    CVTSD2SS,
+    PADDB,
+    PADDW,
+    PADDD,
+    PADDQ,
+    PSUBB,
+    PSUBW,
+    PSUBD,
+    PSUBQ,
+    PAND,
+    PANDN,
+    POR,
+    PXOR,
+    PCMPEQB,
+    PCMPEQW,
+    PCMPEQD,
+    PSHUFD,
+    PUNPCKLBW,
+    PUNPCKLWD,
+    PUNPCKLDQ,
+    PUNPCKLQDQ,
+    PUNPCKHBW,
+    PUNPCKHWD,
+    PUNPCKHDQ,
+    PUNPCKHQDQ,
+    ADDSUBPS,
+    ADDSUBPD,
+    HADDPS,
+    HADDPD,
+    HSUBPS,
+    HSUBPD,
+    MOVDDUP,
+    MOVSHDUP,
+    MOVSLDUP,
+    LDDQU,
+    PSHUFB,
+    PALIGNR,
+    PBLENDVB,
+    PMINUB,
+    PMINUW,
+    PMINUD,
+    PMAXUB,
+    PMAXUW,
+    PMAXUD,
+    PEXTRB,
+    PEXTRW,
+    PEXTRD,
+    PEXTRQ,
+    PINSRB,
+    PINSRW,
+    PINSRD,
+    PINSRQ,
+    ROUNDPS,
+    ROUNDPD,
+    ROUNDSS,
+    ROUNDSD,
+    PTEST,
+    PCMPESTRM,
+    PCMPESTRI,
+    PCMPISTRM,
+    PCMPISTRI,
+    CRC32,
+    AESENC,
+    AESENCLAST,
+    AESDEC,
+    AESDECLAST,
+    AESIMC,
+    AESKEYGENASSIST,
+    PCLMULQDQ,
+    VPCLMULQDQ,
+    SHA1RNDS4,
+    SHA1NEXTE,
+    SHA1MSG1,
+    SHA1MSG2,
+    SHA256RNDS2,
+    SHA256MSG1,
+    SHA256MSG2,
+    VMOVAPS,
+    VMOVUPS,
+    VMOVAPD,
+    VMOVUPD,
+    VMOVDQA,
+    VMOVDQU,
+    VADDPS,
+    VADDPD,
+    VADDSS,
+    VADDSD,
+    VSUBPS,
+    VSUBPD,
+    VSUBSS,
+    VSUBSD,
+    VMULPS,
+    VMULPD,
+    VMULSS,
+    VMULSD,
+    VDIVPS,
+    VDIVPD,
+    VDIVSS,
+    VDIVSD,
+    VMINPS,
+    VMINPD,
+    VMINSS,
+    VMINSD,
+    VMAXPS,
+    VMAXPD,
+    VMAXSS,
+    VMAXSD,
+    VSQRTPS,
+    VSQRTPD,
+    VSQRTSS,
+    VSQRTSD,
+    VANDPS,
+    VANDPD,
+    VANDNPS,
+    VANDNPD,
+    VORPS,
+    VORPD,
+    VXORPS,
+    VXORPD,
+    VBROADCASTSS,
+    VBROADCASTSD,
+    VPBROADCASTB,
+    VPBROADCASTW,
+    VPBROADCASTD,
+    VPBROADCASTQ,
+    VINSERTF128,
+    VINSERTI128,
+    VEXTRACTF128,
+    VEXTRACTI128,
+    VPERMILPS,
+    VPERMILPD,
+    VPERM2F128,
+    VPERM2I128,
+    VPERMD,
+    VPERMPS,
+    VPERMQ,
+    VPERMPD,
+    VPADDB,
+    VPADDW,
+    VPADDD,
+    VPADDQ,
+    VPSUBB,
+    VPSUBW,
+    VPSUBD,
+    VPSUBQ,
+    VPAND,
+    VPANDN,
+    VPOR,
+    VPXOR,
+    VPCMPEQB,
+    VPCMPEQW,
+    VPCMPEQD,
+    VPCMPEQQ,
+    VPMULLD,
+    VPSHUFB,
+    VPSHUFD,
+    VGATHERDPS,
+    VGATHERQPS,
+    VGATHERDPD,
+    VGATHERQPD,
+    VPGATHERDD,
+    VPGATHERQD,
+    VPGATHERDQ,
+    VPGATHERQQ,
+    VZEROUPPER,
+    VZEROALL,
+    VFMADD132PS,
+    VFMADD132PD,
+    VFMADD132SS,
+    VFMADD132SD,
+    VFMADD213PS,
+    VFMADD213PD,
+    VFMADD213SS,
+    VFMADD213SD,
+    VFMADD231PS,
+    VFMADD231PD,
+    VFMADD231SS,
+    VFMADD231SD,
+    VFMSUB132PS,
+    VFMSUB132PD,
+    VFMSUB132SS,
+    VFMSUB132SD,
+    VFMSUB213PS,
+    VFMSUB213PD,
+    VFMSUB213SS,
+    VFMSUB213SD,
+    VFMSUB231PS,
+    VFMSUB231PD,
+    VFMSUB231SS,
+    VFMSUB231SD,
+    VFNMADD132PS,
+    VFNMADD132PD,
+    VFNMADD132SS,
+    VFNMADD132SD,
+    VFNMADD213PS,
+    VFNMADD213PD,
+    VFNMADD213SS,
+    VFNMADD213SD,
+    VFNMADD231PS,
+    VFNMADD231PD,
+    VFNMADD231SS,
+    VFNMADD231SD,
+    VFNMSUB132PS,
+    VFNMSUB132PD,
+    VFNMSUB132SS,
+    VFNMSUB132SD,
+    VFNMSUB213PS,
+    VFNMSUB213PD,
+    VFNMSUB213SS,
+    VFNMSUB213SD,
+    VFNMSUB231PS,
+    VFNMSUB231PD,
+    VFNMSUB231SS,
+    VFNMSUB231SD,
+    VCVTPH2PS,
+    VCVTPS2PH,
+    VPDPBUSD,
+    VPDPBUSDS,
+    VPDPWSSD,
+    VPDPWSSDS;
+
+    Jcc = "J",
+    SETcc = "SET",
+    CMOVcc = "CMOV",
 }
@@ -4,12 +4,1127 @@
 // the Mozilla Public License version 2.0 and additional exceptions.
 // For more details, see the LICENSE, LICENSE.additional, and CONTRIBUTING files.
 
-use crate::instruction::Instruction;
+use std::fmt::Display;
 
-// mov <dest>, <src>
-// mov eax, dword [variable]
+use crate::{
+    instruction::{
+        Instruction, MemoryOperand, MemoryOperandError, Operand, OperandSize, Prefix, Register,
+    },
+    mnemonic::Mnemonic,
+};
 
-pub fn parse(text: &str) -> Instruction {
-    // Parsing logic will be implemented here
-    todo!()
+/* *
+ * Intel syntax (NASM flavoured), one instruction per line:
+ *
+ * ```
+ * mov <dest>, <src>      ; comment
+ * mov eax, dword [variable]
+ * mov rax, qword fs:[r12 + rsi*4 - 0x10]
+ * mov dword ptr [rel num1 + 4], 'a'
+ * lock add qword [rbx], 1
+ * rep movsb
+ * ```
+ *
+ * Prefix: `lock`, `rep`, `repe`/`repz` and `repne`/`repnz`, it is written before the mnemonic.
+ *
+ * Operands:
+ *
+ * - register: `rax`, `r8d`, `xmm0`, etc., case-insensitive.
+ * - label: any other identifier, e.g. `call func` and `jmp .loop`.
+ * - immediate: decimal `123`, hex `0x7b`, binary `0b0111_1011`, char `'a'` and `'ab'` (little-endian),
+ *   optionally with a sign and a size keyword, e.g. `-1`, `dword 0x90abcdef`.
+ * - memory: `[size [ptr]] [segment:][ [rel] effective address ]`
+ *   - size: `byte`, `word`, `dword`, `qword`, `xmmword`, `ymmword`, optional.
+ *   - segment: `fs` and `gs`, also accepted inside the brackets, e.g. `[fs:0x28]`.
+ *   - effective address: `base + index*scale + displacement`, `rip + displacement`,
+ *     or `symbol + displacement`, the terms can be in any order.
+ *   - RIP-relative: `[rel symbol]` and `[rip + disp]`. `[symbol]` is also RIP-relative
+ *     since ANASM generates position-independent code only.
+ */
+
+/// The position in the text, in bytes, i.e. `start..end`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    fn merge(&self, other: &Span) -> Span {
+        Span::new(self.start, other.end)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub span: Span,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseErrorKind {
+    UnexpectedCharacter(char),
+    UnterminatedCharLiteral,
+    InvalidNumber(String),
+    NumberOutOfRange,
+    UnexpectedEndOfLine,
+    UnexpectedToken(String),
+    UnknownMnemonic(String),
+    TooManyOperands,
+    TooManyRegisters,   // more than two registers in the effective address
+    TooManySymbols,     // more than one symbol in the effective address
+    NegativeRegister,   // registers and symbols can not be subtracted, e.g. `[rax - rbx]`
+    InvalidMemoryOperand(MemoryOperandError),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match &self.kind {
+            ParseErrorKind::UnexpectedCharacter(c) => format!("unexpected character '{}'", c),
+            ParseErrorKind::UnterminatedCharLiteral => "unterminated char literal".to_owned(),
+            ParseErrorKind::InvalidNumber(text) => format!("invalid number \"{}\"", text),
+            ParseErrorKind::NumberOutOfRange => "number out of range".to_owned(),
+            ParseErrorKind::UnexpectedEndOfLine => "unexpected end of line".to_owned(),
+            ParseErrorKind::UnexpectedToken(text) => format!("unexpected \"{}\"", text),
+            ParseErrorKind::UnknownMnemonic(name) => format!("unknown mnemonic \"{}\"", name),
+            ParseErrorKind::TooManyOperands => "too many operands".to_owned(),
+            ParseErrorKind::TooManyRegisters => {
+                "too many registers in effective address".to_owned()
+            }
+            ParseErrorKind::TooManySymbols => "too many symbols in effective address".to_owned(),
+            ParseErrorKind::NegativeRegister => {
+                "register or symbol can not be subtracted".to_owned()
+            }
+            ParseErrorKind::InvalidMemoryOperand(error) => {
+                format!("invalid memory operand: {:?}", error)
+            }
+        };
+
+        write!(f, "{} at {}..{}", message, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse(text: &str) -> Result<Instruction, ParseError> {
+    let tokens = tokenize(text)?;
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        end: text.len(),
+    };
+    parser.parse_instruction()
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum TokenKind {
+    Identifier(String),
+    Number(u128),
+    Comma,
+    Colon,
+    LeftBracket,
+    RightBracket,
+    Plus,
+    Minus,
+    Star,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Token {
+    kind: TokenKind,
+    span: Span,
+}
+
+impl Token {
+    fn text(&self) -> String {
+        match &self.kind {
+            TokenKind::Identifier(name) => name.clone(),
+            TokenKind::Number(value) => value.to_string(),
+            TokenKind::Comma => ",".to_owned(),
+            TokenKind::Colon => ":".to_owned(),
+            TokenKind::LeftBracket => "[".to_owned(),
+            TokenKind::RightBracket => "]".to_owned(),
+            TokenKind::Plus => "+".to_owned(),
+            TokenKind::Minus => "-".to_owned(),
+            TokenKind::Star => "*".to_owned(),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let offset_of = |idx: usize| chars.get(idx).map_or(text.len(), |(offset, _)| *offset);
+
+    let mut tokens = vec![];
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let (start, c) = chars[idx];
+
+        let kind = match c {
+            ' ' | '\t' | '\r' | '\n' => {
+                idx += 1;
+                continue;
+            }
+            ';' => {
+                // comment
+                break;
+            }
+            ',' => TokenKind::Comma,
+            ':' => TokenKind::Colon,
+            '[' => TokenKind::LeftBracket,
+            ']' => TokenKind::RightBracket,
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '*' => TokenKind::Star,
+            '\'' | '"' => {
+                let (value, next) = tokenize_char_literal(&chars, idx, text.len())?;
+                tokens.push(Token {
+                    kind: TokenKind::Number(value),
+                    span: Span::new(start, offset_of(next)),
+                });
+                idx = next;
+                continue;
+            }
+            '0'..='9' => {
+                let mut next = idx;
+                while next < chars.len()
+                    && (chars[next].1.is_ascii_alphanumeric() || chars[next].1 == '_')
+                {
+                    next += 1;
+                }
+
+                let span = Span::new(start, offset_of(next));
+                let value = parse_number(&text[span.start..span.end], span)?;
+                tokens.push(Token {
+                    kind: TokenKind::Number(value),
+                    span,
+                });
+                idx = next;
+                continue;
+            }
+            'a'..='z' | 'A'..='Z' | '_' | '.' => {
+                let mut next = idx;
+                while next < chars.len()
+                    && (chars[next].1.is_ascii_alphanumeric()
+                        || matches!(chars[next].1, '_' | '.' | '$' | '@'))
+                {
+                    next += 1;
+                }
+
+                let span = Span::new(start, offset_of(next));
+                tokens.push(Token {
+                    kind: TokenKind::Identifier(text[span.start..span.end].to_owned()),
+                    span,
+                });
+                idx = next;
+                continue;
+            }
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedCharacter(c),
+                    Span::new(start, start + c.len_utf8()),
+                ));
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, start + c.len_utf8()),
+        });
+        idx += 1;
+    }
+
+    Ok(tokens)
+}
+
+/// Char literal, e.g. `'a'`, `"a"`, `'\n'` and `'ab'`, multiple chars are packed in
+/// little-endian, i.e. `'ab'` is 0x6261. Returns the value and the index of the next char.
+fn tokenize_char_literal(
+    chars: &[(usize, char)],
+    start_idx: usize,
+    text_length: usize,
+) -> Result<(u128, usize), ParseError> {
+    let (start, quote) = chars[start_idx];
+    let mut bytes: Vec<u8> = vec![];
+    let mut idx = start_idx + 1;
+
+    loop {
+        let Some((offset, c)) = chars.get(idx) else {
+            return Err(ParseError::new(
+                ParseErrorKind::UnterminatedCharLiteral,
+                Span::new(start, text_length),
+            ));
+        };
+
+        match c {
+            c if *c == quote => {
+                idx += 1;
+                break;
+            }
+            '\\' => {
+                let escaped = match chars.get(idx + 1).map(|(_, c)| *c) {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('0') => '\0',
+                    Some(c @ ('\\' | '\'' | '"')) => c,
+                    Some(c) => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::UnexpectedCharacter(c),
+                            Span::new(*offset, *offset + 1 + c.len_utf8()),
+                        ));
+                    }
+                    None => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::UnterminatedCharLiteral,
+                            Span::new(start, text_length),
+                        ));
+                    }
+                };
+                bytes.push(escaped as u8);
+                idx += 2;
+            }
+            c => {
+                let mut buffer = [0u8; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                idx += 1;
+            }
+        }
+    }
+
+    let end = chars.get(idx).map_or(text_length, |(offset, _)| *offset);
+
+    if bytes.len() > 8 {
+        return Err(ParseError::new(
+            ParseErrorKind::NumberOutOfRange,
+            Span::new(start, end),
+        ));
+    }
+
+    let value = bytes
+        .iter()
+        .rev()
+        .fold(0u128, |value, byte| (value << 8) | *byte as u128);
+
+    Ok((value, idx))
+}
+
+/// Decimal `123`, hex `0x7b` and binary `0b0111_1011`, underscores are ignored.
+fn parse_number(text: &str, span: Span) -> Result<u128, ParseError> {
+    let digits = text.replace('_', "");
+    let lowercase = digits.to_ascii_lowercase();
+
+    let (radix, digits) = if let Some(hex) = lowercase.strip_prefix("0x") {
+        (16, hex)
+    } else if let Some(binary) = lowercase.strip_prefix("0b") {
+        (2, binary)
+    } else {
+        (10, lowercase.as_str())
+    };
+
+    let value = u128::from_str_radix(digits, radix)
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber(text.to_owned()), span))?;
+
+    if value > u64::MAX as u128 {
+        return Err(ParseError::new(ParseErrorKind::NumberOutOfRange, span));
+    }
+
+    Ok(value)
+}
+
+fn size_from_keyword(name: &str) -> Option<OperandSize> {
+    let size = match name.to_ascii_lowercase().as_str() {
+        "byte" => OperandSize::Byte,
+        "word" => OperandSize::Word,
+        "dword" => OperandSize::Dword,
+        "qword" => OperandSize::Qword,
+        "xmmword" => OperandSize::XMMWord,
+        "ymmword" => OperandSize::YMMWord,
+        _ => return None,
+    };
+    Some(size)
+}
+
+fn is_segment_register(register: &Register) -> bool {
+    matches!(register, Register::FS | Register::GS)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    end: usize, // the length of the text, for the span of "unexpected end of line"
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn peek_kind(&self, offset: usize) -> Option<&TokenKind> {
+        self.tokens
+            .get(self.position + offset)
+            .map(|token| &token.kind)
+    }
+
+    fn next(&mut self) -> Result<Token, ParseError> {
+        match self.tokens.get(self.position) {
+            Some(token) => {
+                self.position += 1;
+                Ok(token.clone())
+            }
+            None => Err(ParseError::new(
+                ParseErrorKind::UnexpectedEndOfLine,
+                Span::new(self.end, self.end),
+            )),
+        }
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> Result<Token, ParseError> {
+        let token = self.next()?;
+        if token.kind == kind {
+            Ok(token)
+        } else {
+            Err(unexpected(&token))
+        }
+    }
+
+    fn parse_instruction(&mut self) -> Result<Instruction, ParseError> {
+        let mut token = self.next()?;
+
+        let prefix = match &token.kind {
+            TokenKind::Identifier(name) => Prefix::from_name(name),
+            _ => None,
+        };
+        if prefix.is_some() {
+            token = self.next()?;
+        }
+
+        let mnemonic = match &token.kind {
+            TokenKind::Identifier(name) => Mnemonic::from_name(name).ok_or_else(|| {
+                ParseError::new(ParseErrorKind::UnknownMnemonic(name.clone()), token.span)
+            })?,
+            _ => return Err(unexpected(&token)),
+        };
+
+        let mut operands: Vec<Operand> = vec![];
+
+        if self.peek().is_some() {
+            loop {
+                let start = self.peek().map(|token| token.span);
+                let operand = self.parse_operand()?;
+
+                if operands.len() == 4 {
+                    return Err(ParseError::new(
+                        ParseErrorKind::TooManyOperands,
+                        start.unwrap(),
+                    ));
+                }
+                operands.push(operand);
+
+                match self.peek() {
+                    None => break,
+                    Some(token) if token.kind == TokenKind::Comma => {
+                        self.position += 1;
+                    }
+                    Some(token) => return Err(unexpected(token)),
+                }
+            }
+        }
+
+        let instruction = Instruction::new(mnemonic, &operands);
+        Ok(match prefix {
+            Some(prefix) => instruction.with_prefix(prefix),
+            None => instruction,
+        })
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, ParseError> {
+        let token = self.peek().cloned().ok_or(ParseError::new(
+            ParseErrorKind::UnexpectedEndOfLine,
+            Span::new(self.end, self.end),
+        ))?;
+
+        match &token.kind {
+            TokenKind::Identifier(name) => {
+                if let Some(size) = size_from_keyword(name) {
+                    self.position += 1;
+
+                    // the optional `ptr`
+                    if let Some(TokenKind::Identifier(name)) = self.peek_kind(0)
+                        && name.eq_ignore_ascii_case("ptr")
+                    {
+                        self.position += 1;
+                    }
+
+                    match self.peek_kind(0) {
+                        Some(TokenKind::Number(_) | TokenKind::Minus | TokenKind::Plus) => {
+                            self.parse_immediate(Some(size))
+                        }
+                        _ => self.parse_memory(size),
+                    }
+                } else if let Some(register) = Register::from_name(name) {
+                    if is_segment_register(&register)
+                        && self.peek_kind(1) == Some(&TokenKind::Colon)
+                    {
+                        self.parse_memory(OperandSize::Unsized)
+                    } else {
+                        self.position += 1;
+                        Ok(Operand::Register(register))
+                    }
+                } else {
+                    // the target of relative branch, e.g. `call label`
+                    self.position += 1;
+                    Ok(Operand::Label(name.clone()))
+                }
+            }
+            TokenKind::LeftBracket => self.parse_memory(OperandSize::Unsized),
+            TokenKind::Number(_) | TokenKind::Minus | TokenKind::Plus => {
+                self.parse_immediate(None)
+            }
+            _ => Err(unexpected(&token)),
+        }
+    }
+
+    /// Immediate, the variant is the smallest of imm8, imm32 and imm64 that holds the value
+    /// as a signed integer (e.g. `-1` is `Immediate8(0xff)` and `255` is `Immediate32(0xff)`),
+    /// unless the size keyword is specified.
+    ///
+    /// `Immediate16` is only produced by the `word` keyword, since it selects the 16-bit
+    /// operand size of the forms that have no other operand, e.g. `push word 1`.
+    fn parse_immediate(&mut self, size: Option<OperandSize>) -> Result<Operand, ParseError> {
+        let first = self.next()?;
+        let (negative, number_token) = match &first.kind {
+            TokenKind::Minus => (true, self.next()?),
+            TokenKind::Plus => (false, self.next()?),
+            _ => (false, first.clone()),
+        };
+
+        let TokenKind::Number(magnitude) = number_token.kind else {
+            return Err(unexpected(&number_token));
+        };
+
+        let span = first.span.merge(&number_token.span);
+        let value = if negative {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        };
+
+        let out_of_range = || ParseError::new(ParseErrorKind::NumberOutOfRange, span);
+
+        let size = match size {
+            Some(size) => size,
+            None if i8::try_from(value).is_ok() => OperandSize::Byte,
+            None if i32::try_from(value).is_ok() => OperandSize::Dword,
+            None => OperandSize::Qword,
+        };
+
+        // the value must fit in the size, either as a signed or an unsigned integer.
+        let bits = size.bits() as u32;
+        if !matches!(
+            size,
+            OperandSize::Byte | OperandSize::Word | OperandSize::Dword | OperandSize::Qword
+        ) {
+            return Err(unexpected(&number_token));
+        }
+
+        if value < -(1i128 << (bits - 1)) || value >= (1i128 << bits) {
+            return Err(out_of_range());
+        }
+
+        let operand = match size {
+            OperandSize::Byte => Operand::Immediate8(value as u8),
+            OperandSize::Word => Operand::Immediate16(value as u16),
+            OperandSize::Dword => Operand::Immediate32(value as u32),
+            _ => Operand::Immediate64(value as u64),
+        };
+
+        Ok(operand)
+    }
+
+    fn parse_memory(&mut self, size: OperandSize) -> Result<Operand, ParseError> {
+        let start = self.peek().map_or(Span::new(self.end, self.end), |token| token.span);
+
+        let mut segment: Option<Register> = None;
+        let mut base: Option<Register> = None;
+        let mut index: Option<(Register, u8)> = None;
+        let mut symbol: Option<String> = None;
+        let mut displacement: i128 = 0;
+        let mut rip_relative = false;
+
+        // segment override before the brackets, e.g. `fs:[...]`
+        segment = segment.or(self.parse_segment());
+
+        self.expect(TokenKind::LeftBracket)?;
+
+        // segment override inside the brackets, e.g. `[fs:...]`
+        segment = segment.or(self.parse_segment());
+
+        if let Some(TokenKind::Identifier(name)) = self.peek_kind(0)
+            && name.eq_ignore_ascii_case("rel")
+        {
+            rip_relative = true;
+            self.position += 1;
+        }
+
+        let mut negative = false;
+        if let Some(TokenKind::Minus) = self.peek_kind(0) {
+            negative = true;
+            self.position += 1;
+        }
+
+        let end = loop {
+            let token = self.next()?;
+
+            match &token.kind {
+                TokenKind::Identifier(name) => {
+                    if let Some(register) = Register::from_name(name) {
+                        if negative {
+                            return Err(ParseError::new(
+                                ParseErrorKind::NegativeRegister,
+                                token.span,
+                            ));
+                        }
+
+                        if self.peek_kind(0) == Some(&TokenKind::Star) {
+                            // index*scale
+                            self.position += 1;
+                            let scale_token = self.next()?;
+                            let TokenKind::Number(scale) = scale_token.kind else {
+                                return Err(unexpected(&scale_token));
+                            };
+                            if index.is_some() {
+                                return Err(ParseError::new(
+                                    ParseErrorKind::TooManyRegisters,
+                                    token.span,
+                                ));
+                            }
+                            index = Some((register, scale.min(u8::MAX as u128) as u8));
+                        } else if base.is_none() {
+                            base = Some(register);
+                        } else if index.is_none() {
+                            index = Some((register, 1));
+                        } else {
+                            return Err(ParseError::new(
+                                ParseErrorKind::TooManyRegisters,
+                                token.span,
+                            ));
+                        }
+                    } else {
+                        if negative {
+                            return Err(ParseError::new(
+                                ParseErrorKind::NegativeRegister,
+                                token.span,
+                            ));
+                        }
+                        if symbol.is_some() {
+                            return Err(ParseError::new(
+                                ParseErrorKind::TooManySymbols,
+                                token.span,
+                            ));
+                        }
+                        symbol = Some(name.clone());
+                    }
+                }
+                TokenKind::Number(value) => {
+                    if self.peek_kind(0) == Some(&TokenKind::Star) {
+                        // scale*index
+                        self.position += 1;
+                        let register_token = self.next()?;
+                        let register = match &register_token.kind {
+                            TokenKind::Identifier(name) => Register::from_name(name),
+                            _ => None,
+                        }
+                        .ok_or_else(|| unexpected(&register_token))?;
+
+                        if negative {
+                            return Err(ParseError::new(
+                                ParseErrorKind::NegativeRegister,
+                                register_token.span,
+                            ));
+                        }
+                        if index.is_some() {
+                            return Err(ParseError::new(
+                                ParseErrorKind::TooManyRegisters,
+                                register_token.span,
+                            ));
+                        }
+                        index = Some((register, (*value).min(u8::MAX as u128) as u8));
+                    } else if negative {
+                        displacement -= *value as i128;
+                    } else {
+                        displacement += *value as i128;
+                    }
+                }
+                _ => return Err(unexpected(&token)),
+            }
+
+            let token = self.next()?;
+            match token.kind {
+                TokenKind::RightBracket => break token.span,
+                TokenKind::Plus => negative = false,
+                TokenKind::Minus => negative = true,
+                _ => return Err(unexpected(&token)),
+            }
+        };
+
+        let span = start.merge(&end);
+
+        if base == Some(Register::RIP) {
+            base = None;
+            rip_relative = true;
+        }
+
+        // `[symbol]` is RIP-relative since ANASM generates position-independent code only.
+        if base.is_none() && index.is_none() && segment.is_none() && symbol.is_some() {
+            rip_relative = true;
+        }
+
+        let displacement = i64::try_from(displacement)
+            .map_err(|_| ParseError::new(ParseErrorKind::NumberOutOfRange, span))?;
+
+        let memory = MemoryOperand {
+            size,
+            segment,
+            base,
+            index: index.map(|(register, _)| register),
+            scale: index.map_or(1, |(_, scale)| scale),
+            displacement,
+            symbol,
+            rip_relative,
+        };
+
+        memory
+            .validate()
+            .map_err(|error| ParseError::new(ParseErrorKind::InvalidMemoryOperand(error), span))?;
+
+        Ok(Operand::Memory(memory))
+    }
+
+    /// Segment override, i.e. `fs:` and `gs:`.
+    fn parse_segment(&mut self) -> Option<Register> {
+        if let Some(TokenKind::Identifier(name)) = self.peek_kind(0)
+            && let Some(register) = Register::from_name(name)
+            && is_segment_register(&register)
+            && self.peek_kind(1) == Some(&TokenKind::Colon)
+        {
+            self.position += 2;
+            Some(register)
+        } else {
+            None
+        }
+    }
+}
+
+fn unexpected(token: &Token) -> ParseError {
+    ParseError::new(ParseErrorKind::UnexpectedToken(token.text()), token.span)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        instruction::{
+            Instruction, MemoryOperand, MemoryOperandError, Operand,
+            OperandSize::{self, *},
+            Prefix,
+            Register::{self, *},
+        },
+        mnemonic::{
+            Condition,
+            Mnemonic::{self, *},
+        },
+    };
+
+    use super::{ParseError, ParseErrorKind, Span, parse};
+
+    fn instruction(mnemonic: Mnemonic, operands: &[Operand]) -> Instruction {
+        Instruction::new(mnemonic, operands)
+    }
+
+    fn mem(size: OperandSize, base: Register) -> MemoryOperand {
+        MemoryOperand::new(size, base)
+    }
+
+    fn error(kind: ParseErrorKind, start: usize, end: usize) -> ParseError {
+        ParseError {
+            kind,
+            span: Span::new(start, end),
+        }
+    }
+
+    #[test]
+    fn test_parse_register() {
+        assert_eq!(
+            parse("mov rax, rcx").unwrap(),
+            instruction(MOV, &[RAX.into(), RCX.into()])
+        );
+        assert_eq!(
+            parse("  MOV  R9D,ECX  ; comment").unwrap(),
+            instruction(MOV, &[R9D.into(), ECX.into()])
+        );
+        assert_eq!(
+            parse("movzx rax, cl").unwrap(),
+            instruction(MOVZX, &[RAX.into(), CL.into()])
+        );
+        assert_eq!(
+            parse("mov rax, DR7").unwrap(),
+            instruction(MOV, &[RAX.into(), DR7.into()])
+        );
+    }
+
+    #[test]
+    fn test_parse_label() {
+        assert_eq!(
+            parse("call func").unwrap(),
+            instruction(CALL, &[Operand::Label("func".to_owned())])
+        );
+        assert_eq!(
+            parse("jmp .loop_1").unwrap(),
+            instruction(JMP, &[Operand::Label(".loop_1".to_owned())])
+        );
+        assert_eq!(
+            parse("jnae .done").unwrap(),
+            instruction(Jcc(Condition::B), &[Operand::Label(".done".to_owned())])
+        );
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        assert_eq!(
+            parse("lock add qword [rbx], 1").unwrap(),
+            instruction(ADD, &[mem(Qword, RBX).into(), Operand::Immediate8(1)])
+                .with_prefix(Prefix::LOCK)
+        );
+        assert_eq!(
+            parse("LOCK xchg [rax], ecx").unwrap(),
+            instruction(XCHG, &[mem(Unsized, RAX).into(), ECX.into()]).with_prefix(Prefix::LOCK)
+        );
+        assert_eq!(parse("rep movsb").unwrap(), instruction(MOVSB, &[]).with_prefix(Prefix::REP));
+        assert_eq!(
+            parse("repz cmpsq").unwrap(),
+            instruction(CMPSQ, &[]).with_prefix(Prefix::REPE)
+        );
+        assert_eq!(
+            parse("repnz scasb").unwrap(),
+            instruction(SCASB, &[]).with_prefix(Prefix::REPNE)
+        );
+    }
+
+    #[test]
+    fn test_parse_immediate() {
+        assert_eq!(
+            parse("mov al, 0xef").unwrap(),
+            instruction(MOV, &[AL.into(), Operand::Immediate32(0xef)])
+        );
+        assert_eq!(
+            parse("mov eax, 123").unwrap(),
+            instruction(MOV, &[EAX.into(), Operand::Immediate8(123)])
+        );
+        assert_eq!(
+            parse("mov eax, -1").unwrap(),
+            instruction(MOV, &[EAX.into(), Operand::Immediate8(0xff)])
+        );
+        assert_eq!(
+            parse("mov eax, 0b1000_0000").unwrap(),
+            instruction(MOV, &[EAX.into(), Operand::Immediate32(0x80)])
+        );
+        assert_eq!(
+            parse("mov eax, 0x90abcdef").unwrap(),
+            instruction(MOV, &[EAX.into(), Operand::Immediate64(0x90abcdef)])
+        );
+        assert_eq!(
+            parse("mov rax, 0x1234567890abcdef").unwrap(),
+            instruction(MOV, &[RAX.into(), Operand::Immediate64(0x1234567890abcdef)])
+        );
+        assert_eq!(
+            parse("mov rax, 0xffffffffffffffff").unwrap(),
+            instruction(MOV, &[RAX.into(), Operand::Immediate64(u64::MAX)])
+        );
+        assert_eq!(
+            parse("mov rax, dword 0x90abcdef").unwrap(),
+            instruction(MOV, &[RAX.into(), Operand::Immediate32(0x90abcdef)])
+        );
+        assert_eq!(
+            parse("mov al, 'a'").unwrap(),
+            instruction(MOV, &[AL.into(), Operand::Immediate8(b'a')])
+        );
+        assert_eq!(
+            parse(r"mov al, '\n'").unwrap(),
+            instruction(MOV, &[AL.into(), Operand::Immediate8(b'\n')])
+        );
+        assert_eq!(
+            parse("mov ax, \"ab\"").unwrap(),
+            instruction(MOV, &[AX.into(), Operand::Immediate32(0x6261)])
+        );
+        assert_eq!(
+            parse("push word 1").unwrap(),
+            instruction(PUSH, &[Operand::Immediate16(1)])
+        );
+    }
+
+    #[test]
+    fn test_parse_memory() {
+        assert_eq!(
+            parse("mov rax, [rbx]").unwrap(),
+            instruction(MOV, &[RAX.into(), mem(Unsized, RBX).into()])
+        );
+        assert_eq!(
+            parse("mov dword [rax], 0x12345678").unwrap(),
+            instruction(
+                MOV,
+                &[mem(Dword, RAX).into(), Operand::Immediate32(0x12345678)]
+            )
+        );
+        assert_eq!(
+            parse("mov dword ptr [rax], 0x12345678").unwrap(),
+            instruction(
+                MOV,
+                &[mem(Dword, RAX).into(), Operand::Immediate32(0x12345678)]
+            )
+        );
+        assert_eq!(
+            parse("mov r8, [rsp - 0x10]").unwrap(),
+            instruction(
+                MOV,
+                &[R8.into(), mem(Unsized, RSP).with_displacement(-0x10).into()]
+            )
+        );
+        assert_eq!(
+            parse("mov rax, qword [rcx + rsi*4 + 0x1234]").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Qword, RCX)
+                        .with_index(RSI, 4)
+                        .with_displacement(0x1234)
+                        .into()
+                ]
+            )
+        );
+        assert_eq!(
+            parse("mov rax, [8*r10 + r9 + 1 - 2]").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    mem(Unsized, R9)
+                        .with_index(R10, 8)
+                        .with_displacement(-1)
+                        .into()
+                ]
+            )
+        );
+        assert_eq!(
+            parse("mov rax, [rcx + rsi]").unwrap(),
+            instruction(
+                MOV,
+                &[RAX.into(), mem(Unsized, RCX).with_index(RSI, 1).into()]
+            )
+        );
+
+        // VSIB, the index is a vector register
+        assert_eq!(
+            parse("vgatherdps ymm1, [rax + ymm2*4], ymm3").unwrap(),
+            instruction(
+                VGATHERDPS,
+                &[YMM1.into(), mem(Unsized, RAX).with_index(YMM2, 4).into(), YMM3.into()]
+            )
+        );
+
+        // segment override
+        assert_eq!(
+            parse("mov r10, qword fs:[r12 + rsi*4 - 0x10]").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    R10.into(),
+                    mem(Qword, R12)
+                        .with_segment(FS)
+                        .with_index(RSI, 4)
+                        .with_displacement(-0x10)
+                        .into()
+                ]
+            )
+        );
+        assert_eq!(
+            parse("mov rax, gs:[rbx]").unwrap(),
+            instruction(MOV, &[RAX.into(), mem(Unsized, RBX).with_segment(GS).into()])
+        );
+        assert_eq!(
+            parse("mov rax, qword [fs:0x28]").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    RAX.into(),
+                    MemoryOperand::new_absolute(Qword, 0x28)
+                        .with_segment(FS)
+                        .into()
+                ]
+            )
+        );
+
+        // RIP-relative
+        assert_eq!(
+            parse("mov eax, [rel num1]").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    EAX.into(),
+                    MemoryOperand::new_rip_relative(Unsized)
+                        .with_symbol("num1")
+                        .into()
+                ]
+            )
+        );
+        assert_eq!(
+            parse("mov dword [rel num1 + 0x10], eax").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    MemoryOperand::new_rip_relative(Dword)
+                        .with_symbol("num1")
+                        .with_displacement(0x10)
+                        .into(),
+                    EAX.into()
+                ]
+            )
+        );
+        assert_eq!(
+            parse("mov eax, [num1]").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    EAX.into(),
+                    MemoryOperand::new_rip_relative(Unsized)
+                        .with_symbol("num1")
+                        .into()
+                ]
+            )
+        );
+        assert_eq!(
+            parse("mov eax, dword [rip - 8]").unwrap(),
+            instruction(
+                MOV,
+                &[
+                    EAX.into(),
+                    MemoryOperand::new_rip_relative(Dword)
+                        .with_displacement(-8)
+                        .into()
+                ]
+            )
+        );
+
+        assert_eq!(
+            parse("lea rax, [rip + label]").unwrap(),
+            instruction(
+                LEA,
+                &[
+                    RAX.into(),
+                    MemoryOperand::new_rip_relative(Unsized)
+                        .with_symbol("label")
+                        .into()
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("").unwrap_err(),
+            error(ParseErrorKind::UnexpectedEndOfLine, 0, 0)
+        );
+        assert_eq!(
+            parse("foo rax, rbx").unwrap_err(),
+            error(ParseErrorKind::UnknownMnemonic("foo".to_owned()), 0, 3)
+        );
+        assert_eq!(
+            parse("lock").unwrap_err(),
+            error(ParseErrorKind::UnexpectedEndOfLine, 4, 4)
+        );
+        assert_eq!(
+            parse("mov rax, rbx,").unwrap_err(),
+            error(ParseErrorKind::UnexpectedEndOfLine, 13, 13)
+        );
+        assert_eq!(
+            parse("mov rax rbx").unwrap_err(),
+            error(ParseErrorKind::UnexpectedToken("rbx".to_owned()), 8, 11)
+        );
+        assert_eq!(
+            parse("mov rax, #1").unwrap_err(),
+            error(ParseErrorKind::UnexpectedCharacter('#'), 9, 10)
+        );
+        assert_eq!(
+            parse("mov rax, 0x12g").unwrap_err(),
+            error(ParseErrorKind::InvalidNumber("0x12g".to_owned()), 9, 14)
+        );
+        assert_eq!(
+            parse("mov rax, 0x1_0000_0000_0000_0000").unwrap_err(),
+            error(ParseErrorKind::NumberOutOfRange, 9, 32)
+        );
+        assert_eq!(
+            parse("mov al, byte 0x100").unwrap_err(),
+            error(ParseErrorKind::NumberOutOfRange, 13, 18)
+        );
+        assert_eq!(
+            parse("mov al, 'a").unwrap_err(),
+            error(ParseErrorKind::UnterminatedCharLiteral, 8, 10)
+        );
+        assert_eq!(
+            parse("mov rax, [rbx + rcx + rdx]").unwrap_err(),
+            error(ParseErrorKind::TooManyRegisters, 22, 25)
+        );
+        assert_eq!(
+            parse("mov rax, [rbx - rcx]").unwrap_err(),
+            error(ParseErrorKind::NegativeRegister, 16, 19)
+        );
+        assert_eq!(
+            parse("mov rax, [rbx + a + b]").unwrap_err(),
+            error(ParseErrorKind::TooManySymbols, 20, 21)
+        );
+        assert_eq!(
+            parse("mov eax, [ebx]").unwrap_err(),
+            error(
+                ParseErrorKind::InvalidMemoryOperand(MemoryOperandError::InvalidBase(EBX)),
+                9,
+                14
+            )
+        );
+        assert_eq!(
+            parse("mov rax, [rbx + rsp*2]").unwrap_err(),
+            error(
+                ParseErrorKind::InvalidMemoryOperand(MemoryOperandError::InvalidIndex(RSP)),
+                9,
+                22
+            )
+        );
+        assert_eq!(
+            parse("mov rax, [rbx + num1]").unwrap_err(),
+            error(
+                ParseErrorKind::InvalidMemoryOperand(MemoryOperandError::SymbolWithoutRipRelative),
+                9,
+                21
+            )
+        );
+        assert_eq!(
+            parse("mov rax, [rbx + rsi*3]").unwrap_err(),
+            error(
+                ParseErrorKind::InvalidMemoryOperand(MemoryOperandError::InvalidScale(3)),
+                9,
+                22
+            )
+        );
+    }
 }